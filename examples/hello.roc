@@ -1,6 +1,7 @@
 app "example-app"
     packages { pf: "https://github.com/lukewilliamboswell/roc-tui/releases/download/0.0.2/WGLVMwEtG9JJbYr60L_HkOqNCcowgkwEhSyqipgisaY.tar.br" }
     imports [
+        pf.Command.{ Command },
         pf.Event.{ Event },
         pf.Elem.{ Elem },
     ]
@@ -11,11 +12,14 @@ program = { init, update, render }
 # Model
 Model : { text : Str }
 
-init = \_ -> { text: "Hello world!" }
+init = \_ -> { model: { text: "Hello world!" }, commands: [] }
 
 # Handle Events
-update : Model, Event -> Model
-update = \model, _ -> model
+update : Model, Event -> { model : Model, commands : List Command }
+update = \model, event ->
+    when event is
+        KeyPressed { code } if code == Esc -> { model, commands: [Quit 0] }
+        _ -> { model, commands: [] }
 
 # Render UI
 render : Model -> List Elem
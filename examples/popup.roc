@@ -1,7 +1,8 @@
 app "example-app"
     packages { pf: "https://github.com/lukewilliamboswell/roc-tui/releases/download/0.0.2/WGLVMwEtG9JJbYr60L_HkOqNCcowgkwEhSyqipgisaY.tar.br" }
     imports [
-        pf.Event.{ Event, Bounds },
+        pf.Command.{ Command },
+        pf.Event.{ Event, Startup },
         pf.Elem.{ Elem },
     ]
     provides [program, Model] {} to pf
@@ -11,20 +12,24 @@ program = { init, update, render }
 # Model
 Model : {showPopup : Bool}
 
-init : Bounds -> Model
-init = \_ -> {showPopup : Bool.true}
+init : Startup -> { model : Model, commands : List Command }
+init = \_ -> { model: {showPopup : Bool.true}, commands: [] }
 
 # Handle Events
-update : Model, Event -> Model
+update : Model, Event -> { model : Model, commands : List Command }
 update = \model, event ->
     when event is
-        KeyPressed code ->
+        KeyPressed { code } ->
             when code is
                 Enter ->
-                    {model & showPopup : !model.showPopup}
-                _ -> model
+                    { model: {model & showPopup : !model.showPopup}, commands: [] }
+                Esc if model.showPopup ->
+                    { model: {model & showPopup : Bool.false}, commands: [] }
+                Esc ->
+                    { model, commands: [Quit 0] }
+                _ -> { model, commands: [] }
 
-        _ -> model
+        _ -> { model, commands: [] }
 
 # Render UI
 render : Model -> List Elem
@@ -34,7 +39,7 @@ render = \model ->
         _ -> [ body ]
 
 # Background widgets
-bgText = [[Elem.styled "Some background text... press Enter key to toggle modal!" { fg: Blue }]]
+bgText = [[Elem.styled "Some background text... press Enter key to toggle modal, or Esc to quit!" { fg: Blue }]]
 title = Elem.unstyled "Popup Demo"
 block = Elem.blockConfig { title, borders : [All] }
 body = Elem.layout [ Elem.paragraph { text : bgText, block } ] {}
@@ -44,7 +49,7 @@ modal =
     Elem.layout [ 
         Elem.paragraph {
             text : [
-                [Elem.styled "Can you handle this... press Enter to close me!" { fg: Red }],
+                [Elem.styled "Can you handle this... press Enter or Esc to close me!" { fg: Red }],
             ], 
             block : Elem.blockConfig { 
                 title : Elem.styled "WARNING!" { bg : Red, fg: White}, 
@@ -1,7 +1,8 @@
 app "example-app"
     packages { pf: "https://github.com/lukewilliamboswell/roc-tui/releases/download/0.0.2/WGLVMwEtG9JJbYr60L_HkOqNCcowgkwEhSyqipgisaY.tar.br" }
     imports [
-        pf.Event.{ Event, Bounds },
+        pf.Command.{ Command },
+        pf.Event.{ Event, Startup },
         pf.Elem.{ Elem },
     ]
     provides [program, Model] {} to pf
@@ -9,16 +10,36 @@ app "example-app"
 program = { init, update, render }
 
 # Model
-Model : { draft : Str, todos : List Str, selected : Nat }
+Model : { path : Str, draft : Str, todos : List Str, selected : Nat }
 
-init : Bounds -> Model
-init = \_ -> { draft: "", todos: [], selected : 0 }
+# The todos are saved one per line, in the file given on the command line
+# e.g. `./todo work.txt`, or todos.txt by default
+init : Startup -> { model : Model, commands : List Command }
+init = \{ args } ->
+    path = List.get args 1 |> Result.withDefault "todos.txt"
+
+    { model: { path, draft: "", todos: [], selected : 0 }, commands: [ReadFile path] }
 
 # Handle Events
-update : Model, Event -> Model
+update : Model, Event -> { model : Model, commands : List Command }
 update = \model, event ->
     when event is
-        KeyPressed code ->
+        KeyPressed { code } if code == Esc -> { model, commands: [Quit 0] }
+        FileRead { contents } ->
+            todos = Str.split contents "\n" |> List.dropIf Str.isEmpty
+            { model: { model & todos }, commands: [] }
+        _ ->
+            newModel = updateModel model event
+            if newModel.todos == model.todos then
+                { model: newModel, commands: [] }
+            else
+                contents = Str.joinWith newModel.todos "\n"
+                { model: newModel, commands: [WriteFile { path: newModel.path, contents }] }
+
+updateModel : Model, Event -> Model
+updateModel = \model, event ->
+    when event is
+        KeyPressed { code } ->
             when code is
                 Scalar char -> { model & draft: Str.concat model.draft char, selected : 0 }
                 Delete | Backspace -> { model & draft: removeChar model.draft }
@@ -0,0 +1,134 @@
+interface Command
+    exposes [Command, TimerConfig, ReadLinesConfig, RenameConfig, SpawnConfig, ProcessInput, CursorStyle, EditorConfig]
+    imports [Event.{ EnvVar, FileContents }]
+
+## Ask the host to do something on behalf of the app. These are returned from
+## `init` and `update` alongside the new model.
+##
+##     KeyPressed { code } ->
+##         when code is
+##             Esc -> { model, commands: [Quit 0] }
+##             _ -> { model, commands: [] }
+##
+## - `Quit` restores the terminal and exits the program with the given exit code.
+## - `SetBracketedPaste` turns bracketed paste on or off. When it is on, which
+## is the default, pasted text arrives as a single `Paste` event instead of
+## one `KeyPressed` per character.
+## - `SetFocusReporting` turns `FocusGained` and `FocusLost` events on or off,
+## they are on by default.
+## - `SetTickRate` changes the milliseconds between `Tick` events, the default
+## is 50. Use `SetTickRate 0` to stop ticks altogether.
+## - `StartTimer` sends a `Timer` event with the timer's id once the time is up.
+## Starting a timer with the id of one which is already running restarts it.
+## - `CancelTimer` stops the timer with the given id.
+## - `ReadFile` reads a whole file as text, the contents arrive in a `FileRead`
+## event.
+## - `ReadFileLines` reads some of the lines of a file, these arrive in a
+## `FileLinesRead` event.
+## - `ListDir` lists the entries in a directory, which arrive in a `DirListed`
+## event.
+## - `WriteFile` replaces the contents of a file, creating it if needed. The
+## contents are written to a temporary file which is then renamed over the
+## original, so a crash never leaves the file half written.
+## - `AppendFile` adds to the end of a file, creating it if needed.
+## - `RenameFile` moves a file, replacing anything already at `to`.
+## - `DeleteFile` removes a file.
+##
+## Files are read and written in the background so large files don't freeze
+## the UI. Writes, renames and deletes send a `FileDone` event once they have
+## finished. If anything goes wrong a `FileFailed` event says which path and why.
+##
+## - `SpawnProcess` runs a program in the background. Each line it writes to
+## stdout or stderr arrives in a `ProcessOutput` event tagged with the
+## process's id, followed by a `ProcessExited` event once it has finished.
+## - `WriteStdin` sends text to a running process's stdin, no newline is added.
+## - `CloseStdin` closes a process's stdin, for programs which read until the
+## end of their input.
+## - `KillProcess` stops a running process, it still sends `ProcessExited`.
+##
+## Any processes which are still running when the app quits are killed. If a
+## process can't be started, or the id isn't running, a `ProcessFailed` event
+## says why.
+##
+## - `CopyToClipboard` puts text on the system clipboard using the terminal's
+## OSC 52 support, so it works over SSH. Inside tmux it needs
+## `set -g allow-passthrough on`.
+## - `ReadClipboard` asks the terminal for the clipboard, which arrives in a
+## `ClipboardRead` event. Many terminals only allow this once it is turned on
+## in their settings, otherwise a `ClipboardUnavailable` event is sent.
+## - `SetTitle` sets the title of the terminal window or tab.
+## - `SetCursorStyle` changes the shape of the cursor and whether it blinks,
+## e.g. `SteadyBar` while inserting text. Where the cursor goes is set with
+## the `cursor` of a paragraph.
+##
+## The title and cursor are put back the way they were when the app exits.
+##
+## - `OpenEditor` lets the user edit some text in their own editor, which is
+## `VISUAL` or `EDITOR`. The app is hidden until the editor exits, then the
+## text arrives in an `Edited` event. If the editor exits with an error, such
+## as `:cq` in vim, an `EditCancelled` event is sent instead.
+Command : [
+    Quit U8,
+    SetBracketedPaste Bool,
+    SetFocusReporting Bool,
+    SetTickRate U64,
+    StartTimer TimerConfig,
+    CancelTimer Str,
+    ReadFile Str,
+    ReadFileLines ReadLinesConfig,
+    ListDir Str,
+    WriteFile FileContents,
+    AppendFile FileContents,
+    RenameFile RenameConfig,
+    DeleteFile Str,
+    SpawnProcess SpawnConfig,
+    WriteStdin ProcessInput,
+    CloseStdin Str,
+    KillProcess Str,
+    CopyToClipboard Str,
+    ReadClipboard,
+    SetTitle Str,
+    SetCursorStyle CursorStyle,
+    OpenEditor EditorConfig,
+]
+
+## A named timer, which keeps going off every `milliseconds` if `repeat` is set.
+##
+##     StartTimer { id: "refresh", milliseconds: 5000, repeat: Bool.true }
+##     StartTimer { id: "hideToast", milliseconds: 3000, repeat: Bool.false }
+TimerConfig : { id : Str, milliseconds : U64, repeat : Bool }
+
+## Read `count` lines starting from the zero based line `start`, which is handy
+## for paging through a large file.
+##
+##     ReadFileLines { path: "app.log", start: 100, count: 50 }
+ReadLinesConfig : { path : Str, start : Nat, count : Nat }
+
+RenameConfig : { from : Str, to : Str }
+
+## A program to run, `id` names it in the events it sends and must not be the
+## same as a process which is still running. The `env` variables are added to
+## the app's own environment, and an empty `cwd` uses the app's directory.
+##
+##     SpawnProcess { id: "build", command: "cargo", args: ["build"], env: [], cwd: "" }
+SpawnConfig : { id : Str, command : Str, args : List Str, env : List EnvVar, cwd : Str }
+
+##     WriteStdin { id: "repl", input: "1 + 1\n" }
+ProcessInput : { id : Str, input : Str }
+
+## `Default` is whatever the user has configured for their terminal.
+CursorStyle : [
+    Default,
+    BlinkingBlock,
+    SteadyBlock,
+    BlinkingUnderline,
+    SteadyUnderline,
+    BlinkingBar,
+    SteadyBar,
+]
+
+## The `id` is given back in the event for the edit, so an app can tell which
+## text was being edited.
+##
+##     OpenEditor { id: "message", text: model.message }
+EditorConfig : { id : Str, text : Str }
@@ -15,6 +15,15 @@ interface Elem
         Corner,
         Span,
         ListConfig,
+        TabsConfig,
+        TableConfig,
+        TableRow,
+        GaugeConfig,
+        LineGaugeConfig,
+        Progress,
+        SparklineConfig,
+        SparklineMax,
+        BarSet,
         BlockConfig,
         ScrollOffset,
         ParagraphConfig,
@@ -26,16 +35,26 @@ interface Elem
         unstyled,
         layout,
         list,
+        tabs,
+        table,
+        tableRow,
+        gauge,
+        lineGauge,
+        sparkline,
         styled,
     ]
     imports []
 
-# TODO roc glue can't support more elements in this recusrive tage here yet
 Elem : [
     Block BlockConfig,
     Paragraph ParagraphConfig,
     ListItems ListConfig,
     Layout (List Elem) LayoutConfig,
+    Tabs TabsConfig,
+    Table TableConfig,
+    Gauge GaugeConfig,
+    LineGauge LineGaugeConfig,
+    Sparkline SparklineConfig,
 ]
 
 ## Options to text in a span
@@ -60,6 +79,16 @@ Cursor : [Hidden, At CursorPosition]
 Corner : [TopLeft, TopRight, BottomRight, BottomLeft]
 ModalPosition : { percentX : U16, percentY : U16 }
 ListSelection : [None, Selected Nat]
+
+## How far along a gauge is, out of 100 or as a fraction. Values past the end
+## show a full gauge.
+Progress : [Percentage U16, Ratio U64 U64]
+
+## The value a full sparkline bar shows, `Auto` uses the largest data point
+SparklineMax : [Auto, Max U64]
+
+## How many steps a sparkline bar is drawn with within a single row
+BarSet : [NineLevels, ThreeLevels]
 PopupConfig : [
     None,
     Centered ModalPosition,
@@ -130,6 +159,112 @@ list :{
 list = \{ items ? [],selected ? None,block ? defaultBlock, style ? defaultStyle, highlightSymbol ? ">",highlightSymbolRepeat ? Bool.false, highlightStyle ? defaultStyle,startCorner ? TopLeft,   } -> 
     ListItems { items, selected, block, style, highlightSymbol, highlightSymbolRepeat, highlightStyle, startCorner, }
 
+## Create a tabs widget, which shows a row of titles with one highlighted
+##
+##     tabs {
+##         titles : [
+##             [unstyled "Inbox"],
+##             [unstyled "Sent"],
+##             [unstyled "Drafts"],
+##         ],
+##         selected : 1,
+##         block : blockConfig { borders : [ All ] },
+##         highlightStyle : st { fg : Yellow },
+##     }
+tabs :{
+        titles ? List Line,
+        selected ? Nat,
+        divider ? Span,
+        block ? BlockConfig,
+        style ? Style,
+        highlightStyle ? Style,
+    }
+    -> Elem
+tabs = \{ titles ? [], selected ? 0, divider ? unstyled "│", block ? defaultBlock, style ? defaultStyle, highlightStyle ? defaultStyle } ->
+    Tabs { titles, selected, divider, block, style, highlightStyle }
+
+## Create a table widget, with a row of cells for each item
+##
+##     table {
+##         header : tableRow [[unstyled "Name"], [unstyled "Size"]] { style : st { modifiers : [Bold] } },
+##         rows : [
+##             tableRow [[unstyled "main.roc"], [unstyled "2 KB"]] {},
+##             tableRow [[unstyled "README.md"], [unstyled "4 KB"]] {},
+##         ],
+##         widths : [Percentage 70, Percentage 30],
+##         selected : Selected 0,
+##         highlightStyle : st { fg : Blue },
+##     }
+table :{
+        header ? TableRow,
+        rows ? List TableRow,
+        widths ? List Constraint,
+        columnSpacing ? U16,
+        selected ? ListSelection,
+        block ? BlockConfig,
+        style ? Style,
+        highlightSymbol ? Str,
+        highlightStyle ? Style,
+    }
+    -> Elem
+table = \{ header ? { cells: [], style: defaultStyle }, rows ? [], widths ? [], columnSpacing ? 1, selected ? None, block ? defaultBlock, style ? defaultStyle, highlightSymbol ? "", highlightStyle ? defaultStyle } ->
+    Table { header, rows, widths, columnSpacing, selected, block, style, highlightSymbol, highlightStyle }
+
+tableRow : List Line, { style ? Style } -> TableRow
+tableRow = \cells, { style ? defaultStyle } -> { cells, style }
+
+## Create a gauge widget, which fills its area to show progress
+##
+##     gauge {
+##         progress : Ratio uploaded total,
+##         label : unstyled "Uploading",
+##         gaugeStyle : st { fg : Green },
+##     }
+gauge :{
+        progress ? Progress,
+        label ? Span,
+        block ? BlockConfig,
+        style ? Style,
+        gaugeStyle ? Style,
+        useUnicode ? Bool,
+    }
+    -> Elem
+gauge = \{ progress ? Percentage 0, label ? unstyled "", block ? defaultBlock, style ? defaultStyle, gaugeStyle ? defaultStyle, useUnicode ? Bool.false } ->
+    Gauge { progress, label, block, style, gaugeStyle, useUnicode }
+
+## Create a gauge widget which takes a single line
+##
+##     lineGauge { progress : Percentage 40, lineType : Thick }
+lineGauge :{
+        progress ? Progress,
+        label ? Span,
+        block ? BlockConfig,
+        style ? Style,
+        gaugeStyle ? Style,
+        lineType ? BorderType,
+    }
+    -> Elem
+lineGauge = \{ progress ? Percentage 0, label ? unstyled "", block ? defaultBlock, style ? defaultStyle, gaugeStyle ? defaultStyle, lineType ? Plain } ->
+    LineGauge { progress, label, block, style, gaugeStyle, lineType }
+
+## Create a sparkline widget, which shows data as a row of bars
+##
+##     sparkline {
+##         data : model.cpuHistory,
+##         max : Max 100,
+##         style : st { fg : Cyan },
+##     }
+sparkline :{
+        data ? List U64,
+        max ? SparklineMax,
+        block ? BlockConfig,
+        style ? Style,
+        barSet ? BarSet,
+    }
+    -> Elem
+sparkline = \{ data ? [], max ? Auto, block ? defaultBlock, style ? defaultStyle, barSet ? NineLevels } ->
+    Sparkline { data, max, block, style, barSet }
+
 blockConfig : { title ?Span, titleAlignment ?Alignment, style ?Style, borders ?List BorderModifier, borderStyle ?Style, borderType ?BorderType } -> BlockConfig
 blockConfig = \{ title ? { text: "", style: defaultStyle }, titleAlignment ? Left, style ? defaultStyle, borders ? [], borderStyle ? defaultStyle, borderType ? Plain } -> { title, titleAlignment, style, borders, borderStyle, borderType }
 
@@ -181,6 +316,65 @@ ListConfig : {
     startCorner : Corner,
 }
 
+# A widget to display a row of titles, with the selected one highlighted
+TabsConfig : {
+    titles : List Line,
+    selected : Nat,
+    divider : Span,
+    block : BlockConfig,
+    style : Style,
+    highlightStyle : Style,
+}
+
+# A widget to display rows of cells in columns, among which one row can be
+# selected (optional). A header with no cells isn't shown.
+TableConfig : {
+    header : TableRow,
+    rows : List TableRow,
+    widths : List Constraint,
+    columnSpacing : U16,
+    selected : ListSelection,
+    block : BlockConfig,
+    style : Style,
+    highlightSymbol : Str,
+    highlightStyle : Style,
+}
+
+## The cells of a table row, each of which is a line of text
+TableRow : { cells : List Line, style : Style }
+
+# A widget to display progress by filling in its area. The percentage is
+# shown when the label is empty.
+GaugeConfig : {
+    progress : Progress,
+    label : Span,
+    block : BlockConfig,
+    style : Style,
+    gaugeStyle : Style,
+    useUnicode : Bool,
+}
+
+# A widget to display progress as a line after its label. The percentage is
+# shown when the label is empty.
+LineGaugeConfig : {
+    progress : Progress,
+    label : Span,
+    block : BlockConfig,
+    style : Style,
+    gaugeStyle : Style,
+    lineType : BorderType,
+}
+
+# A widget to display data points as bars, which fits a lot of history in a
+# small space. Only as many points as there are columns are shown.
+SparklineConfig : {
+    data : List U64,
+    max : SparklineMax,
+    block : BlockConfig,
+    style : Style,
+    barSet : BarSet,
+}
+
 ## The following list of 16 base colors are available for almost all terminals;
 ## - `Light`, `Dark`, `DarkGrey`, `Black`, `Red`, `DarkRed`, `Green`, `DarkGreen`, 
 ## `Yellow`, `DarkYellow`, `Blue`, `DarkBlue`, `Magenta`, `DarkMagenta` `Cyan`, 
@@ -1,24 +1,216 @@
 interface Event
     exposes [ 
         KeyCode, 
+        KeyEvent,
+        KeyEventKind,
+        KeyModifier,
+        BackgroundColor,
         Bounds,
+        Capabilities,
+        ColorSupport,
+        DirContents,
+        DirEntry,
+        EditedText,
+        EditFailure,
+        EditorExit,
+        EnvVar,
         Event,
+        ExitStatus,
+        FileContents,
+        FileError,
+        FileFailure,
+        FileKind,
+        FileLines,
+        FileOperation,
+        FileSuccess,
+        TickInfo,
         MediaKeyCode,
         ModifierKeyCode,
+        MouseButton,
+        MouseEvent,
+        MouseEventKind,
+        OutputLine,
+        OutputStream,
+        ProcessExit,
+        ProcessFailure,
+        Startup,
     ]
     imports []
 
+## Something which happened that `update` can respond to.
+##
+## `Suspended` is sent when the user presses ctrl+z, just before the app stops
+## and the shell gets the terminal back. `Resumed` is sent once it is brought
+## back with `fg`, and the screen is redrawn.
 Event : [
-    KeyPressed KeyCode,
+    KeyPressed KeyEvent,
     FocusGained,
     FocusLost,
     Paste Str,
     Resize Bounds,
-    Tick, 
+    Tick TickInfo,
+    Mouse MouseEvent,
+    Timer Str,
+    FileRead FileContents,
+    FileLinesRead FileLines,
+    DirListed DirContents,
+    FileFailed FileFailure,
+    FileDone FileSuccess,
+    ProcessOutput OutputLine,
+    ProcessExited ProcessExit,
+    ProcessFailed ProcessFailure,
+    ClipboardRead Str,
+    ClipboardUnavailable,
+    Suspended,
+    Resumed,
+    Edited EditedText,
+    EditCancelled EditorExit,
+    EditFailed EditFailure,
 ]
 
 Bounds : { height : U16, width : U16 }
 
+## Everything `init` is told about how the app was launched. `args` starts
+## with the path of the program, followed by the arguments it was given.
+## `env` holds the environment variables sorted by name, and `cwd` is the
+## directory the app was started in.
+##
+##     init = \{ args, bounds } ->
+##         path = List.get args 1 |> Result.withDefault "todos.txt"
+Startup : {
+    args : List Str,
+    env : List EnvVar,
+    cwd : Str,
+    bounds : Bounds,
+    capabilities : Capabilities,
+}
+
+## What the terminal supports, detected when the app starts.
+##
+## - `colors` is how many colors can be shown. `Rgb` and `Indexed` colors
+## are changed to the nearest color the terminal supports when they are drawn,
+## and with `Monochrome` only the text modifiers are kept. Users can override
+## this with `ROC_TUI_COLORS`, or turn colors off by setting `NO_COLOR`.
+## - `unicode` is false when the locale isn't UTF-8, then box drawing and
+## other symbols may not show up properly.
+## - `background` is the terminal's background color if it told us, which is
+## handy for picking a light or dark theme.
+## - `keyboardEnhancement` is true when the terminal supports the kitty
+## keyboard protocol.
+Capabilities : {
+    colors : ColorSupport,
+    unicode : Bool,
+    background : BackgroundColor,
+    keyboardEnhancement : Bool,
+}
+
+ColorSupport : [Monochrome, Basic, Indexed, TrueColor]
+
+BackgroundColor : [Unknown, Rgb U8 U8 U8]
+
+EnvVar : { name : Str, value : Str }
+
+## Timing for a `Tick`, in milliseconds. `elapsed` is the time since the app
+## started and `delta` the time since the previous tick, both are measured
+## with a monotonic clock so they never go backwards. `wallClock` is the time
+## since the Unix epoch, this can jump if the system clock changes.
+##
+##     Tick { delta } -> { model & spinner: model.spinner + delta }
+TickInfo : { elapsed : U64, delta : U64, wallClock : U64 }
+
+FileContents : { path : Str, contents : Str }
+
+## The lines read by `ReadFileLines`, `start` is the zero based line number of
+## the first line. There are fewer lines than asked for near the end of a file.
+FileLines : { path : Str, start : Nat, lines : List Str }
+
+## The entries in a directory, sorted by name.
+DirContents : { path : Str, entries : List DirEntry }
+
+DirEntry : { name : Str, kind : FileKind }
+
+FileKind : [File, Dir, Symlink, Other]
+
+## A file command which finished without any problems. For `RenameFile` the
+## `path` is where the file was moved from.
+FileSuccess : { path : Str, operation : FileOperation }
+
+## Which file command failed on `path`, and why.
+##
+##     FileFailed { path } -> { model & status: "Unable to open \(path)" }
+FileFailure : { path : Str, operation : FileOperation, error : FileError }
+
+FileOperation : [Read, ListDir, Write, Append, Rename, Delete]
+
+FileError : [
+    NotFound,
+    PermissionDenied,
+    AlreadyExists,
+    DiskFull,
+    InvalidUtf8,
+    Other Str,
+]
+
+## A line written by a process started with `SpawnProcess`, without its line
+## ending. Output which isn't valid UTF-8 has the bad bytes replaced.
+##
+##     ProcessOutput { id: "build", stream: Stderr, line } ->
+##         { model & errors: List.append model.errors line }
+OutputLine : { id : Str, stream : OutputStream, line : Str }
+
+OutputStream : [Stdout, Stderr]
+
+## Sent once a process has exited and all of its output has been sent.
+ProcessExit : { id : Str, status : ExitStatus }
+
+## A process either exits with a `Code`, or on Unix can be stopped by a
+## `Signal` e.g. 9 after `KillProcess`.
+ExitStatus : [Code I32, Signal I32]
+
+## Why a process command failed, e.g. `NotFound` when the program doesn't exist
+## or no process with that id is running, and `AlreadyExists` when the id is
+## already in use.
+ProcessFailure : { id : Str, error : FileError }
+
+## The text after the user saved it and closed their editor.
+##
+##     Edited { id: "message", text } -> { model & message: Str.trim text }
+EditedText : { id : Str, text : Str }
+
+## The editor exited with an error, the text should be left as it was.
+EditorExit : { id : Str, status : ExitStatus }
+
+## Why the editor couldn't be opened, e.g. `NotFound` when it isn't installed.
+EditFailure : { id : Str, error : FileError }
+
+## A key press along with any modifier keys that were held down at the time.
+##
+##     KeyPressed { code, modifiers } ->
+##         if code == Scalar "s" && List.contains modifiers Control then
+##
+## Most terminals only report `Press`; `Repeat` and `Release` are only sent
+## by terminals which support keyboard enhancements.
+KeyEvent : { code : KeyCode, modifiers : List KeyModifier, kind : KeyEventKind }
+
+KeyModifier : [Shift, Control, Alt, Super, Hyper, Meta]
+
+KeyEventKind : [Press, Repeat, Release]
+
+## A mouse button press, release, drag, move or scroll. The `column` and `row`
+## are zero-based cell positions measured from the top left of the terminal.
+MouseEvent : { kind : MouseEventKind, column : U16, row : U16, modifiers : List KeyModifier }
+
+MouseEventKind : [
+    Down MouseButton,
+    Up MouseButton,
+    Drag MouseButton,
+    Moved,
+    ScrollDown,
+    ScrollUp,
+]
+
+MouseButton : [Left, Right, Middle]
+
 KeyCode : [
     Backspace,
     Enter,
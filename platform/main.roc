@@ -2,27 +2,35 @@
 platform "tui"
     requires { Model } { program : _ }
     exposes [
+        Command,
         Elem,
         Event,
     ]
     packages {}
     imports [
-        Event.{ Bounds, Event },
+        Command.{ Command },
+        Event.{ Event, Startup },
         Elem.{ Elem },
     ]
     provides [programForHost]
 
 programForHost : {
-    init : (Bounds -> Model) as Init,
-    update : (Model, Event -> Model) as Update,
+    # The commands come first so the host can find them without knowing the
+    # layout of the Model
+    init : (Startup -> [T (List Command) Model]) as Init,
+    update : (Model, Event -> [T (List Command) Model]) as Update,
     # The T (List Elem) Model is a workaround to stop use-after-free bug from 
     # Roc throwing away long strings on model re-render
     render : (Model -> [T (List Elem) Model]) as Render,
 }
 programForHost =
     {
-        init: program.init,
-        update: program.update,
+        init: \startup ->
+            { model, commands } = program.init startup
+            T commands model,
+        update: \model, event ->
+            { model: newModel, commands } = program.update model event
+            T commands newModel,
         render: \model ->
             elems = program.render model
             T elems model
@@ -34,7 +42,8 @@ programForHost =
 #     exposes [ Model]
 #     packages {}
 #     imports [
-#         Event.{ Bounds, Event },
+#         Command.{ Command },
+#         Event.{ Event, Startup },
 #         Elem.{ Elem },
 #     ]
 #     provides [programForHost]
@@ -42,8 +51,8 @@ programForHost =
 # Model : {}
 
 # programForHost : {
-#     init : (Bounds -> Model) as Init,
-#     update : (Model, Event -> Model) as Update,
+#     init : (Startup -> [T (List Command) Model]) as Init,
+#     update : (Model, Event -> [T (List Command) Model]) as Update,
 #     render : (Model -> List Elem) as Render,
 # }
 # programForHost = program
\ No newline at end of file
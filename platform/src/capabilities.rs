@@ -0,0 +1,149 @@
+use crate::glue;
+use crate::terminal;
+
+/// Every terminal answers the device attributes query, so the wait normally
+/// ends as soon as it replies. This only matters for terminals which don't.
+const QUERY_TIMEOUT_MS: u64 = 200;
+
+/// Work out what the terminal can do from the environment, and by asking it.
+/// This must run after the terminal is in raw mode, and before the event
+/// thread starts reading input.
+pub fn detect() -> glue::Capabilities {
+    // Ask for the background color and the keyboard enhancement flags, then
+    // for the primary device attributes. Terminals answer in order and skip
+    // queries they don't understand, so the last reply ends the wait.
+    let reply = terminal::query(
+        "\x1b]11;?\x07\x1b[?u\x1b[c",
+        std::time::Duration::from_millis(QUERY_TIMEOUT_MS),
+        |reply| has_csi_reply(reply, b'c'),
+    )
+    .unwrap_or_default();
+
+    glue::Capabilities {
+        background: get_background(&reply),
+        colors: get_color_support(),
+        keyboardEnhancement: has_csi_reply(&reply, b'u'),
+        unicode: supports_unicode(),
+    }
+}
+
+/// The `ROC_TUI_COLORS` environment variable overrides the detected colors
+/// with one of `monochrome`, `basic`, `indexed` or `truecolor`. Otherwise
+/// setting `NO_COLOR` turns colors off, see https://no-color.org
+fn get_color_support() -> glue::ColorSupport {
+    if let Ok(colors) = std::env::var("ROC_TUI_COLORS") {
+        match colors.trim().to_lowercase().as_str() {
+            "monochrome" | "none" => return glue::ColorSupport::Monochrome,
+            "basic" | "16" => return glue::ColorSupport::Basic,
+            "indexed" | "256" => return glue::ColorSupport::Indexed,
+            "truecolor" | "24bit" => return glue::ColorSupport::TrueColor,
+            "" => {}
+            _ => panic!("Unrecognised colors '{}' in ROC_TUI_COLORS", colors),
+        }
+    }
+
+    if std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+        return glue::ColorSupport::Monochrome;
+    }
+
+    detect_color_support()
+}
+
+/// Terminals advertise truecolor with `COLORTERM`, otherwise `TERM` says
+/// whether 256 colors are available
+fn detect_color_support() -> glue::ColorSupport {
+    let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+    let term = std::env::var("TERM").unwrap_or_default();
+
+    if colorterm == "truecolor" || colorterm == "24bit" || term.ends_with("-direct") {
+        glue::ColorSupport::TrueColor
+    } else if cfg!(windows) && std::env::var_os("WT_SESSION").is_some() {
+        // Windows Terminal supports truecolor but doesn't set COLORTERM
+        glue::ColorSupport::TrueColor
+    } else if term == "dumb" {
+        glue::ColorSupport::Monochrome
+    } else if term.contains("256color") {
+        glue::ColorSupport::Indexed
+    } else {
+        glue::ColorSupport::Basic
+    }
+}
+
+/// Box drawing and other symbols need a UTF-8 locale. The first of these
+/// variables which is set decides the locale, as it does for C programs.
+fn supports_unicode() -> bool {
+    if cfg!(windows) {
+        return true;
+    }
+
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .map(|locale| {
+            let locale = locale.to_lowercase();
+            locale.contains("utf-8") || locale.contains("utf8")
+        })
+        .unwrap_or(false)
+}
+
+/// The reply to OSC 11 looks like `11;rgb:RRRR/GGGG/BBBB`, where each
+/// component has between one and four hex digits
+fn get_background(reply: &[u8]) -> glue::BackgroundColor {
+    let rgb = terminal::get_osc_body(reply).and_then(|body| {
+        let color = body.strip_prefix("11;")?;
+        let color = color
+            .strip_prefix("rgb:")
+            .or_else(|| color.strip_prefix("rgba:"))?;
+
+        let mut components = color.split('/').map(|hex| {
+            let value = u32::from_str_radix(hex, 16).ok()?;
+            let max = 16u32.checked_pow(hex.len() as u32)?.checked_sub(1)?;
+            Some((value * 255 / max.max(1)) as u8)
+        });
+        Some((
+            components.next()??,
+            components.next()??,
+            components.next()??,
+        ))
+    });
+
+    match rgb {
+        Some((red, green, blue)) => glue::BackgroundColor::Rgb(red, green, blue),
+        None => glue::BackgroundColor::Unknown,
+    }
+}
+
+/// Whether the bytes hold a private CSI reply such as `ESC [ ? 1 ; 2 c`
+fn has_csi_reply(bytes: &[u8], final_byte: u8) -> bool {
+    bytes
+        .windows(3)
+        .enumerate()
+        .filter(|(_, w)| *w == b"\x1b[?")
+        .any(|(start, _)| {
+            bytes[start + 3..]
+                .iter()
+                .find(|byte| !(byte.is_ascii_digit() || **byte == b';'))
+                == Some(&final_byte)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn background_is_read_from_the_osc_11_reply() {
+        let reply = b"\x1b]11;rgb:ffff/8080/0000\x1b\\\x1b[?0u\x1b[?62;22c";
+        assert_eq!(
+            get_background(reply),
+            glue::BackgroundColor::Rgb(255, 128, 0)
+        );
+        assert!(has_csi_reply(reply, b'u'));
+        assert!(has_csi_reply(reply, b'c'));
+
+        let reply = b"\x1b[?1;2c";
+        assert_eq!(get_background(reply), glue::BackgroundColor::Unknown);
+        assert!(!has_csi_reply(reply, b'u'));
+    }
+}
@@ -0,0 +1,98 @@
+use crate::terminal;
+use std::io::Write;
+
+/// How long to wait for the terminal to send back the clipboard. Terminals
+/// which don't allow reading it never reply, and typing is lost while we wait.
+const READ_TIMEOUT_MS: u64 = 500;
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Set the system clipboard with an OSC 52 escape sequence. The terminal does
+/// the copying, so this works over SSH where the host has no clipboard.
+pub fn copy(text: &str) {
+    let sequence = format!("\x1b]52;c;{}\x07", encode_base64(text.as_bytes()));
+    let mut stdout = std::io::stdout();
+    stdout
+        .write_all(wrap_for_tmux(&sequence).as_bytes())
+        .and_then(|_| stdout.flush())
+        .expect("TODO handle unable to write to the clipboard");
+}
+
+/// Ask the terminal for the contents of the clipboard, `None` if it doesn't
+/// reply in time. Many terminals only allow this once it is turned on in
+/// their settings.
+pub fn read() -> Option<String> {
+    let timeout = std::time::Duration::from_millis(READ_TIMEOUT_MS);
+    let reply = terminal::query_osc(&wrap_for_tmux("\x1b]52;c;?\x07"), timeout)?;
+
+    // The reply looks like `52;c;<base64>`
+    let (_, data) = reply.strip_prefix("52;")?.split_once(';')?;
+    String::from_utf8(decode_base64(data)?).ok()
+}
+
+/// tmux passes escape sequences through to the outer terminal when they are
+/// wrapped in a DCS with every ESC doubled, this needs `allow-passthrough` on
+fn wrap_for_tmux(sequence: &str) -> String {
+    if std::env::var_os("TMUX").is_none() {
+        return sequence.to_string();
+    }
+
+    format!("\x1bPtmux;{}\x1b\\", sequence.replace('\x1b', "\x1b\x1b"))
+}
+
+fn encode_base64(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, byte)| n | u32::from(*byte) << (16 - 8 * i));
+
+        // A chunk of n bytes needs n + 1 characters, the rest is padding
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64[(n >> (18 - 6 * i) & 63) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+fn decode_base64(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len() / 4 * 3);
+    let mut n = 0u32;
+    let mut bits = 0;
+    for c in text.bytes().filter(|c| *c != b'=') {
+        let value = BASE64.iter().position(|b| *b == c)? as u32;
+        n = (n << 6 | value) & 0xffff;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((n >> bits) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_round_trips() {
+        for (text, encoded) in [
+            ("", ""),
+            ("h", "aA=="),
+            ("he", "aGU="),
+            ("hello", "aGVsbG8="),
+            ("héllo wörld", "aMOpbGxvIHfDtnJsZA=="),
+        ] {
+            assert_eq!(encode_base64(text.as_bytes()), encoded);
+            assert_eq!(decode_base64(encoded), Some(text.as_bytes().to_vec()));
+        }
+
+        assert_eq!(decode_base64("not base64!"), None);
+    }
+}
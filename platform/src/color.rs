@@ -0,0 +1,128 @@
+use crate::glue;
+use tui::style::Color;
+
+/// The basic colors in the order of their ANSI numbers, along with the RGB
+/// values xterm shows them as
+const BASIC: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// Each of red, green and blue takes one of these levels in the 6x6x6 color
+/// cube, which is indexes 16 to 231 of the 256 color palette
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// Change a color into the nearest one the terminal can show, or `None` when
+/// colors are turned off altogether
+pub fn downgrade(color: Color, support: glue::ColorSupport) -> Option<Color> {
+    match (support, color) {
+        (glue::ColorSupport::Monochrome, _) => None,
+        (glue::ColorSupport::Indexed, Color::Rgb(red, green, blue)) => {
+            Some(Color::Indexed(get_nearest_index((red, green, blue))))
+        }
+        (glue::ColorSupport::Basic, Color::Rgb(red, green, blue)) => {
+            Some(get_nearest_basic((red, green, blue)))
+        }
+        (glue::ColorSupport::Basic, Color::Indexed(index)) => {
+            Some(get_nearest_basic(get_index_rgb(index)))
+        }
+        _ => Some(color),
+    }
+}
+
+/// The nearest of the color cube and the gray ramp in the 256 color palette.
+/// The first 16 are skipped as terminals often change what they look like.
+fn get_nearest_index(rgb: (u8, u8, u8)) -> u8 {
+    let get_level = |value: u8| {
+        (0..CUBE_LEVELS.len())
+            .min_by_key(|i| CUBE_LEVELS[*i].abs_diff(value))
+            .unwrap_or(0) as u8
+    };
+    let cube = 16 + 36 * get_level(rgb.0) + 6 * get_level(rgb.1) + get_level(rgb.2);
+
+    // The gray ramp is indexes 232 to 255, which go from 8 to 238 in steps of 10
+    let average = (u32::from(rgb.0) + u32::from(rgb.1) + u32::from(rgb.2)) / 3;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+
+    if get_distance(rgb, get_index_rgb(gray)) < get_distance(rgb, get_index_rgb(cube)) {
+        gray
+    } else {
+        cube
+    }
+}
+
+fn get_nearest_basic(rgb: (u8, u8, u8)) -> Color {
+    BASIC
+        .iter()
+        .min_by_key(|(_, basic)| get_distance(rgb, *basic))
+        .map(|(color, _)| *color)
+        .unwrap_or(Color::Reset)
+}
+
+fn get_index_rgb(index: u8) -> (u8, u8, u8) {
+    match index {
+        0..=15 => BASIC[usize::from(index)].1,
+        16..=231 => {
+            let cube = usize::from(index - 16);
+            (
+                CUBE_LEVELS[cube / 36],
+                CUBE_LEVELS[cube / 6 % 6],
+                CUBE_LEVELS[cube % 6],
+            )
+        }
+        _ => {
+            let level = 8 + 10 * (index - 232);
+            (level, level, level)
+        }
+    }
+}
+
+fn get_distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let square = |x: u8, y: u8| u32::from(x.abs_diff(y)).pow(2);
+    square(a.0, b.0) + square(a.1, b.1) + square(a.2, b.2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn colors_downgrade_to_the_nearest_the_terminal_supports() {
+        let indexed = glue::ColorSupport::Indexed;
+        let basic = glue::ColorSupport::Basic;
+
+        assert_eq!(
+            downgrade(Color::Rgb(255, 0, 0), indexed),
+            Some(Color::Indexed(196))
+        );
+        assert_eq!(
+            downgrade(Color::Rgb(128, 128, 128), indexed),
+            Some(Color::Indexed(244))
+        );
+        assert_eq!(
+            downgrade(Color::Rgb(250, 10, 10), basic),
+            Some(Color::LightRed)
+        );
+        assert_eq!(downgrade(Color::Indexed(2), basic), Some(Color::Green));
+        assert_eq!(downgrade(Color::Indexed(244), basic), Some(Color::DarkGray));
+        assert_eq!(
+            downgrade(Color::Rgb(1, 2, 3), glue::ColorSupport::TrueColor),
+            Some(Color::Rgb(1, 2, 3))
+        );
+        assert_eq!(downgrade(Color::Red, glue::ColorSupport::Monochrome), None);
+    }
+}
@@ -0,0 +1,69 @@
+use std::io::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Keeps temporary file names unique when more than one edit is started
+static EDIT_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Used when neither `VISUAL` nor `EDITOR` is set
+const DEFAULT_EDITOR: &str = if cfg!(windows) { "notepad" } else { "vi" };
+
+pub enum Edit {
+    Saved(String),
+    /// The editor exited with an error, which is how editors like git's
+    /// expect the user to abandon an edit e.g. `:cq` in vim
+    Cancelled(std::process::ExitStatus),
+}
+
+/// Open the user's editor on a temporary file holding the text, and wait for
+/// it to exit. The terminal must already be given back to the shell, and
+/// nothing else may read input meanwhile.
+pub fn edit(text: &str) -> std::io::Result<Edit> {
+    let path = std::env::temp_dir().join(format!(
+        "roc-tui-{}-{}.txt",
+        std::process::id(),
+        EDIT_COUNT.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+
+    // The text may be private, and the temp directory is shared with other
+    // users, so only we may read it
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    let result = options
+        .open(&path)
+        .and_then(|mut file| file.write_all(text.as_bytes()))
+        .and_then(|_| run_editor(&path));
+
+    let _ = std::fs::remove_file(&path);
+    result
+}
+
+fn run_editor(path: &std::path::Path) -> std::io::Result<Edit> {
+    // The editor may come with arguments of its own e.g. `code --wait`
+    let editor = get_editor();
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or(DEFAULT_EDITOR);
+
+    let status = std::process::Command::new(program)
+        .args(words)
+        .arg(path)
+        .status()?;
+
+    if status.success() {
+        std::fs::read_to_string(path).map(Edit::Saved)
+    } else {
+        Ok(Edit::Cancelled(status))
+    }
+}
+
+/// `VISUAL` is the full screen editor, which is preferred over `EDITOR`
+fn get_editor() -> String {
+    ["VISUAL", "EDITOR"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|editor| !editor.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_EDITOR.to_string())
+}
@@ -0,0 +1,223 @@
+use crate::glue;
+use crate::ui::InputEvent;
+use roc_std::RocStr;
+use std::io::{BufRead, Write};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::Sender;
+use std::sync::{Mutex, OnceLock};
+
+/// Keeps temporary file names unique when the same file is written twice
+static WRITE_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+/// Work waiting for the file worker thread, see `in_background`
+static WORKER: OnceLock<Mutex<Sender<Job>>> = OnceLock::new();
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Read a whole file as text, then send a FileRead event
+pub fn read(path: String, tx: Sender<InputEvent>) {
+    in_background(tx, move || match std::fs::read_to_string(&path) {
+        Ok(contents) => InputEvent::FileRead { path, contents },
+        Err(error) => failed(path, glue::FileOperation::Read, error),
+    });
+}
+
+/// Read `count` lines starting from the zero based line `start`, then send a
+/// FileLinesRead event. Only the lines up to the end of the range are read.
+pub fn read_lines(path: String, start: usize, count: usize, tx: Sender<InputEvent>) {
+    in_background(tx, move || {
+        let lines = std::fs::File::open(&path).and_then(|file| {
+            std::io::BufReader::new(file)
+                .lines()
+                .skip(start)
+                .take(count)
+                .collect::<std::io::Result<Vec<String>>>()
+        });
+
+        match lines {
+            Ok(lines) => InputEvent::FileLinesRead { path, start, lines },
+            Err(error) => failed(path, glue::FileOperation::Read, error),
+        }
+    });
+}
+
+/// List the entries in a directory sorted by name, then send a DirListed event
+pub fn list_dir(path: String, tx: Sender<InputEvent>) {
+    in_background(tx, move || {
+        let entries = std::fs::read_dir(&path).and_then(|dir| {
+            dir.map(|entry| {
+                let entry = entry?;
+                let name = entry.file_name().to_string_lossy().into_owned();
+                Ok((name, get_file_kind(entry.file_type()?)))
+            })
+            .collect::<std::io::Result<Vec<(String, glue::FileKind)>>>()
+        });
+
+        match entries {
+            Ok(mut entries) => {
+                entries.sort();
+                InputEvent::DirListed { path, entries }
+            }
+            Err(error) => failed(path, glue::FileOperation::ListDir, error),
+        }
+    });
+}
+
+/// Replace the contents of a file, then send a FileDone event. The contents
+/// go to a temporary file in the same directory which is renamed into place,
+/// so the file is never left half written.
+pub fn write(path: String, contents: String, tx: Sender<InputEvent>) {
+    in_background(tx, move || {
+        let result = write_atomically(std::path::Path::new(&path), contents.as_bytes());
+        done(path, glue::FileOperation::Write, result)
+    });
+}
+
+/// Add to the end of a file, then send a FileDone event
+pub fn append(path: String, contents: String, tx: Sender<InputEvent>) {
+    in_background(tx, move || {
+        let result = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all(contents.as_bytes()));
+        done(path, glue::FileOperation::Append, result)
+    });
+}
+
+/// Move a file, then send a FileDone event for the path it was moved from
+pub fn rename(from: String, to: String, tx: Sender<InputEvent>) {
+    in_background(tx, move || {
+        let result = std::fs::rename(&from, &to);
+        done(from, glue::FileOperation::Rename, result)
+    });
+}
+
+/// Remove a file, then send a FileDone event
+pub fn delete(path: String, tx: Sender<InputEvent>) {
+    in_background(tx, move || {
+        let result = std::fs::remove_file(&path);
+        done(path, glue::FileOperation::Delete, result)
+    });
+}
+
+fn write_atomically(path: &std::path::Path, contents: &[u8]) -> std::io::Result<()> {
+    let file_name = path.file_name().ok_or_else(|| {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, "path has no file name")
+    })?;
+    let temp_path = path.with_file_name(format!(
+        ".{}.{}.{}.tmp",
+        file_name.to_string_lossy(),
+        std::process::id(),
+        WRITE_COUNT.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let result = std::fs::File::create(&temp_path).and_then(|mut file| {
+        file.write_all(contents)?;
+
+        // Keep the permissions of the file we are replacing
+        if let Ok(metadata) = std::fs::metadata(path) {
+            file.set_permissions(metadata.permissions())?;
+        }
+
+        file.sync_all()?;
+        std::fs::rename(&temp_path, path)
+    });
+
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp_path);
+    }
+
+    result
+}
+
+pub fn get_file_error(error: &std::io::Error) -> glue::FileError {
+    match error.kind() {
+        std::io::ErrorKind::NotFound => glue::FileError::NotFound,
+        std::io::ErrorKind::PermissionDenied => glue::FileError::PermissionDenied,
+        std::io::ErrorKind::AlreadyExists => glue::FileError::AlreadyExists,
+        // Reading text only reports invalid data when the file isn't UTF-8
+        std::io::ErrorKind::InvalidData => glue::FileError::InvalidUtf8,
+        _ if error.raw_os_error() == Some(libc::ENOSPC) => glue::FileError::DiskFull,
+        _ => glue::FileError::Other(RocStr::from(&error.to_string()[..])),
+    }
+}
+
+fn get_file_kind(file_type: std::fs::FileType) -> glue::FileKind {
+    if file_type.is_file() {
+        glue::FileKind::File
+    } else if file_type.is_dir() {
+        glue::FileKind::Dir
+    } else if file_type.is_symlink() {
+        glue::FileKind::Symlink
+    } else {
+        glue::FileKind::Other
+    }
+}
+
+fn done(path: String, operation: glue::FileOperation, result: std::io::Result<()>) -> InputEvent {
+    match result {
+        Ok(()) => InputEvent::FileDone { path, operation },
+        Err(error) => failed(path, operation, error),
+    }
+}
+
+fn failed(path: String, operation: glue::FileOperation, error: std::io::Error) -> InputEvent {
+    InputEvent::FileFailed {
+        path,
+        operation,
+        error,
+    }
+}
+
+/// Do the work on the file worker thread so the UI stays responsive, and send
+/// the resulting event back to the event loop. There is a single worker so
+/// commands finish in the order the app gave them, otherwise an older write
+/// could land after a newer one.
+fn in_background<F>(tx: Sender<InputEvent>, work: F)
+where
+    F: FnOnce() -> InputEvent + Send + 'static,
+{
+    let worker = WORKER.get_or_init(|| {
+        let (jobs, rx) = std::sync::mpsc::channel::<Job>();
+        std::thread::spawn(move || {
+            for job in rx {
+                job();
+            }
+        });
+        Mutex::new(jobs)
+    });
+
+    let job: Job = Box::new(move || {
+        // The app may have quit while we were busy, then there is nobody to tell
+        let _ = tx.send(work());
+    });
+    worker
+        .lock()
+        .expect("TODO handle the file worker lock being poisoned")
+        .send(job)
+        .expect("TODO handle the file worker thread stopping");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_finish_in_the_order_they_were_given() {
+        let path = std::env::temp_dir().join(format!("roc-tui-order-{}.txt", std::process::id()));
+        let path = path.to_string_lossy().into_owned();
+        let (tx, rx) = std::sync::mpsc::channel();
+
+        write(path.clone(), "A".repeat(1 << 20), tx.clone());
+        write(path.clone(), "B".to_string(), tx);
+
+        for _ in 0..2 {
+            let event = rx.recv().unwrap();
+            assert!(matches!(event, InputEvent::FileDone { .. }));
+        }
+        let contents = std::fs::read_to_string(&path);
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(contents.unwrap(), "B");
+    }
+}
@@ -28,18 +28,28 @@
 #[repr(u8)]
 pub enum discriminant_Elem {
     Block = 0,
-    Layout = 1,
-    ListItems = 2,
-    Paragraph = 3,
+    Gauge = 1,
+    Layout = 2,
+    LineGauge = 3,
+    ListItems = 4,
+    Paragraph = 5,
+    Sparkline = 6,
+    Table = 7,
+    Tabs = 8,
 }
 
 impl core::fmt::Debug for discriminant_Elem {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Block => f.write_str("discriminant_Elem::Block"),
+            Self::Gauge => f.write_str("discriminant_Elem::Gauge"),
             Self::Layout => f.write_str("discriminant_Elem::Layout"),
+            Self::LineGauge => f.write_str("discriminant_Elem::LineGauge"),
             Self::ListItems => f.write_str("discriminant_Elem::ListItems"),
             Self::Paragraph => f.write_str("discriminant_Elem::Paragraph"),
+            Self::Sparkline => f.write_str("discriminant_Elem::Sparkline"),
+            Self::Table => f.write_str("discriminant_Elem::Table"),
+            Self::Tabs => f.write_str("discriminant_Elem::Tabs"),
         }
     }
 }
@@ -65,12 +75,30 @@ pub struct Elem {
 #[repr(C)]
 union union_Elem {
     Block: core::mem::ManuallyDrop<Elem_Block>,
+    Gauge: core::mem::ManuallyDrop<Elem_Gauge>,
     Layout: core::mem::ManuallyDrop<Elem_Layout>,
+    LineGauge: core::mem::ManuallyDrop<Elem_LineGauge>,
     ListItems: core::mem::ManuallyDrop<Elem_ListItems>,
     Paragraph: core::mem::ManuallyDrop<Elem_Paragraph>,
+    Sparkline: core::mem::ManuallyDrop<Elem_Sparkline>,
+    Table: core::mem::ManuallyDrop<Elem_Table>,
+    Tabs: core::mem::ManuallyDrop<Elem_Tabs>,
     _sizer: [u8; 4],
 }
 
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[repr(C)]
+struct tagged_union_Elem {
+    union: union_Elem,
+    discriminant: discriminant_Elem,
+}
+
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
@@ -95,23 +123,57 @@ pub struct Span {
 #[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(u8)]
 pub enum discriminant_Event {
-    FocusGained = 0,
-    FocusLost = 1,
-    KeyPressed = 2,
-    Paste = 3,
-    Resize = 4,
-    Tick = 5,
+    ClipboardRead = 0,
+    ClipboardUnavailable = 1,
+    DirListed = 2,
+    EditCancelled = 3,
+    EditFailed = 4,
+    Edited = 5,
+    FileDone = 6,
+    FileFailed = 7,
+    FileLinesRead = 8,
+    FileRead = 9,
+    FocusGained = 10,
+    FocusLost = 11,
+    KeyPressed = 12,
+    Mouse = 13,
+    Paste = 14,
+    ProcessExited = 15,
+    ProcessFailed = 16,
+    ProcessOutput = 17,
+    Resize = 18,
+    Resumed = 19,
+    Suspended = 20,
+    Tick = 21,
+    Timer = 22,
 }
 
 impl core::fmt::Debug for discriminant_Event {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
+            Self::ClipboardRead => f.write_str("discriminant_Event::ClipboardRead"),
+            Self::ClipboardUnavailable => f.write_str("discriminant_Event::ClipboardUnavailable"),
+            Self::DirListed => f.write_str("discriminant_Event::DirListed"),
+            Self::EditCancelled => f.write_str("discriminant_Event::EditCancelled"),
+            Self::EditFailed => f.write_str("discriminant_Event::EditFailed"),
+            Self::Edited => f.write_str("discriminant_Event::Edited"),
+            Self::FileDone => f.write_str("discriminant_Event::FileDone"),
+            Self::FileFailed => f.write_str("discriminant_Event::FileFailed"),
+            Self::FileLinesRead => f.write_str("discriminant_Event::FileLinesRead"),
+            Self::FileRead => f.write_str("discriminant_Event::FileRead"),
             Self::FocusGained => f.write_str("discriminant_Event::FocusGained"),
             Self::FocusLost => f.write_str("discriminant_Event::FocusLost"),
             Self::KeyPressed => f.write_str("discriminant_Event::KeyPressed"),
+            Self::Mouse => f.write_str("discriminant_Event::Mouse"),
             Self::Paste => f.write_str("discriminant_Event::Paste"),
+            Self::ProcessExited => f.write_str("discriminant_Event::ProcessExited"),
+            Self::ProcessFailed => f.write_str("discriminant_Event::ProcessFailed"),
+            Self::ProcessOutput => f.write_str("discriminant_Event::ProcessOutput"),
             Self::Resize => f.write_str("discriminant_Event::Resize"),
+            Self::Resumed => f.write_str("discriminant_Event::Resumed"),
+            Self::Suspended => f.write_str("discriminant_Event::Suspended"),
             Self::Tick => f.write_str("discriminant_Event::Tick"),
+            Self::Timer => f.write_str("discriminant_Event::Timer"),
         }
     }
 }
@@ -123,10 +185,25 @@ impl core::fmt::Debug for discriminant_Event {
 ))]
 #[repr(C)]
 pub union Event {
-    KeyPressed: core::mem::ManuallyDrop<KeyCode>,
+    ClipboardRead: core::mem::ManuallyDrop<roc_std::RocStr>,
+    DirListed: core::mem::ManuallyDrop<DirContents>,
+    EditCancelled: core::mem::ManuallyDrop<EditorExit>,
+    EditFailed: core::mem::ManuallyDrop<EditFailure>,
+    Edited: core::mem::ManuallyDrop<EditedText>,
+    FileDone: core::mem::ManuallyDrop<FileSuccess>,
+    FileFailed: core::mem::ManuallyDrop<FileFailure>,
+    FileLinesRead: core::mem::ManuallyDrop<FileLines>,
+    FileRead: core::mem::ManuallyDrop<FileContents>,
+    KeyPressed: core::mem::ManuallyDrop<KeyEvent>,
+    Mouse: core::mem::ManuallyDrop<MouseEvent>,
     Paste: core::mem::ManuallyDrop<roc_std::RocStr>,
+    ProcessExited: core::mem::ManuallyDrop<ProcessExit>,
+    ProcessFailed: core::mem::ManuallyDrop<ProcessFailure>,
+    ProcessOutput: core::mem::ManuallyDrop<OutputLine>,
     Resize: Bounds,
-    _sizer: [u8; 20],
+    Tick: TickInfo,
+    Timer: core::mem::ManuallyDrop<roc_std::RocStr>,
+    _sizer: [u8; 40],
 }
 
 #[cfg(any(
@@ -257,6 +334,132 @@ pub struct Bounds {
     pub width: u16,
 }
 
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct Startup {
+    pub args: roc_std::RocList<roc_std::RocStr>,
+    pub cwd: roc_std::RocStr,
+    pub env: roc_std::RocList<EnvVar>,
+    pub bounds: Bounds,
+    pub capabilities: Capabilities,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct Capabilities {
+    pub background: BackgroundColor,
+    pub colors: ColorSupport,
+    pub keyboardEnhancement: bool,
+    pub unicode: bool,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum ColorSupport {
+    Basic = 0,
+    Indexed = 1,
+    Monochrome = 2,
+    TrueColor = 3,
+}
+
+impl core::fmt::Debug for ColorSupport {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Basic => f.write_str("ColorSupport::Basic"),
+            Self::Indexed => f.write_str("ColorSupport::Indexed"),
+            Self::Monochrome => f.write_str("ColorSupport::Monochrome"),
+            Self::TrueColor => f.write_str("ColorSupport::TrueColor"),
+        }
+    }
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum discriminant_BackgroundColor {
+    Rgb = 0,
+    Unknown = 1,
+}
+
+impl core::fmt::Debug for discriminant_BackgroundColor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Rgb => f.write_str("discriminant_BackgroundColor::Rgb"),
+            Self::Unknown => f.write_str("discriminant_BackgroundColor::Unknown"),
+        }
+    }
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[repr(C)]
+pub union BackgroundColor {
+    Rgb: BackgroundColor_Rgb,
+    _sizer: [u8; 4],
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+struct BackgroundColor_Rgb {
+    pub f0: u8,
+    pub f1: u8,
+    pub f2: u8,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TickInfo {
+    pub delta: u64,
+    pub elapsed: u64,
+    pub wallClock: u64,
+}
+
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
@@ -272,6 +475,21 @@ pub struct R1 {
     // pub update: TODO_roc_function_67,
 }
 
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct KeyEvent {
+    pub code: KeyCode,
+    pub modifiers: roc_std::RocList<KeyModifier>,
+    pub kind: KeyEventKind,
+}
+
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
@@ -458,27 +676,28 @@ impl core::fmt::Debug for MediaKeyCode {
     target_arch = "x86",
     target_arch = "x86_64"
 ))]
-#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
-#[repr(transparent)]
-struct Elem_Paragraph {
-    pub f0: ParagraphConfig,
+#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum KeyModifier {
+    Alt = 0,
+    Control = 1,
+    Hyper = 2,
+    Meta = 3,
+    Shift = 4,
+    Super = 5,
 }
 
-#[cfg(any(
-    target_arch = "arm",
-    target_arch = "aarch64",
-    target_arch = "wasm32",
-    target_arch = "x86",
-    target_arch = "x86_64"
-))]
-#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
-#[repr(C)]
-pub struct ParagraphConfig {
-    pub block: BlockConfig,
-    pub text: roc_std::RocList<roc_std::RocList<Span>>,
-    pub cursor: Cursor,
-    pub scroll: u16,
-    pub textAlignment: Alignment,
+impl core::fmt::Debug for KeyModifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Alt => f.write_str("KeyModifier::Alt"),
+            Self::Control => f.write_str("KeyModifier::Control"),
+            Self::Hyper => f.write_str("KeyModifier::Hyper"),
+            Self::Meta => f.write_str("KeyModifier::Meta"),
+            Self::Shift => f.write_str("KeyModifier::Shift"),
+            Self::Super => f.write_str("KeyModifier::Super"),
+        }
+    }
 }
 
 #[cfg(any(
@@ -490,16 +709,18 @@ pub struct ParagraphConfig {
 ))]
 #[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(u8)]
-pub enum discriminant_Cursor {
-    At = 0,
-    Hidden = 1,
+pub enum KeyEventKind {
+    Press = 0,
+    Release = 1,
+    Repeat = 2,
 }
 
-impl core::fmt::Debug for discriminant_Cursor {
+impl core::fmt::Debug for KeyEventKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            Self::At => f.write_str("discriminant_Cursor::At"),
-            Self::Hidden => f.write_str("discriminant_Cursor::Hidden"),
+            Self::Press => f.write_str("KeyEventKind::Press"),
+            Self::Release => f.write_str("KeyEventKind::Release"),
+            Self::Repeat => f.write_str("KeyEventKind::Repeat"),
         }
     }
 }
@@ -511,10 +732,13 @@ impl core::fmt::Debug for discriminant_Cursor {
     target_arch = "x86",
     target_arch = "x86_64"
 ))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
-pub union Cursor {
-    At: CursorPosition,
-    _sizer: [u8; 6],
+pub struct MouseEvent {
+    pub modifiers: roc_std::RocList<KeyModifier>,
+    pub column: u16,
+    pub row: u16,
+    pub kind: MouseEventKind,
 }
 
 #[cfg(any(
@@ -524,24 +748,28 @@ pub union Cursor {
     target_arch = "x86",
     target_arch = "x86_64"
 ))]
-#[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
-#[repr(C)]
-pub struct CursorPosition {
-    pub col: u16,
-    pub row: u16,
+#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum discriminant_MouseEventKind {
+    Down = 0,
+    Drag = 1,
+    Moved = 2,
+    ScrollDown = 3,
+    ScrollUp = 4,
+    Up = 5,
 }
 
-#[cfg(any(
-    target_arch = "arm",
-    target_arch = "aarch64",
-    target_arch = "wasm32",
-    target_arch = "x86",
-    target_arch = "x86_64"
-))]
-#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
-#[repr(transparent)]
-struct Elem_ListItems {
-    pub f0: ListConfig,
+impl core::fmt::Debug for discriminant_MouseEventKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Down => f.write_str("discriminant_MouseEventKind::Down"),
+            Self::Drag => f.write_str("discriminant_MouseEventKind::Drag"),
+            Self::Moved => f.write_str("discriminant_MouseEventKind::Moved"),
+            Self::ScrollDown => f.write_str("discriminant_MouseEventKind::ScrollDown"),
+            Self::ScrollUp => f.write_str("discriminant_MouseEventKind::ScrollUp"),
+            Self::Up => f.write_str("discriminant_MouseEventKind::Up"),
+        }
+    }
 }
 
 #[cfg(any(
@@ -551,17 +779,12 @@ struct Elem_ListItems {
     target_arch = "x86",
     target_arch = "x86_64"
 ))]
-#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
-pub struct ListConfig {
-    pub block: BlockConfig,
-    pub highlightStyle: Style,
-    pub highlightSymbol: roc_std::RocStr,
-    pub items: roc_std::RocList<roc_std::RocList<Span>>,
-    pub selected: ListSelection,
-    pub style: Style,
-    pub highlightSymbolRepeat: bool,
-    pub startCorner: Corner,
+pub union MouseEventKind {
+    Down: MouseButton,
+    Drag: MouseButton,
+    Up: MouseButton,
+    _sizer: [u8; 2],
 }
 
 #[cfg(any(
@@ -573,20 +796,18 @@ pub struct ListConfig {
 ))]
 #[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(u8)]
-pub enum Corner {
-    BottomLeft = 0,
-    BottomRight = 1,
-    TopLeft = 2,
-    TopRight = 3,
+pub enum MouseButton {
+    Left = 0,
+    Middle = 1,
+    Right = 2,
 }
 
-impl core::fmt::Debug for Corner {
+impl core::fmt::Debug for MouseButton {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            Self::BottomLeft => f.write_str("Corner::BottomLeft"),
-            Self::BottomRight => f.write_str("Corner::BottomRight"),
-            Self::TopLeft => f.write_str("Corner::TopLeft"),
-            Self::TopRight => f.write_str("Corner::TopRight"),
+            Self::Left => f.write_str("MouseButton::Left"),
+            Self::Middle => f.write_str("MouseButton::Middle"),
+            Self::Right => f.write_str("MouseButton::Right"),
         }
     }
 }
@@ -600,16 +821,56 @@ impl core::fmt::Debug for Corner {
 ))]
 #[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(u8)]
-pub enum discriminant_ListSelection {
-    None = 0,
-    Selected = 1,
+pub enum discriminant_Command {
+    AppendFile = 0,
+    CancelTimer = 1,
+    CloseStdin = 2,
+    CopyToClipboard = 3,
+    DeleteFile = 4,
+    KillProcess = 5,
+    ListDir = 6,
+    OpenEditor = 7,
+    Quit = 8,
+    ReadClipboard = 9,
+    ReadFile = 10,
+    ReadFileLines = 11,
+    RenameFile = 12,
+    SetBracketedPaste = 13,
+    SetCursorStyle = 14,
+    SetFocusReporting = 15,
+    SetTickRate = 16,
+    SetTitle = 17,
+    SpawnProcess = 18,
+    StartTimer = 19,
+    WriteFile = 20,
+    WriteStdin = 21,
 }
 
-impl core::fmt::Debug for discriminant_ListSelection {
+impl core::fmt::Debug for discriminant_Command {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            Self::None => f.write_str("discriminant_ListSelection::None"),
-            Self::Selected => f.write_str("discriminant_ListSelection::Selected"),
+            Self::AppendFile => f.write_str("discriminant_Command::AppendFile"),
+            Self::CancelTimer => f.write_str("discriminant_Command::CancelTimer"),
+            Self::CloseStdin => f.write_str("discriminant_Command::CloseStdin"),
+            Self::CopyToClipboard => f.write_str("discriminant_Command::CopyToClipboard"),
+            Self::DeleteFile => f.write_str("discriminant_Command::DeleteFile"),
+            Self::KillProcess => f.write_str("discriminant_Command::KillProcess"),
+            Self::ListDir => f.write_str("discriminant_Command::ListDir"),
+            Self::OpenEditor => f.write_str("discriminant_Command::OpenEditor"),
+            Self::Quit => f.write_str("discriminant_Command::Quit"),
+            Self::ReadClipboard => f.write_str("discriminant_Command::ReadClipboard"),
+            Self::ReadFile => f.write_str("discriminant_Command::ReadFile"),
+            Self::ReadFileLines => f.write_str("discriminant_Command::ReadFileLines"),
+            Self::RenameFile => f.write_str("discriminant_Command::RenameFile"),
+            Self::SetBracketedPaste => f.write_str("discriminant_Command::SetBracketedPaste"),
+            Self::SetCursorStyle => f.write_str("discriminant_Command::SetCursorStyle"),
+            Self::SetFocusReporting => f.write_str("discriminant_Command::SetFocusReporting"),
+            Self::SetTickRate => f.write_str("discriminant_Command::SetTickRate"),
+            Self::SetTitle => f.write_str("discriminant_Command::SetTitle"),
+            Self::SpawnProcess => f.write_str("discriminant_Command::SpawnProcess"),
+            Self::StartTimer => f.write_str("discriminant_Command::StartTimer"),
+            Self::WriteFile => f.write_str("discriminant_Command::WriteFile"),
+            Self::WriteStdin => f.write_str("discriminant_Command::WriteStdin"),
         }
     }
 }
@@ -620,9 +881,55 @@ impl core::fmt::Debug for discriminant_ListSelection {
     target_arch = "x86"
 ))]
 #[repr(C)]
-pub union ListSelection {
-    Selected: u32,
-    _sizer: [u8; 8],
+pub union Command {
+    AppendFile: core::mem::ManuallyDrop<FileContents>,
+    CancelTimer: core::mem::ManuallyDrop<roc_std::RocStr>,
+    CloseStdin: core::mem::ManuallyDrop<roc_std::RocStr>,
+    CopyToClipboard: core::mem::ManuallyDrop<roc_std::RocStr>,
+    DeleteFile: core::mem::ManuallyDrop<roc_std::RocStr>,
+    KillProcess: core::mem::ManuallyDrop<roc_std::RocStr>,
+    ListDir: core::mem::ManuallyDrop<roc_std::RocStr>,
+    OpenEditor: core::mem::ManuallyDrop<EditorConfig>,
+    Quit: u8,
+    ReadFile: core::mem::ManuallyDrop<roc_std::RocStr>,
+    ReadFileLines: core::mem::ManuallyDrop<ReadLinesConfig>,
+    RenameFile: core::mem::ManuallyDrop<RenameConfig>,
+    SetBracketedPaste: bool,
+    SetCursorStyle: CursorStyle,
+    SetFocusReporting: bool,
+    SetTickRate: u64,
+    SetTitle: core::mem::ManuallyDrop<roc_std::RocStr>,
+    SpawnProcess: core::mem::ManuallyDrop<SpawnConfig>,
+    StartTimer: core::mem::ManuallyDrop<TimerConfig>,
+    WriteFile: core::mem::ManuallyDrop<FileContents>,
+    WriteStdin: core::mem::ManuallyDrop<ProcessInput>,
+    _sizer: [u8; 64],
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "wasm32",
+    target_arch = "x86"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TimerConfig {
+    pub milliseconds: u64,
+    pub id: roc_std::RocStr,
+    pub repeat: bool,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "wasm32",
+    target_arch = "x86"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct ReadLinesConfig {
+    pub count: u32,
+    pub path: roc_std::RocStr,
+    pub start: u32,
 }
 
 #[cfg(any(
@@ -634,9 +941,22 @@ pub union ListSelection {
 ))]
 #[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
-struct Elem_Layout {
-    pub f0: roc_std::RocList<Elem>,
-    pub f1: LayoutConfig,
+pub struct FileContents {
+    pub contents: roc_std::RocStr,
+    pub path: roc_std::RocStr,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "wasm32",
+    target_arch = "x86"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct FileLines {
+    pub lines: roc_std::RocList<roc_std::RocStr>,
+    pub path: roc_std::RocStr,
+    pub start: u32,
 }
 
 #[cfg(any(
@@ -648,12 +968,23 @@ struct Elem_Layout {
 ))]
 #[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
-pub struct LayoutConfig {
-    pub constraints: roc_std::RocList<Constraint>,
-    pub hMargin: u16,
-    pub popup: PopupConfig,
-    pub vMargin: u16,
-    pub direction: LayoutDirection,
+pub struct DirContents {
+    pub entries: roc_std::RocList<DirEntry>,
+    pub path: roc_std::RocStr,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct DirEntry {
+    pub name: roc_std::RocStr,
+    pub kind: FileKind,
 }
 
 #[cfg(any(
@@ -665,16 +996,20 @@ pub struct LayoutConfig {
 ))]
 #[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(u8)]
-pub enum LayoutDirection {
-    Horizontal = 0,
-    Vertical = 1,
+pub enum FileKind {
+    Dir = 0,
+    File = 1,
+    Other = 2,
+    Symlink = 3,
 }
 
-impl core::fmt::Debug for LayoutDirection {
+impl core::fmt::Debug for FileKind {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            Self::Horizontal => f.write_str("LayoutDirection::Horizontal"),
-            Self::Vertical => f.write_str("LayoutDirection::Vertical"),
+            Self::Dir => f.write_str("FileKind::Dir"),
+            Self::File => f.write_str("FileKind::File"),
+            Self::Other => f.write_str("FileKind::Other"),
+            Self::Symlink => f.write_str("FileKind::Symlink"),
         }
     }
 }
@@ -686,18 +1021,41 @@ impl core::fmt::Debug for LayoutDirection {
     target_arch = "x86",
     target_arch = "x86_64"
 ))]
-#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct FileFailure {
+    pub error: FileError,
+    pub path: roc_std::RocStr,
+    pub operation: FileOperation,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(u8)]
-pub enum discriminant_PopupConfig {
-    Centered = 0,
-    None = 1,
+pub enum FileOperation {
+    Append = 0,
+    Delete = 1,
+    ListDir = 2,
+    Read = 3,
+    Rename = 4,
+    Write = 5,
 }
 
-impl core::fmt::Debug for discriminant_PopupConfig {
+impl core::fmt::Debug for FileOperation {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            Self::Centered => f.write_str("discriminant_PopupConfig::Centered"),
-            Self::None => f.write_str("discriminant_PopupConfig::None"),
+            Self::Append => f.write_str("FileOperation::Append"),
+            Self::Delete => f.write_str("FileOperation::Delete"),
+            Self::ListDir => f.write_str("FileOperation::ListDir"),
+            Self::Read => f.write_str("FileOperation::Read"),
+            Self::Rename => f.write_str("FileOperation::Rename"),
+            Self::Write => f.write_str("FileOperation::Write"),
         }
     }
 }
@@ -709,10 +1067,39 @@ impl core::fmt::Debug for discriminant_PopupConfig {
     target_arch = "x86",
     target_arch = "x86_64"
 ))]
+#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum discriminant_FileError {
+    AlreadyExists = 0,
+    DiskFull = 1,
+    InvalidUtf8 = 2,
+    NotFound = 3,
+    Other = 4,
+    PermissionDenied = 5,
+}
+
+impl core::fmt::Debug for discriminant_FileError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::AlreadyExists => f.write_str("discriminant_FileError::AlreadyExists"),
+            Self::DiskFull => f.write_str("discriminant_FileError::DiskFull"),
+            Self::InvalidUtf8 => f.write_str("discriminant_FileError::InvalidUtf8"),
+            Self::NotFound => f.write_str("discriminant_FileError::NotFound"),
+            Self::Other => f.write_str("discriminant_FileError::Other"),
+            Self::PermissionDenied => f.write_str("discriminant_FileError::PermissionDenied"),
+        }
+    }
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "wasm32",
+    target_arch = "x86"
+))]
 #[repr(C)]
-pub union PopupConfig {
-    Centered: ModalPosition,
-    _sizer: [u8; 6],
+pub union FileError {
+    Other: core::mem::ManuallyDrop<roc_std::RocStr>,
+    _sizer: [u8; 16],
 }
 
 #[cfg(any(
@@ -722,11 +1109,11 @@ pub union PopupConfig {
     target_arch = "x86",
     target_arch = "x86_64"
 ))]
-#[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
-pub struct ModalPosition {
-    pub percentX: u16,
-    pub percentY: u16,
+pub struct RenameConfig {
+    pub from: roc_std::RocStr,
+    pub to: roc_std::RocStr,
 }
 
 #[cfg(any(
@@ -736,11 +1123,11 @@ pub struct ModalPosition {
     target_arch = "x86",
     target_arch = "x86_64"
 ))]
-#[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
-struct Constraint_Ratio {
-    pub f0: u32,
-    pub f1: u32,
+pub struct FileSuccess {
+    pub path: roc_std::RocStr,
+    pub operation: FileOperation,
 }
 
 #[cfg(any(
@@ -751,9 +1138,13 @@ struct Constraint_Ratio {
     target_arch = "x86_64"
 ))]
 #[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
-#[repr(transparent)]
-struct Elem_Block {
-    pub f0: BlockConfig,
+#[repr(C)]
+pub struct SpawnConfig {
+    pub args: roc_std::RocList<roc_std::RocStr>,
+    pub command: roc_std::RocStr,
+    pub cwd: roc_std::RocStr,
+    pub env: roc_std::RocList<EnvVar>,
+    pub id: roc_std::RocStr,
 }
 
 #[cfg(any(
@@ -765,13 +1156,38 @@ struct Elem_Block {
 ))]
 #[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
-pub struct BlockConfig {
-    pub borderStyle: Style,
-    pub borders: roc_std::RocList<BorderModifier>,
-    pub style: Style,
-    pub title: Span,
-    pub borderType: BorderType,
-    pub titleAlignment: Alignment,
+pub struct EnvVar {
+    pub name: roc_std::RocStr,
+    pub value: roc_std::RocStr,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct ProcessInput {
+    pub id: roc_std::RocStr,
+    pub input: roc_std::RocStr,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct OutputLine {
+    pub id: roc_std::RocStr,
+    pub line: roc_std::RocStr,
+    pub stream: OutputStream,
 }
 
 #[cfg(any(
@@ -783,22 +1199,34 @@ pub struct BlockConfig {
 ))]
 #[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(u8)]
-pub enum Alignment {
-    Center = 0,
-    Left = 1,
-    Right = 2,
+pub enum OutputStream {
+    Stderr = 0,
+    Stdout = 1,
 }
 
-impl core::fmt::Debug for Alignment {
+impl core::fmt::Debug for OutputStream {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            Self::Center => f.write_str("Alignment::Center"),
-            Self::Left => f.write_str("Alignment::Left"),
-            Self::Right => f.write_str("Alignment::Right"),
+            Self::Stderr => f.write_str("OutputStream::Stderr"),
+            Self::Stdout => f.write_str("OutputStream::Stdout"),
         }
     }
 }
 
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct ProcessExit {
+    pub id: roc_std::RocStr,
+    pub status: ExitStatus,
+}
+
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
@@ -808,24 +1236,34 @@ impl core::fmt::Debug for Alignment {
 ))]
 #[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(u8)]
-pub enum BorderType {
-    Double = 0,
-    Plain = 1,
-    Rounded = 2,
-    Thick = 3,
+pub enum discriminant_ExitStatus {
+    Code = 0,
+    Signal = 1,
 }
 
-impl core::fmt::Debug for BorderType {
+impl core::fmt::Debug for discriminant_ExitStatus {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            Self::Double => f.write_str("BorderType::Double"),
-            Self::Plain => f.write_str("BorderType::Plain"),
-            Self::Rounded => f.write_str("BorderType::Rounded"),
-            Self::Thick => f.write_str("BorderType::Thick"),
+            Self::Code => f.write_str("discriminant_ExitStatus::Code"),
+            Self::Signal => f.write_str("discriminant_ExitStatus::Signal"),
         }
     }
 }
 
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[repr(C)]
+pub union ExitStatus {
+    Code: i32,
+    Signal: i32,
+    _sizer: [u8; 8],
+}
+
 #[cfg(any(
     target_arch = "arm",
     target_arch = "aarch64",
@@ -835,10 +1273,9 @@ impl core::fmt::Debug for BorderType {
 ))]
 #[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
-pub struct Style {
-    pub modifiers: roc_std::RocList<TextModifier>,
-    pub bg: Color,
-    pub fg: Color,
+pub struct ProcessFailure {
+    pub error: FileError,
+    pub id: roc_std::RocStr,
 }
 
 #[cfg(any(
@@ -850,50 +1287,26 @@ pub struct Style {
 ))]
 #[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(u8)]
-pub enum discriminant_Color {
-    Black = 0,
-    Blue = 1,
-    Cyan = 2,
-    DarkGray = 3,
-    Default = 4,
-    Gray = 5,
-    Green = 6,
-    Indexed = 7,
-    LightBlue = 8,
-    LightCyan = 9,
-    LightGreen = 10,
-    LightMagenta = 11,
-    LightRed = 12,
-    LightYellow = 13,
-    Magenta = 14,
-    Red = 15,
-    Rgb = 16,
-    White = 17,
-    Yellow = 18,
+pub enum CursorStyle {
+    BlinkingBar = 0,
+    BlinkingBlock = 1,
+    BlinkingUnderline = 2,
+    Default = 3,
+    SteadyBar = 4,
+    SteadyBlock = 5,
+    SteadyUnderline = 6,
 }
 
-impl core::fmt::Debug for discriminant_Color {
+impl core::fmt::Debug for CursorStyle {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
-            Self::Black => f.write_str("discriminant_Color::Black"),
-            Self::Blue => f.write_str("discriminant_Color::Blue"),
-            Self::Cyan => f.write_str("discriminant_Color::Cyan"),
-            Self::DarkGray => f.write_str("discriminant_Color::DarkGray"),
-            Self::Default => f.write_str("discriminant_Color::Default"),
-            Self::Gray => f.write_str("discriminant_Color::Gray"),
-            Self::Green => f.write_str("discriminant_Color::Green"),
-            Self::Indexed => f.write_str("discriminant_Color::Indexed"),
-            Self::LightBlue => f.write_str("discriminant_Color::LightBlue"),
-            Self::LightCyan => f.write_str("discriminant_Color::LightCyan"),
-            Self::LightGreen => f.write_str("discriminant_Color::LightGreen"),
-            Self::LightMagenta => f.write_str("discriminant_Color::LightMagenta"),
-            Self::LightRed => f.write_str("discriminant_Color::LightRed"),
-            Self::LightYellow => f.write_str("discriminant_Color::LightYellow"),
-            Self::Magenta => f.write_str("discriminant_Color::Magenta"),
-            Self::Red => f.write_str("discriminant_Color::Red"),
-            Self::Rgb => f.write_str("discriminant_Color::Rgb"),
-            Self::White => f.write_str("discriminant_Color::White"),
-            Self::Yellow => f.write_str("discriminant_Color::Yellow"),
+            Self::BlinkingBar => f.write_str("CursorStyle::BlinkingBar"),
+            Self::BlinkingBlock => f.write_str("CursorStyle::BlinkingBlock"),
+            Self::BlinkingUnderline => f.write_str("CursorStyle::BlinkingUnderline"),
+            Self::Default => f.write_str("CursorStyle::Default"),
+            Self::SteadyBar => f.write_str("CursorStyle::SteadyBar"),
+            Self::SteadyBlock => f.write_str("CursorStyle::SteadyBlock"),
+            Self::SteadyUnderline => f.write_str("CursorStyle::SteadyUnderline"),
         }
     }
 }
@@ -905,11 +1318,11 @@ impl core::fmt::Debug for discriminant_Color {
     target_arch = "x86",
     target_arch = "x86_64"
 ))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
-pub union Color {
-    Indexed: u8,
-    Rgb: Color_Rgb,
-    _sizer: [u8; 4],
+pub struct EditorConfig {
+    pub id: roc_std::RocStr,
+    pub text: roc_std::RocStr,
 }
 
 #[cfg(any(
@@ -919,127 +1332,6336 @@ pub union Color {
     target_arch = "x86",
     target_arch = "x86_64"
 ))]
-#[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
-struct Color_Rgb {
-    pub f0: u8,
-    pub f1: u8,
-    pub f2: u8,
+pub struct EditedText {
+    pub id: roc_std::RocStr,
+    pub text: roc_std::RocStr,
 }
 
 #[cfg(any(
+    target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
     target_arch = "x86_64"
 ))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
-union union_Elem {
-    Block: core::mem::ManuallyDrop<Elem_Block>,
-    Layout: core::mem::ManuallyDrop<Elem_Layout>,
-    ListItems: core::mem::ManuallyDrop<Elem_ListItems>,
-    Paragraph: core::mem::ManuallyDrop<Elem_Paragraph>,
-    _sizer: [u8; 8],
+pub struct EditorExit {
+    pub id: roc_std::RocStr,
+    pub status: ExitStatus,
 }
 
 #[cfg(any(
+    target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
     target_arch = "x86_64"
 ))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
-pub union Event {
-    KeyPressed: core::mem::ManuallyDrop<KeyCode>,
-    Paste: core::mem::ManuallyDrop<roc_std::RocStr>,
-    Resize: Bounds,
-    _sizer: [u8; 40],
+pub struct EditFailure {
+    pub error: FileError,
+    pub id: roc_std::RocStr,
 }
 
 #[cfg(any(
+    target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
     target_arch = "x86_64"
 ))]
-#[repr(C)]
-pub union KeyCode {
-    Function: u8,
-    Media: MediaKeyCode,
-    Modifier: ModifierKeyCode,
-    Scalar: core::mem::ManuallyDrop<roc_std::RocStr>,
-    _sizer: [u8; 32],
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(transparent)]
+struct Elem_Paragraph {
+    pub f0: ParagraphConfig,
 }
 
 #[cfg(any(
+    target_arch = "arm",
     target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
     target_arch = "x86_64"
 ))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
 #[repr(C)]
-pub union ListSelection {
-    Selected: u64,
-    _sizer: [u8; 16],
+pub struct ParagraphConfig {
+    pub block: BlockConfig,
+    pub text: roc_std::RocList<roc_std::RocList<Span>>,
+    pub cursor: Cursor,
+    pub scroll: u16,
+    pub textAlignment: Alignment,
 }
 
-impl Elem {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    #[inline(always)]
-    fn storage(&self) -> Option<&core::cell::Cell<roc_std::Storage>> {
-        let mask = match std::mem::size_of::<usize>() {
-            4 => 0b11,
-            8 => 0b111,
-            _ => unreachable!(),
-        };
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum discriminant_Cursor {
+    At = 0,
+    Hidden = 1,
+}
 
-        // NOTE: pointer provenance is probably lost here
-        let unmasked_address = (self.pointer as usize) & !mask;
-        let untagged = unmasked_address as *const core::cell::Cell<roc_std::Storage>;
+impl core::fmt::Debug for discriminant_Cursor {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::At => f.write_str("discriminant_Cursor::At"),
+            Self::Hidden => f.write_str("discriminant_Cursor::Hidden"),
+        }
+    }
+}
 
-        if untagged.is_null() {
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[repr(C)]
+pub union Cursor {
+    At: CursorPosition,
+    _sizer: [u8; 6],
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct CursorPosition {
+    pub col: u16,
+    pub row: u16,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(transparent)]
+struct Elem_ListItems {
+    pub f0: ListConfig,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct ListConfig {
+    pub block: BlockConfig,
+    pub highlightStyle: Style,
+    pub highlightSymbol: roc_std::RocStr,
+    pub items: roc_std::RocList<roc_std::RocList<Span>>,
+    pub selected: ListSelection,
+    pub style: Style,
+    pub highlightSymbolRepeat: bool,
+    pub startCorner: Corner,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum Corner {
+    BottomLeft = 0,
+    BottomRight = 1,
+    TopLeft = 2,
+    TopRight = 3,
+}
+
+impl core::fmt::Debug for Corner {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::BottomLeft => f.write_str("Corner::BottomLeft"),
+            Self::BottomRight => f.write_str("Corner::BottomRight"),
+            Self::TopLeft => f.write_str("Corner::TopLeft"),
+            Self::TopRight => f.write_str("Corner::TopRight"),
+        }
+    }
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum discriminant_ListSelection {
+    None = 0,
+    Selected = 1,
+}
+
+impl core::fmt::Debug for discriminant_ListSelection {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::None => f.write_str("discriminant_ListSelection::None"),
+            Self::Selected => f.write_str("discriminant_ListSelection::Selected"),
+        }
+    }
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "wasm32",
+    target_arch = "x86"
+))]
+#[repr(C)]
+pub union ListSelection {
+    Selected: u32,
+    _sizer: [u8; 8],
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(transparent)]
+struct Elem_Tabs {
+    pub f0: TabsConfig,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "wasm32",
+    target_arch = "x86"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TabsConfig {
+    pub block: BlockConfig,
+    pub divider: Span,
+    pub highlightStyle: Style,
+    pub selected: u32,
+    pub style: Style,
+    pub titles: roc_std::RocList<roc_std::RocList<Span>>,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(transparent)]
+struct Elem_Table {
+    pub f0: TableConfig,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TableConfig {
+    pub block: BlockConfig,
+    pub header: TableRow,
+    pub highlightStyle: Style,
+    pub highlightSymbol: roc_std::RocStr,
+    pub rows: roc_std::RocList<TableRow>,
+    pub selected: ListSelection,
+    pub style: Style,
+    pub widths: roc_std::RocList<Constraint>,
+    pub columnSpacing: u16,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TableRow {
+    pub cells: roc_std::RocList<roc_std::RocList<Span>>,
+    pub style: Style,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(transparent)]
+struct Elem_Gauge {
+    pub f0: GaugeConfig,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "wasm32",
+    target_arch = "x86"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct GaugeConfig {
+    pub progress: Progress,
+    pub block: BlockConfig,
+    pub gaugeStyle: Style,
+    pub label: Span,
+    pub style: Style,
+    pub useUnicode: bool,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(transparent)]
+struct Elem_LineGauge {
+    pub f0: LineGaugeConfig,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "wasm32",
+    target_arch = "x86"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct LineGaugeConfig {
+    pub progress: Progress,
+    pub block: BlockConfig,
+    pub gaugeStyle: Style,
+    pub label: Span,
+    pub style: Style,
+    pub lineType: BorderType,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum discriminant_Progress {
+    Percentage = 0,
+    Ratio = 1,
+}
+
+impl core::fmt::Debug for discriminant_Progress {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Percentage => f.write_str("discriminant_Progress::Percentage"),
+            Self::Ratio => f.write_str("discriminant_Progress::Ratio"),
+        }
+    }
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[repr(C)]
+pub union Progress {
+    Percentage: u16,
+    Ratio: Progress_Ratio,
+    _sizer: [u8; 24],
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+struct Progress_Ratio {
+    pub f0: u64,
+    pub f1: u64,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(transparent)]
+struct Elem_Sparkline {
+    pub f0: SparklineConfig,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "wasm32",
+    target_arch = "x86"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct SparklineConfig {
+    pub max: SparklineMax,
+    pub block: BlockConfig,
+    pub data: roc_std::RocList<u64>,
+    pub style: Style,
+    pub barSet: BarSet,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum discriminant_SparklineMax {
+    Auto = 0,
+    Max = 1,
+}
+
+impl core::fmt::Debug for discriminant_SparklineMax {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Auto => f.write_str("discriminant_SparklineMax::Auto"),
+            Self::Max => f.write_str("discriminant_SparklineMax::Max"),
+        }
+    }
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[repr(C)]
+pub union SparklineMax {
+    Max: u64,
+    _sizer: [u8; 16],
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum BarSet {
+    NineLevels = 0,
+    ThreeLevels = 1,
+}
+
+impl core::fmt::Debug for BarSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NineLevels => f.write_str("BarSet::NineLevels"),
+            Self::ThreeLevels => f.write_str("BarSet::ThreeLevels"),
+        }
+    }
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+struct Elem_Layout {
+    pub f0: roc_std::RocList<Elem>,
+    pub f1: LayoutConfig,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct LayoutConfig {
+    pub constraints: roc_std::RocList<Constraint>,
+    pub hMargin: u16,
+    pub popup: PopupConfig,
+    pub vMargin: u16,
+    pub direction: LayoutDirection,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum LayoutDirection {
+    Horizontal = 0,
+    Vertical = 1,
+}
+
+impl core::fmt::Debug for LayoutDirection {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Horizontal => f.write_str("LayoutDirection::Horizontal"),
+            Self::Vertical => f.write_str("LayoutDirection::Vertical"),
+        }
+    }
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum discriminant_PopupConfig {
+    Centered = 0,
+    None = 1,
+}
+
+impl core::fmt::Debug for discriminant_PopupConfig {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Centered => f.write_str("discriminant_PopupConfig::Centered"),
+            Self::None => f.write_str("discriminant_PopupConfig::None"),
+        }
+    }
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[repr(C)]
+pub union PopupConfig {
+    Centered: ModalPosition,
+    _sizer: [u8; 6],
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct ModalPosition {
+    pub percentX: u16,
+    pub percentY: u16,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+struct Constraint_Ratio {
+    pub f0: u32,
+    pub f1: u32,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(transparent)]
+struct Elem_Block {
+    pub f0: BlockConfig,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct BlockConfig {
+    pub borderStyle: Style,
+    pub borders: roc_std::RocList<BorderModifier>,
+    pub style: Style,
+    pub title: Span,
+    pub borderType: BorderType,
+    pub titleAlignment: Alignment,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum Alignment {
+    Center = 0,
+    Left = 1,
+    Right = 2,
+}
+
+impl core::fmt::Debug for Alignment {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Center => f.write_str("Alignment::Center"),
+            Self::Left => f.write_str("Alignment::Left"),
+            Self::Right => f.write_str("Alignment::Right"),
+        }
+    }
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum BorderType {
+    Double = 0,
+    Plain = 1,
+    Rounded = 2,
+    Thick = 3,
+}
+
+impl core::fmt::Debug for BorderType {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Double => f.write_str("BorderType::Double"),
+            Self::Plain => f.write_str("BorderType::Plain"),
+            Self::Rounded => f.write_str("BorderType::Rounded"),
+            Self::Thick => f.write_str("BorderType::Thick"),
+        }
+    }
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct Style {
+    pub modifiers: roc_std::RocList<TextModifier>,
+    pub bg: Color,
+    pub fg: Color,
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(u8)]
+pub enum discriminant_Color {
+    Black = 0,
+    Blue = 1,
+    Cyan = 2,
+    DarkGray = 3,
+    Default = 4,
+    Gray = 5,
+    Green = 6,
+    Indexed = 7,
+    LightBlue = 8,
+    LightCyan = 9,
+    LightGreen = 10,
+    LightMagenta = 11,
+    LightRed = 12,
+    LightYellow = 13,
+    Magenta = 14,
+    Red = 15,
+    Rgb = 16,
+    White = 17,
+    Yellow = 18,
+}
+
+impl core::fmt::Debug for discriminant_Color {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Black => f.write_str("discriminant_Color::Black"),
+            Self::Blue => f.write_str("discriminant_Color::Blue"),
+            Self::Cyan => f.write_str("discriminant_Color::Cyan"),
+            Self::DarkGray => f.write_str("discriminant_Color::DarkGray"),
+            Self::Default => f.write_str("discriminant_Color::Default"),
+            Self::Gray => f.write_str("discriminant_Color::Gray"),
+            Self::Green => f.write_str("discriminant_Color::Green"),
+            Self::Indexed => f.write_str("discriminant_Color::Indexed"),
+            Self::LightBlue => f.write_str("discriminant_Color::LightBlue"),
+            Self::LightCyan => f.write_str("discriminant_Color::LightCyan"),
+            Self::LightGreen => f.write_str("discriminant_Color::LightGreen"),
+            Self::LightMagenta => f.write_str("discriminant_Color::LightMagenta"),
+            Self::LightRed => f.write_str("discriminant_Color::LightRed"),
+            Self::LightYellow => f.write_str("discriminant_Color::LightYellow"),
+            Self::Magenta => f.write_str("discriminant_Color::Magenta"),
+            Self::Red => f.write_str("discriminant_Color::Red"),
+            Self::Rgb => f.write_str("discriminant_Color::Rgb"),
+            Self::White => f.write_str("discriminant_Color::White"),
+            Self::Yellow => f.write_str("discriminant_Color::Yellow"),
+        }
+    }
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[repr(C)]
+pub union Color {
+    Indexed: u8,
+    Rgb: Color_Rgb,
+    _sizer: [u8; 4],
+}
+
+#[cfg(any(
+    target_arch = "arm",
+    target_arch = "aarch64",
+    target_arch = "wasm32",
+    target_arch = "x86",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+struct Color_Rgb {
+    pub f0: u8,
+    pub f1: u8,
+    pub f2: u8,
+}
+
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86_64"
+))]
+#[repr(C)]
+union union_Elem {
+    Block: core::mem::ManuallyDrop<Elem_Block>,
+    Gauge: core::mem::ManuallyDrop<Elem_Gauge>,
+    Layout: core::mem::ManuallyDrop<Elem_Layout>,
+    LineGauge: core::mem::ManuallyDrop<Elem_LineGauge>,
+    ListItems: core::mem::ManuallyDrop<Elem_ListItems>,
+    Paragraph: core::mem::ManuallyDrop<Elem_Paragraph>,
+    Sparkline: core::mem::ManuallyDrop<Elem_Sparkline>,
+    Table: core::mem::ManuallyDrop<Elem_Table>,
+    Tabs: core::mem::ManuallyDrop<Elem_Tabs>,
+    _sizer: [u8; 8],
+}
+
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86_64"
+))]
+#[repr(C)]
+pub union Event {
+    ClipboardRead: core::mem::ManuallyDrop<roc_std::RocStr>,
+    DirListed: core::mem::ManuallyDrop<DirContents>,
+    EditCancelled: core::mem::ManuallyDrop<EditorExit>,
+    EditFailed: core::mem::ManuallyDrop<EditFailure>,
+    Edited: core::mem::ManuallyDrop<EditedText>,
+    FileDone: core::mem::ManuallyDrop<FileSuccess>,
+    FileFailed: core::mem::ManuallyDrop<FileFailure>,
+    FileLinesRead: core::mem::ManuallyDrop<FileLines>,
+    FileRead: core::mem::ManuallyDrop<FileContents>,
+    KeyPressed: core::mem::ManuallyDrop<KeyEvent>,
+    Mouse: core::mem::ManuallyDrop<MouseEvent>,
+    Paste: core::mem::ManuallyDrop<roc_std::RocStr>,
+    ProcessExited: core::mem::ManuallyDrop<ProcessExit>,
+    ProcessFailed: core::mem::ManuallyDrop<ProcessFailure>,
+    ProcessOutput: core::mem::ManuallyDrop<OutputLine>,
+    Resize: Bounds,
+    Tick: TickInfo,
+    Timer: core::mem::ManuallyDrop<roc_std::RocStr>,
+    _sizer: [u8; 72],
+}
+
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86_64"
+))]
+#[repr(C)]
+pub union KeyCode {
+    Function: u8,
+    Media: MediaKeyCode,
+    Modifier: ModifierKeyCode,
+    Scalar: core::mem::ManuallyDrop<roc_std::RocStr>,
+    _sizer: [u8; 32],
+}
+
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86_64"
+))]
+#[repr(C)]
+pub union Command {
+    AppendFile: core::mem::ManuallyDrop<FileContents>,
+    CancelTimer: core::mem::ManuallyDrop<roc_std::RocStr>,
+    CloseStdin: core::mem::ManuallyDrop<roc_std::RocStr>,
+    CopyToClipboard: core::mem::ManuallyDrop<roc_std::RocStr>,
+    DeleteFile: core::mem::ManuallyDrop<roc_std::RocStr>,
+    KillProcess: core::mem::ManuallyDrop<roc_std::RocStr>,
+    ListDir: core::mem::ManuallyDrop<roc_std::RocStr>,
+    OpenEditor: core::mem::ManuallyDrop<EditorConfig>,
+    Quit: u8,
+    ReadFile: core::mem::ManuallyDrop<roc_std::RocStr>,
+    ReadFileLines: core::mem::ManuallyDrop<ReadLinesConfig>,
+    RenameFile: core::mem::ManuallyDrop<RenameConfig>,
+    SetBracketedPaste: bool,
+    SetCursorStyle: CursorStyle,
+    SetFocusReporting: bool,
+    SetTickRate: u64,
+    SetTitle: core::mem::ManuallyDrop<roc_std::RocStr>,
+    SpawnProcess: core::mem::ManuallyDrop<SpawnConfig>,
+    StartTimer: core::mem::ManuallyDrop<TimerConfig>,
+    WriteFile: core::mem::ManuallyDrop<FileContents>,
+    WriteStdin: core::mem::ManuallyDrop<ProcessInput>,
+    _sizer: [u8; 128],
+}
+
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TimerConfig {
+    pub id: roc_std::RocStr,
+    pub milliseconds: u64,
+    pub repeat: bool,
+}
+
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct ReadLinesConfig {
+    pub count: u64,
+    pub path: roc_std::RocStr,
+    pub start: u64,
+}
+
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct FileLines {
+    pub lines: roc_std::RocList<roc_std::RocStr>,
+    pub path: roc_std::RocStr,
+    pub start: u64,
+}
+
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86_64"
+))]
+#[repr(C)]
+pub union FileError {
+    Other: core::mem::ManuallyDrop<roc_std::RocStr>,
+    _sizer: [u8; 32],
+}
+
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86_64"
+))]
+#[repr(C)]
+pub union ListSelection {
+    Selected: u64,
+    _sizer: [u8; 16],
+}
+
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct TabsConfig {
+    pub block: BlockConfig,
+    pub divider: Span,
+    pub highlightStyle: Style,
+    pub selected: u64,
+    pub style: Style,
+    pub titles: roc_std::RocList<roc_std::RocList<Span>>,
+}
+
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct GaugeConfig {
+    pub block: BlockConfig,
+    pub gaugeStyle: Style,
+    pub label: Span,
+    pub progress: Progress,
+    pub style: Style,
+    pub useUnicode: bool,
+}
+
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct LineGaugeConfig {
+    pub block: BlockConfig,
+    pub gaugeStyle: Style,
+    pub label: Span,
+    pub progress: Progress,
+    pub style: Style,
+    pub lineType: BorderType,
+}
+
+#[cfg(any(
+    target_arch = "aarch64",
+    target_arch = "x86_64"
+))]
+#[derive(Clone, Debug, Eq, Ord, Hash, PartialEq, PartialOrd)]
+#[repr(C)]
+pub struct SparklineConfig {
+    pub block: BlockConfig,
+    pub data: roc_std::RocList<u64>,
+    pub max: SparklineMax,
+    pub style: Style,
+    pub barSet: BarSet,
+}
+
+impl Elem {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    #[inline(always)]
+    fn storage(&self) -> Option<&core::cell::Cell<roc_std::Storage>> {
+        // NOTE: pointer provenance is probably lost here
+        let untagged = self.pointer as *const core::cell::Cell<roc_std::Storage>;
+
+        if untagged.is_null() {
             None
         } else {
             unsafe {
-                Some(&*untagged.sub(1))
+                Some(&*untagged.sub(1))
+            }
+        }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Returns which variant this tag union holds. Note that this never includes a payload!
+    pub fn discriminant(&self) -> discriminant_Elem {
+        // There are too many tags to fit in the pointer, so the discriminant is stored after the payload
+        unsafe { (*(self.pointer as *const tagged_union_Elem)).discriminant }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Internal helper
+    fn union_pointer(&self) -> *mut union_Elem {
+        self.pointer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Block`, with the appropriate payload
+    pub fn Block(arg0: BlockConfig) -> Self {
+            unsafe {
+                let ptr = roc_std::roc_alloc_refcounted::<tagged_union_Elem>();
+
+                *ptr = tagged_union_Elem {
+                    union: union_Elem {
+                        Block: core::mem::ManuallyDrop::new(Elem_Block {
+                        f0: arg0,
+                        })
+                    },
+                    discriminant: discriminant_Elem::Block,
+                };
+
+                Self {
+                    pointer: ptr as *mut union_Elem,
+                }
+            }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Block` and convert it to `Block`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Block`.
+            pub unsafe fn into_Block(mut self) -> BlockConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::Block);
+        let payload = {
+            let ptr = self.pointer;
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut (*ptr).Block,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload.f0
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Block` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Block`.
+            pub unsafe fn as_Block(&self) -> &BlockConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::Block);
+        let payload = {
+            let ptr = self.pointer;
+
+            unsafe { &(*ptr).Block }
+        };
+
+        
+        &payload.f0
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Gauge`, with the appropriate payload
+    pub fn Gauge(arg0: GaugeConfig) -> Self {
+            unsafe {
+                let ptr = roc_std::roc_alloc_refcounted::<tagged_union_Elem>();
+
+                *ptr = tagged_union_Elem {
+                    union: union_Elem {
+                        Gauge: core::mem::ManuallyDrop::new(Elem_Gauge {
+                        f0: arg0,
+                        })
+                    },
+                    discriminant: discriminant_Elem::Gauge,
+                };
+
+                Self {
+                    pointer: ptr as *mut union_Elem,
+                }
+            }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Gauge` and convert it to `Gauge`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Gauge`.
+            pub unsafe fn into_Gauge(mut self) -> GaugeConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::Gauge);
+        let payload = {
+            let ptr = self.pointer;
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut (*ptr).Gauge,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload.f0
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Gauge` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Gauge`.
+            pub unsafe fn as_Gauge(&self) -> &GaugeConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::Gauge);
+        let payload = {
+            let ptr = self.pointer;
+
+            unsafe { &(*ptr).Gauge }
+        };
+
+        
+        &payload.f0
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Layout`, with the appropriate payload
+    pub fn Layout(arg0: roc_std::RocList<Elem>, arg1: LayoutConfig) -> Self {
+            unsafe {
+                let ptr = roc_std::roc_alloc_refcounted::<tagged_union_Elem>();
+
+                *ptr = tagged_union_Elem {
+                    union: union_Elem {
+                        Layout: core::mem::ManuallyDrop::new(Elem_Layout {
+                        f0: arg0,
+                        f1: arg1,
+                        })
+                    },
+                    discriminant: discriminant_Elem::Layout,
+                };
+
+                Self {
+                    pointer: ptr as *mut union_Elem,
+                }
+            }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Layout` and convert it to `Layout`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Layout`.
+            pub unsafe fn into_Layout(mut self) -> (roc_std::RocList<Elem>, LayoutConfig) {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::Layout);
+        let payload = {
+            let ptr = self.pointer;
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut (*ptr).Layout,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        (
+            payload.f0, 
+            payload.f1
+        )
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Layout` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Layout`.
+            pub unsafe fn as_Layout(&self) -> (&roc_std::RocList<Elem>, &LayoutConfig) {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::Layout);
+        let payload = {
+            let ptr = self.pointer;
+
+            unsafe { &(*ptr).Layout }
+        };
+
+        (
+            &payload.f0, 
+            &payload.f1
+        )
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `LineGauge`, with the appropriate payload
+    pub fn LineGauge(arg0: LineGaugeConfig) -> Self {
+            unsafe {
+                let ptr = roc_std::roc_alloc_refcounted::<tagged_union_Elem>();
+
+                *ptr = tagged_union_Elem {
+                    union: union_Elem {
+                        LineGauge: core::mem::ManuallyDrop::new(Elem_LineGauge {
+                        f0: arg0,
+                        })
+                    },
+                    discriminant: discriminant_Elem::LineGauge,
+                };
+
+                Self {
+                    pointer: ptr as *mut union_Elem,
+                }
+            }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `LineGauge` and convert it to `LineGauge`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `LineGauge`.
+            pub unsafe fn into_LineGauge(mut self) -> LineGaugeConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::LineGauge);
+        let payload = {
+            let ptr = self.pointer;
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut (*ptr).LineGauge,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload.f0
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `LineGauge` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `LineGauge`.
+            pub unsafe fn as_LineGauge(&self) -> &LineGaugeConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::LineGauge);
+        let payload = {
+            let ptr = self.pointer;
+
+            unsafe { &(*ptr).LineGauge }
+        };
+
+        
+        &payload.f0
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `ListItems`, with the appropriate payload
+    pub fn ListItems(arg0: ListConfig) -> Self {
+            unsafe {
+                let ptr = roc_std::roc_alloc_refcounted::<tagged_union_Elem>();
+
+                *ptr = tagged_union_Elem {
+                    union: union_Elem {
+                        ListItems: core::mem::ManuallyDrop::new(Elem_ListItems {
+                        f0: arg0,
+                        })
+                    },
+                    discriminant: discriminant_Elem::ListItems,
+                };
+
+                Self {
+                    pointer: ptr as *mut union_Elem,
+                }
+            }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `ListItems` and convert it to `ListItems`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `ListItems`.
+            pub unsafe fn into_ListItems(mut self) -> ListConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::ListItems);
+        let payload = {
+            let ptr = self.pointer;
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut (*ptr).ListItems,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload.f0
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `ListItems` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `ListItems`.
+            pub unsafe fn as_ListItems(&self) -> &ListConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::ListItems);
+        let payload = {
+            let ptr = self.pointer;
+
+            unsafe { &(*ptr).ListItems }
+        };
+
+        
+        &payload.f0
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Paragraph`, with the appropriate payload
+    pub fn Paragraph(arg0: ParagraphConfig) -> Self {
+            unsafe {
+                let ptr = roc_std::roc_alloc_refcounted::<tagged_union_Elem>();
+
+                *ptr = tagged_union_Elem {
+                    union: union_Elem {
+                        Paragraph: core::mem::ManuallyDrop::new(Elem_Paragraph {
+                        f0: arg0,
+                        })
+                    },
+                    discriminant: discriminant_Elem::Paragraph,
+                };
+
+                Self {
+                    pointer: ptr as *mut union_Elem,
+                }
+            }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Paragraph` and convert it to `Paragraph`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Paragraph`.
+            pub unsafe fn into_Paragraph(mut self) -> ParagraphConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::Paragraph);
+        let payload = {
+            let ptr = self.pointer;
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut (*ptr).Paragraph,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload.f0
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Paragraph` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Paragraph`.
+            pub unsafe fn as_Paragraph(&self) -> &ParagraphConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::Paragraph);
+        let payload = {
+            let ptr = self.pointer;
+
+            unsafe { &(*ptr).Paragraph }
+        };
+
+        
+        &payload.f0
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Sparkline`, with the appropriate payload
+    pub fn Sparkline(arg0: SparklineConfig) -> Self {
+            unsafe {
+                let ptr = roc_std::roc_alloc_refcounted::<tagged_union_Elem>();
+
+                *ptr = tagged_union_Elem {
+                    union: union_Elem {
+                        Sparkline: core::mem::ManuallyDrop::new(Elem_Sparkline {
+                        f0: arg0,
+                        })
+                    },
+                    discriminant: discriminant_Elem::Sparkline,
+                };
+
+                Self {
+                    pointer: ptr as *mut union_Elem,
+                }
+            }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Sparkline` and convert it to `Sparkline`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Sparkline`.
+            pub unsafe fn into_Sparkline(mut self) -> SparklineConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::Sparkline);
+        let payload = {
+            let ptr = self.pointer;
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut (*ptr).Sparkline,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload.f0
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Sparkline` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Sparkline`.
+            pub unsafe fn as_Sparkline(&self) -> &SparklineConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::Sparkline);
+        let payload = {
+            let ptr = self.pointer;
+
+            unsafe { &(*ptr).Sparkline }
+        };
+
+        
+        &payload.f0
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Table`, with the appropriate payload
+    pub fn Table(arg0: TableConfig) -> Self {
+            unsafe {
+                let ptr = roc_std::roc_alloc_refcounted::<tagged_union_Elem>();
+
+                *ptr = tagged_union_Elem {
+                    union: union_Elem {
+                        Table: core::mem::ManuallyDrop::new(Elem_Table {
+                        f0: arg0,
+                        })
+                    },
+                    discriminant: discriminant_Elem::Table,
+                };
+
+                Self {
+                    pointer: ptr as *mut union_Elem,
+                }
+            }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Table` and convert it to `Table`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Table`.
+            pub unsafe fn into_Table(mut self) -> TableConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::Table);
+        let payload = {
+            let ptr = self.pointer;
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut (*ptr).Table,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload.f0
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Table` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Table`.
+            pub unsafe fn as_Table(&self) -> &TableConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::Table);
+        let payload = {
+            let ptr = self.pointer;
+
+            unsafe { &(*ptr).Table }
+        };
+
+        
+        &payload.f0
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Tabs`, with the appropriate payload
+    pub fn Tabs(arg0: TabsConfig) -> Self {
+            unsafe {
+                let ptr = roc_std::roc_alloc_refcounted::<tagged_union_Elem>();
+
+                *ptr = tagged_union_Elem {
+                    union: union_Elem {
+                        Tabs: core::mem::ManuallyDrop::new(Elem_Tabs {
+                        f0: arg0,
+                        })
+                    },
+                    discriminant: discriminant_Elem::Tabs,
+                };
+
+                Self {
+                    pointer: ptr as *mut union_Elem,
+                }
+            }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Tabs` and convert it to `Tabs`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Tabs`.
+            pub unsafe fn into_Tabs(mut self) -> TabsConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::Tabs);
+        let payload = {
+            let ptr = self.pointer;
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut (*ptr).Tabs,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload.f0
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Tabs` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Tabs`.
+            pub unsafe fn as_Tabs(&self) -> &TabsConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Elem::Tabs);
+        let payload = {
+            let ptr = self.pointer;
+
+            unsafe { &(*ptr).Tabs }
+        };
+
+        
+        &payload.f0
+    }
+}
+
+impl Drop for Elem {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn drop(&mut self) {
+        // We only need to do any work if there's actually a heap-allocated payload.
+        if let Some(storage) = self.storage() {
+            let mut new_storage = storage.get();
+
+            // Decrement the refcount
+            let needs_dealloc = !new_storage.is_readonly() && new_storage.decrease();
+
+            if needs_dealloc {
+                // Drop the payload first.
+                            match self.discriminant() {
+                discriminant_Elem::Block => unsafe { core::mem::ManuallyDrop::drop(&mut (&mut *self.union_pointer()).Block) },
+                discriminant_Elem::Gauge => unsafe { core::mem::ManuallyDrop::drop(&mut (&mut *self.union_pointer()).Gauge) },
+                discriminant_Elem::Layout => unsafe { core::mem::ManuallyDrop::drop(&mut (&mut *self.union_pointer()).Layout) },
+                discriminant_Elem::LineGauge => unsafe { core::mem::ManuallyDrop::drop(&mut (&mut *self.union_pointer()).LineGauge) },
+                discriminant_Elem::ListItems => unsafe { core::mem::ManuallyDrop::drop(&mut (&mut *self.union_pointer()).ListItems) },
+                discriminant_Elem::Paragraph => unsafe { core::mem::ManuallyDrop::drop(&mut (&mut *self.union_pointer()).Paragraph) },
+                discriminant_Elem::Sparkline => unsafe { core::mem::ManuallyDrop::drop(&mut (&mut *self.union_pointer()).Sparkline) },
+                discriminant_Elem::Table => unsafe { core::mem::ManuallyDrop::drop(&mut (&mut *self.union_pointer()).Table) },
+                discriminant_Elem::Tabs => unsafe { core::mem::ManuallyDrop::drop(&mut (&mut *self.union_pointer()).Tabs) },
+            }
+
+
+                // Dealloc the pointer
+                let alignment = core::mem::align_of::<Self>().max(core::mem::align_of::<roc_std::Storage>());
+
+                unsafe { crate::roc::roc_dealloc(storage.as_ptr().cast(), alignment as u32); }
+            } else {
+                // Write the storage back.
+                storage.set(new_storage);
+            }
+        }
+    }
+}
+
+impl Eq for Elem {}
+
+impl PartialEq for Elem {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn eq(&self, other: &Self) -> bool {
+            if self.discriminant() != other.discriminant() {
+                return false;
+            }
+
+            unsafe {
+            match self.discriminant() {
+                discriminant_Elem::Block => (&*self.union_pointer()).Block == (&*other.union_pointer()).Block,
+                discriminant_Elem::Gauge => (&*self.union_pointer()).Gauge == (&*other.union_pointer()).Gauge,
+                discriminant_Elem::Layout => (&*self.union_pointer()).Layout == (&*other.union_pointer()).Layout,
+                discriminant_Elem::LineGauge => (&*self.union_pointer()).LineGauge == (&*other.union_pointer()).LineGauge,
+                discriminant_Elem::ListItems => (&*self.union_pointer()).ListItems == (&*other.union_pointer()).ListItems,
+                discriminant_Elem::Paragraph => (&*self.union_pointer()).Paragraph == (&*other.union_pointer()).Paragraph,
+                discriminant_Elem::Sparkline => (&*self.union_pointer()).Sparkline == (&*other.union_pointer()).Sparkline,
+                discriminant_Elem::Table => (&*self.union_pointer()).Table == (&*other.union_pointer()).Table,
+                discriminant_Elem::Tabs => (&*self.union_pointer()).Tabs == (&*other.union_pointer()).Tabs,
+            }
+        }
+    }
+}
+
+impl PartialOrd for Elem {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match self.discriminant().partial_cmp(&other.discriminant()) {
+            Some(core::cmp::Ordering::Equal) => {}
+            not_eq => return not_eq,
+        }
+
+        unsafe {
+            match self.discriminant() {
+                discriminant_Elem::Block => (&*self.union_pointer()).Block.partial_cmp(&(&*other.union_pointer()).Block),
+                discriminant_Elem::Gauge => (&*self.union_pointer()).Gauge.partial_cmp(&(&*other.union_pointer()).Gauge),
+                discriminant_Elem::Layout => (&*self.union_pointer()).Layout.partial_cmp(&(&*other.union_pointer()).Layout),
+                discriminant_Elem::LineGauge => (&*self.union_pointer()).LineGauge.partial_cmp(&(&*other.union_pointer()).LineGauge),
+                discriminant_Elem::ListItems => (&*self.union_pointer()).ListItems.partial_cmp(&(&*other.union_pointer()).ListItems),
+                discriminant_Elem::Paragraph => (&*self.union_pointer()).Paragraph.partial_cmp(&(&*other.union_pointer()).Paragraph),
+                discriminant_Elem::Sparkline => (&*self.union_pointer()).Sparkline.partial_cmp(&(&*other.union_pointer()).Sparkline),
+                discriminant_Elem::Table => (&*self.union_pointer()).Table.partial_cmp(&(&*other.union_pointer()).Table),
+                discriminant_Elem::Tabs => (&*self.union_pointer()).Tabs.partial_cmp(&(&*other.union_pointer()).Tabs),
+            }
+        }
+    }
+}
+
+impl Ord for Elem {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            match self.discriminant().cmp(&other.discriminant()) {
+                core::cmp::Ordering::Equal => {}
+                not_eq => return not_eq,
+            }
+
+            unsafe {
+            match self.discriminant() {
+                discriminant_Elem::Block => (&*self.union_pointer()).Block.cmp(&(&*other.union_pointer()).Block),
+                discriminant_Elem::Gauge => (&*self.union_pointer()).Gauge.cmp(&(&*other.union_pointer()).Gauge),
+                discriminant_Elem::Layout => (&*self.union_pointer()).Layout.cmp(&(&*other.union_pointer()).Layout),
+                discriminant_Elem::LineGauge => (&*self.union_pointer()).LineGauge.cmp(&(&*other.union_pointer()).LineGauge),
+                discriminant_Elem::ListItems => (&*self.union_pointer()).ListItems.cmp(&(&*other.union_pointer()).ListItems),
+                discriminant_Elem::Paragraph => (&*self.union_pointer()).Paragraph.cmp(&(&*other.union_pointer()).Paragraph),
+                discriminant_Elem::Sparkline => (&*self.union_pointer()).Sparkline.cmp(&(&*other.union_pointer()).Sparkline),
+                discriminant_Elem::Table => (&*self.union_pointer()).Table.cmp(&(&*other.union_pointer()).Table),
+                discriminant_Elem::Tabs => (&*self.union_pointer()).Tabs.cmp(&(&*other.union_pointer()).Tabs),
+            }
+        }
+    }
+}
+
+impl Clone for Elem {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn clone(&self) -> Self {
+        if let Some(storage) = self.storage() {
+            let mut new_storage = storage.get();
+            if !new_storage.is_readonly() {
+                new_storage.increment_reference_count();
+                storage.set(new_storage);
+            }
+        }
+
+        Self {
+            pointer: self.pointer
+        }
+    }
+}
+
+impl core::hash::Hash for Elem {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
+            discriminant_Elem::Block => unsafe {
+                    discriminant_Elem::Block.hash(state);
+                    (&*self.union_pointer()).Block.hash(state);
+                },
+            discriminant_Elem::Gauge => unsafe {
+                    discriminant_Elem::Gauge.hash(state);
+                    (&*self.union_pointer()).Gauge.hash(state);
+                },
+            discriminant_Elem::Layout => unsafe {
+                    discriminant_Elem::Layout.hash(state);
+                    (&*self.union_pointer()).Layout.hash(state);
+                },
+            discriminant_Elem::LineGauge => unsafe {
+                    discriminant_Elem::LineGauge.hash(state);
+                    (&*self.union_pointer()).LineGauge.hash(state);
+                },
+            discriminant_Elem::ListItems => unsafe {
+                    discriminant_Elem::ListItems.hash(state);
+                    (&*self.union_pointer()).ListItems.hash(state);
+                },
+            discriminant_Elem::Paragraph => unsafe {
+                    discriminant_Elem::Paragraph.hash(state);
+                    (&*self.union_pointer()).Paragraph.hash(state);
+                },
+            discriminant_Elem::Sparkline => unsafe {
+                    discriminant_Elem::Sparkline.hash(state);
+                    (&*self.union_pointer()).Sparkline.hash(state);
+                },
+            discriminant_Elem::Table => unsafe {
+                    discriminant_Elem::Table.hash(state);
+                    (&*self.union_pointer()).Table.hash(state);
+                },
+            discriminant_Elem::Tabs => unsafe {
+                    discriminant_Elem::Tabs.hash(state);
+                    (&*self.union_pointer()).Tabs.hash(state);
+                },
+        }
+    }
+}
+
+impl core::fmt::Debug for Elem {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Elem::")?;
+
+        unsafe {
+            match self.discriminant() {
+                discriminant_Elem::Block => f.debug_tuple("Block")
+        .field(&(&*(&*self.union_pointer()).Block).f0)
+        .finish(),
+                discriminant_Elem::Gauge => f.debug_tuple("Gauge")
+        .field(&(&*(&*self.union_pointer()).Gauge).f0)
+        .finish(),
+                discriminant_Elem::Layout => f.debug_tuple("Layout")
+        .field(&(&*(&*self.union_pointer()).Layout).f0)
+.field(&(&*(&*self.union_pointer()).Layout).f1)
+        .finish(),
+                discriminant_Elem::LineGauge => f.debug_tuple("LineGauge")
+        .field(&(&*(&*self.union_pointer()).LineGauge).f0)
+        .finish(),
+                discriminant_Elem::ListItems => f.debug_tuple("ListItems")
+        .field(&(&*(&*self.union_pointer()).ListItems).f0)
+        .finish(),
+                discriminant_Elem::Paragraph => f.debug_tuple("Paragraph")
+        .field(&(&*(&*self.union_pointer()).Paragraph).f0)
+        .finish(),
+                discriminant_Elem::Sparkline => f.debug_tuple("Sparkline")
+        .field(&(&*(&*self.union_pointer()).Sparkline).f0)
+        .finish(),
+                discriminant_Elem::Table => f.debug_tuple("Table")
+        .field(&(&*(&*self.union_pointer()).Table).f0)
+        .finish(),
+                discriminant_Elem::Tabs => f.debug_tuple("Tabs")
+        .field(&(&*(&*self.union_pointer()).Tabs).f0)
+        .finish(),
+            }
+        }
+    }
+}
+
+impl Event {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// Returns which variant this tag union holds. Note that this never includes a payload!
+    pub fn discriminant(&self) -> discriminant_Event {
+        unsafe {
+            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
+
+            core::mem::transmute::<u8, discriminant_Event>(*bytes.as_ptr().add(32))
+        }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// Internal helper
+    fn set_discriminant(&mut self, discriminant: discriminant_Event) {
+        let discriminant_ptr: *mut discriminant_Event = (self as *mut Event).cast();
+
+        unsafe {
+            *(discriminant_ptr.add(32)) = discriminant;
+        }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `ClipboardRead`, with the appropriate payload
+    pub fn ClipboardRead(arg: roc_std::RocStr) -> Self {
+            let mut answer = Self {
+                ClipboardRead: core::mem::ManuallyDrop::new(arg)
+            };
+
+            answer.set_discriminant(discriminant_Event::ClipboardRead);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `ClipboardRead` and convert it to `ClipboardRead`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `ClipboardRead`.
+            pub unsafe fn into_ClipboardRead(mut self) -> roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::ClipboardRead);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.ClipboardRead,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `ClipboardRead` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `ClipboardRead`.
+            pub unsafe fn as_ClipboardRead(&self) -> &roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::ClipboardRead);
+        let payload = &self.ClipboardRead;
+
+        &payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named ClipboardUnavailable, which has no payload.
+    pub const ClipboardUnavailable: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<Event>()];
+
+        bytes[32] = discriminant_Event::ClipboardUnavailable as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<Event>()], Event>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the ClipboardUnavailable tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_ClipboardUnavailable(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the ClipboardUnavailable tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_ClipboardUnavailable(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `DirListed`, with the appropriate payload
+    pub fn DirListed(arg0: DirContents) -> Self {
+            let mut answer = Self {
+                DirListed: core::mem::ManuallyDrop::new(arg0)
+            };
+
+            answer.set_discriminant(discriminant_Event::DirListed);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `DirListed` and convert it to `DirListed`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `DirListed`.
+            pub unsafe fn into_DirListed(mut self) -> DirContents {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::DirListed);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.DirListed,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `DirListed` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `DirListed`.
+            pub unsafe fn as_DirListed(&self) -> &DirContents {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::DirListed);
+        let payload = &self.DirListed;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `EditCancelled`, with the appropriate payload
+    pub fn EditCancelled(arg0: EditorExit) -> Self {
+            let mut answer = Self {
+                EditCancelled: core::mem::ManuallyDrop::new(arg0)
+            };
+
+            answer.set_discriminant(discriminant_Event::EditCancelled);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `EditCancelled` and convert it to `EditCancelled`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `EditCancelled`.
+            pub unsafe fn into_EditCancelled(mut self) -> EditorExit {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::EditCancelled);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.EditCancelled,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `EditCancelled` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `EditCancelled`.
+            pub unsafe fn as_EditCancelled(&self) -> &EditorExit {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::EditCancelled);
+        let payload = &self.EditCancelled;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `EditFailed`, with the appropriate payload
+    pub fn EditFailed(arg0: EditFailure) -> Self {
+            let mut answer = Self {
+                EditFailed: core::mem::ManuallyDrop::new(arg0)
+            };
+
+            answer.set_discriminant(discriminant_Event::EditFailed);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `EditFailed` and convert it to `EditFailed`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `EditFailed`.
+            pub unsafe fn into_EditFailed(mut self) -> EditFailure {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::EditFailed);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.EditFailed,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `EditFailed` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `EditFailed`.
+            pub unsafe fn as_EditFailed(&self) -> &EditFailure {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::EditFailed);
+        let payload = &self.EditFailed;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Edited`, with the appropriate payload
+    pub fn Edited(arg0: EditedText) -> Self {
+            let mut answer = Self {
+                Edited: core::mem::ManuallyDrop::new(arg0)
+            };
+
+            answer.set_discriminant(discriminant_Event::Edited);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `Edited` and convert it to `Edited`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Edited`.
+            pub unsafe fn into_Edited(mut self) -> EditedText {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::Edited);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.Edited,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `Edited` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Edited`.
+            pub unsafe fn as_Edited(&self) -> &EditedText {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::Edited);
+        let payload = &self.Edited;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `FileDone`, with the appropriate payload
+    pub fn FileDone(arg0: FileSuccess) -> Self {
+            let mut answer = Self {
+                FileDone: core::mem::ManuallyDrop::new(arg0)
+            };
+
+            answer.set_discriminant(discriminant_Event::FileDone);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `FileDone` and convert it to `FileDone`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `FileDone`.
+            pub unsafe fn into_FileDone(mut self) -> FileSuccess {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::FileDone);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.FileDone,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `FileDone` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `FileDone`.
+            pub unsafe fn as_FileDone(&self) -> &FileSuccess {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::FileDone);
+        let payload = &self.FileDone;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `FileFailed`, with the appropriate payload
+    pub fn FileFailed(arg0: FileFailure) -> Self {
+            let mut answer = Self {
+                FileFailed: core::mem::ManuallyDrop::new(arg0)
+            };
+
+            answer.set_discriminant(discriminant_Event::FileFailed);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `FileFailed` and convert it to `FileFailed`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `FileFailed`.
+            pub unsafe fn into_FileFailed(mut self) -> FileFailure {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::FileFailed);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.FileFailed,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `FileFailed` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `FileFailed`.
+            pub unsafe fn as_FileFailed(&self) -> &FileFailure {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::FileFailed);
+        let payload = &self.FileFailed;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `FileLinesRead`, with the appropriate payload
+    pub fn FileLinesRead(arg0: FileLines) -> Self {
+            let mut answer = Self {
+                FileLinesRead: core::mem::ManuallyDrop::new(arg0)
+            };
+
+            answer.set_discriminant(discriminant_Event::FileLinesRead);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `FileLinesRead` and convert it to `FileLinesRead`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `FileLinesRead`.
+            pub unsafe fn into_FileLinesRead(mut self) -> FileLines {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::FileLinesRead);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.FileLinesRead,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `FileLinesRead` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `FileLinesRead`.
+            pub unsafe fn as_FileLinesRead(&self) -> &FileLines {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::FileLinesRead);
+        let payload = &self.FileLinesRead;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `FileRead`, with the appropriate payload
+    pub fn FileRead(arg0: FileContents) -> Self {
+            let mut answer = Self {
+                FileRead: core::mem::ManuallyDrop::new(arg0)
+            };
+
+            answer.set_discriminant(discriminant_Event::FileRead);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `FileRead` and convert it to `FileRead`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `FileRead`.
+            pub unsafe fn into_FileRead(mut self) -> FileContents {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::FileRead);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.FileRead,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `FileRead` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `FileRead`.
+            pub unsafe fn as_FileRead(&self) -> &FileContents {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::FileRead);
+        let payload = &self.FileRead;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named FocusGained, which has no payload.
+    pub const FocusGained: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<Event>()];
+
+        bytes[32] = discriminant_Event::FocusGained as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<Event>()], Event>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the FocusGained tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_FocusGained(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the FocusGained tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_FocusGained(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named FocusLost, which has no payload.
+    pub const FocusLost: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<Event>()];
+
+        bytes[32] = discriminant_Event::FocusLost as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<Event>()], Event>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the FocusLost tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_FocusLost(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the FocusLost tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_FocusLost(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `KeyPressed`, with the appropriate payload
+    pub fn KeyPressed(arg0: KeyEvent) -> Self {
+            let mut answer = Self {
+                KeyPressed: core::mem::ManuallyDrop::new(arg0)
+            };
+
+            answer.set_discriminant(discriminant_Event::KeyPressed);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `KeyPressed` and convert it to `KeyPressed`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `KeyPressed`.
+            pub unsafe fn into_KeyPressed(mut self) -> KeyEvent {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::KeyPressed);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.KeyPressed,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `KeyPressed` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `KeyPressed`.
+            pub unsafe fn as_KeyPressed(&self) -> &KeyEvent {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::KeyPressed);
+        let payload = &self.KeyPressed;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Mouse`, with the appropriate payload
+    pub fn Mouse(arg0: MouseEvent) -> Self {
+            let mut answer = Self {
+                Mouse: core::mem::ManuallyDrop::new(arg0)
+            };
+
+            answer.set_discriminant(discriminant_Event::Mouse);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `Mouse` and convert it to `Mouse`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Mouse`.
+            pub unsafe fn into_Mouse(mut self) -> MouseEvent {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::Mouse);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.Mouse,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `Mouse` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Mouse`.
+            pub unsafe fn as_Mouse(&self) -> &MouseEvent {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::Mouse);
+        let payload = &self.Mouse;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Paste`, with the appropriate payload
+    pub fn Paste(arg: roc_std::RocStr) -> Self {
+            let mut answer = Self {
+                Paste: core::mem::ManuallyDrop::new(arg)
+            };
+
+            answer.set_discriminant(discriminant_Event::Paste);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `Paste` and convert it to `Paste`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Paste`.
+            pub unsafe fn into_Paste(mut self) -> roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::Paste);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.Paste,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `Paste` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Paste`.
+            pub unsafe fn as_Paste(&self) -> &roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::Paste);
+        let payload = &self.Paste;
+
+        &payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `ProcessExited`, with the appropriate payload
+    pub fn ProcessExited(arg0: ProcessExit) -> Self {
+            let mut answer = Self {
+                ProcessExited: core::mem::ManuallyDrop::new(arg0)
+            };
+
+            answer.set_discriminant(discriminant_Event::ProcessExited);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `ProcessExited` and convert it to `ProcessExited`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `ProcessExited`.
+            pub unsafe fn into_ProcessExited(mut self) -> ProcessExit {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::ProcessExited);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.ProcessExited,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `ProcessExited` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `ProcessExited`.
+            pub unsafe fn as_ProcessExited(&self) -> &ProcessExit {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::ProcessExited);
+        let payload = &self.ProcessExited;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `ProcessFailed`, with the appropriate payload
+    pub fn ProcessFailed(arg0: ProcessFailure) -> Self {
+            let mut answer = Self {
+                ProcessFailed: core::mem::ManuallyDrop::new(arg0)
+            };
+
+            answer.set_discriminant(discriminant_Event::ProcessFailed);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `ProcessFailed` and convert it to `ProcessFailed`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `ProcessFailed`.
+            pub unsafe fn into_ProcessFailed(mut self) -> ProcessFailure {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::ProcessFailed);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.ProcessFailed,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `ProcessFailed` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `ProcessFailed`.
+            pub unsafe fn as_ProcessFailed(&self) -> &ProcessFailure {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::ProcessFailed);
+        let payload = &self.ProcessFailed;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `ProcessOutput`, with the appropriate payload
+    pub fn ProcessOutput(arg0: OutputLine) -> Self {
+            let mut answer = Self {
+                ProcessOutput: core::mem::ManuallyDrop::new(arg0)
+            };
+
+            answer.set_discriminant(discriminant_Event::ProcessOutput);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `ProcessOutput` and convert it to `ProcessOutput`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `ProcessOutput`.
+            pub unsafe fn into_ProcessOutput(mut self) -> OutputLine {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::ProcessOutput);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.ProcessOutput,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `ProcessOutput` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `ProcessOutput`.
+            pub unsafe fn as_ProcessOutput(&self) -> &OutputLine {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::ProcessOutput);
+        let payload = &self.ProcessOutput;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Resize`, with the appropriate payload
+    pub fn Resize(arg0: Bounds) -> Self {
+            let mut answer = Self {
+                Resize: arg0
+            };
+
+            answer.set_discriminant(discriminant_Event::Resize);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `Resize` and convert it to `Resize`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Resize`.
+            pub unsafe fn into_Resize(self) -> Bounds {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::Resize);
+        let payload = self.Resize;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `Resize` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Resize`.
+            pub unsafe fn as_Resize(&self) -> &Bounds {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::Resize);
+        let payload = &self.Resize;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named Resumed, which has no payload.
+    pub const Resumed: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<Event>()];
+
+        bytes[32] = discriminant_Event::Resumed as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<Event>()], Event>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the Resumed tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Resumed(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the Resumed tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Resumed(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named Suspended, which has no payload.
+    pub const Suspended: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<Event>()];
+
+        bytes[32] = discriminant_Event::Suspended as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<Event>()], Event>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the Suspended tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Suspended(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the Suspended tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Suspended(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Tick`, with the appropriate payload
+    pub fn Tick(arg0: TickInfo) -> Self {
+            let mut answer = Self {
+                Tick: arg0
+            };
+
+            answer.set_discriminant(discriminant_Event::Tick);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `Tick` and convert it to `Tick`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Tick`.
+            pub unsafe fn into_Tick(self) -> TickInfo {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::Tick);
+        let payload = self.Tick;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `Tick` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Tick`.
+            pub unsafe fn as_Tick(&self) -> &TickInfo {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::Tick);
+        let payload = &self.Tick;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Timer`, with the appropriate payload
+    pub fn Timer(arg: roc_std::RocStr) -> Self {
+            let mut answer = Self {
+                Timer: core::mem::ManuallyDrop::new(arg)
+            };
+
+            answer.set_discriminant(discriminant_Event::Timer);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `Timer` and convert it to `Timer`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Timer`.
+            pub unsafe fn into_Timer(mut self) -> roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::Timer);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.Timer,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Event` has a `.discriminant()` of `Timer` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Timer`.
+            pub unsafe fn as_Timer(&self) -> &roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Event::Timer);
+        let payload = &self.Timer;
+
+        &payload
+    }
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// Returns which variant this tag union holds. Note that this never includes a payload!
+    pub fn discriminant(&self) -> discriminant_Event {
+        unsafe {
+            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
+
+            core::mem::transmute::<u8, discriminant_Event>(*bytes.as_ptr().add(64))
+        }
+    }
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// Internal helper
+    fn set_discriminant(&mut self, discriminant: discriminant_Event) {
+        let discriminant_ptr: *mut discriminant_Event = (self as *mut Event).cast();
+
+        unsafe {
+            *(discriminant_ptr.add(64)) = discriminant;
+        }
+    }
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named ClipboardUnavailable, which has no payload.
+    pub const ClipboardUnavailable: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<Event>()];
+
+        bytes[64] = discriminant_Event::ClipboardUnavailable as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<Event>()], Event>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named FocusGained, which has no payload.
+    pub const FocusGained: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<Event>()];
+
+        bytes[64] = discriminant_Event::FocusGained as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<Event>()], Event>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named FocusLost, which has no payload.
+    pub const FocusLost: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<Event>()];
+
+        bytes[64] = discriminant_Event::FocusLost as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<Event>()], Event>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named Resumed, which has no payload.
+    pub const Resumed: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<Event>()];
+
+        bytes[64] = discriminant_Event::Resumed as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<Event>()], Event>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named Suspended, which has no payload.
+    pub const Suspended: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<Event>()];
+
+        bytes[64] = discriminant_Event::Suspended as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<Event>()], Event>(bytes)
+    };
+}
+
+impl Drop for Event {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn drop(&mut self) {
+        // Drop the payloads
+                    match self.discriminant() {
+                discriminant_Event::ClipboardRead => unsafe { core::mem::ManuallyDrop::drop(&mut self.ClipboardRead) },
+                discriminant_Event::ClipboardUnavailable => {}
+                discriminant_Event::DirListed => unsafe { core::mem::ManuallyDrop::drop(&mut self.DirListed) },
+                discriminant_Event::EditCancelled => unsafe { core::mem::ManuallyDrop::drop(&mut self.EditCancelled) },
+                discriminant_Event::EditFailed => unsafe { core::mem::ManuallyDrop::drop(&mut self.EditFailed) },
+                discriminant_Event::Edited => unsafe { core::mem::ManuallyDrop::drop(&mut self.Edited) },
+                discriminant_Event::FileDone => unsafe { core::mem::ManuallyDrop::drop(&mut self.FileDone) },
+                discriminant_Event::FileFailed => unsafe { core::mem::ManuallyDrop::drop(&mut self.FileFailed) },
+                discriminant_Event::FileLinesRead => unsafe { core::mem::ManuallyDrop::drop(&mut self.FileLinesRead) },
+                discriminant_Event::FileRead => unsafe { core::mem::ManuallyDrop::drop(&mut self.FileRead) },
+                discriminant_Event::FocusGained => {}
+                discriminant_Event::FocusLost => {}
+                discriminant_Event::KeyPressed => unsafe { core::mem::ManuallyDrop::drop(&mut self.KeyPressed) },
+                discriminant_Event::Mouse => unsafe { core::mem::ManuallyDrop::drop(&mut self.Mouse) },
+                discriminant_Event::Paste => unsafe { core::mem::ManuallyDrop::drop(&mut self.Paste) },
+                discriminant_Event::ProcessExited => unsafe { core::mem::ManuallyDrop::drop(&mut self.ProcessExited) },
+                discriminant_Event::ProcessFailed => unsafe { core::mem::ManuallyDrop::drop(&mut self.ProcessFailed) },
+                discriminant_Event::ProcessOutput => unsafe { core::mem::ManuallyDrop::drop(&mut self.ProcessOutput) },
+                discriminant_Event::Resize => {}
+                discriminant_Event::Resumed => {}
+                discriminant_Event::Suspended => {}
+                discriminant_Event::Tick => {}
+                discriminant_Event::Timer => unsafe { core::mem::ManuallyDrop::drop(&mut self.Timer) },
+            }
+
+    }
+}
+
+impl Eq for Event {}
+
+impl PartialEq for Event {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn eq(&self, other: &Self) -> bool {
+            if self.discriminant() != other.discriminant() {
+                return false;
+            }
+
+            unsafe {
+            match self.discriminant() {
+                discriminant_Event::ClipboardRead => self.ClipboardRead == other.ClipboardRead,
+                discriminant_Event::ClipboardUnavailable => true,
+                discriminant_Event::DirListed => self.DirListed == other.DirListed,
+                discriminant_Event::EditCancelled => self.EditCancelled == other.EditCancelled,
+                discriminant_Event::EditFailed => self.EditFailed == other.EditFailed,
+                discriminant_Event::Edited => self.Edited == other.Edited,
+                discriminant_Event::FileDone => self.FileDone == other.FileDone,
+                discriminant_Event::FileFailed => self.FileFailed == other.FileFailed,
+                discriminant_Event::FileLinesRead => self.FileLinesRead == other.FileLinesRead,
+                discriminant_Event::FileRead => self.FileRead == other.FileRead,
+                discriminant_Event::FocusGained => true,
+                discriminant_Event::FocusLost => true,
+                discriminant_Event::KeyPressed => self.KeyPressed == other.KeyPressed,
+                discriminant_Event::Mouse => self.Mouse == other.Mouse,
+                discriminant_Event::Paste => self.Paste == other.Paste,
+                discriminant_Event::ProcessExited => self.ProcessExited == other.ProcessExited,
+                discriminant_Event::ProcessFailed => self.ProcessFailed == other.ProcessFailed,
+                discriminant_Event::ProcessOutput => self.ProcessOutput == other.ProcessOutput,
+                discriminant_Event::Resize => self.Resize == other.Resize,
+                discriminant_Event::Resumed => true,
+                discriminant_Event::Suspended => true,
+                discriminant_Event::Tick => self.Tick == other.Tick,
+                discriminant_Event::Timer => self.Timer == other.Timer,
+            }
+        }
+    }
+}
+
+impl PartialOrd for Event {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match self.discriminant().partial_cmp(&other.discriminant()) {
+            Some(core::cmp::Ordering::Equal) => {}
+            not_eq => return not_eq,
+        }
+
+        unsafe {
+            match self.discriminant() {
+                discriminant_Event::ClipboardRead => self.ClipboardRead.partial_cmp(&other.ClipboardRead),
+                discriminant_Event::ClipboardUnavailable => Some(core::cmp::Ordering::Equal),
+                discriminant_Event::DirListed => self.DirListed.partial_cmp(&other.DirListed),
+                discriminant_Event::EditCancelled => self.EditCancelled.partial_cmp(&other.EditCancelled),
+                discriminant_Event::EditFailed => self.EditFailed.partial_cmp(&other.EditFailed),
+                discriminant_Event::Edited => self.Edited.partial_cmp(&other.Edited),
+                discriminant_Event::FileDone => self.FileDone.partial_cmp(&other.FileDone),
+                discriminant_Event::FileFailed => self.FileFailed.partial_cmp(&other.FileFailed),
+                discriminant_Event::FileLinesRead => self.FileLinesRead.partial_cmp(&other.FileLinesRead),
+                discriminant_Event::FileRead => self.FileRead.partial_cmp(&other.FileRead),
+                discriminant_Event::FocusGained => Some(core::cmp::Ordering::Equal),
+                discriminant_Event::FocusLost => Some(core::cmp::Ordering::Equal),
+                discriminant_Event::KeyPressed => self.KeyPressed.partial_cmp(&other.KeyPressed),
+                discriminant_Event::Mouse => self.Mouse.partial_cmp(&other.Mouse),
+                discriminant_Event::Paste => self.Paste.partial_cmp(&other.Paste),
+                discriminant_Event::ProcessExited => self.ProcessExited.partial_cmp(&other.ProcessExited),
+                discriminant_Event::ProcessFailed => self.ProcessFailed.partial_cmp(&other.ProcessFailed),
+                discriminant_Event::ProcessOutput => self.ProcessOutput.partial_cmp(&other.ProcessOutput),
+                discriminant_Event::Resize => self.Resize.partial_cmp(&other.Resize),
+                discriminant_Event::Resumed => Some(core::cmp::Ordering::Equal),
+                discriminant_Event::Suspended => Some(core::cmp::Ordering::Equal),
+                discriminant_Event::Tick => self.Tick.partial_cmp(&other.Tick),
+                discriminant_Event::Timer => self.Timer.partial_cmp(&other.Timer),
+            }
+        }
+    }
+}
+
+impl Ord for Event {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            match self.discriminant().cmp(&other.discriminant()) {
+                core::cmp::Ordering::Equal => {}
+                not_eq => return not_eq,
+            }
+
+            unsafe {
+            match self.discriminant() {
+                discriminant_Event::ClipboardRead => self.ClipboardRead.cmp(&other.ClipboardRead),
+                discriminant_Event::ClipboardUnavailable => core::cmp::Ordering::Equal,
+                discriminant_Event::DirListed => self.DirListed.cmp(&other.DirListed),
+                discriminant_Event::EditCancelled => self.EditCancelled.cmp(&other.EditCancelled),
+                discriminant_Event::EditFailed => self.EditFailed.cmp(&other.EditFailed),
+                discriminant_Event::Edited => self.Edited.cmp(&other.Edited),
+                discriminant_Event::FileDone => self.FileDone.cmp(&other.FileDone),
+                discriminant_Event::FileFailed => self.FileFailed.cmp(&other.FileFailed),
+                discriminant_Event::FileLinesRead => self.FileLinesRead.cmp(&other.FileLinesRead),
+                discriminant_Event::FileRead => self.FileRead.cmp(&other.FileRead),
+                discriminant_Event::FocusGained => core::cmp::Ordering::Equal,
+                discriminant_Event::FocusLost => core::cmp::Ordering::Equal,
+                discriminant_Event::KeyPressed => self.KeyPressed.cmp(&other.KeyPressed),
+                discriminant_Event::Mouse => self.Mouse.cmp(&other.Mouse),
+                discriminant_Event::Paste => self.Paste.cmp(&other.Paste),
+                discriminant_Event::ProcessExited => self.ProcessExited.cmp(&other.ProcessExited),
+                discriminant_Event::ProcessFailed => self.ProcessFailed.cmp(&other.ProcessFailed),
+                discriminant_Event::ProcessOutput => self.ProcessOutput.cmp(&other.ProcessOutput),
+                discriminant_Event::Resize => self.Resize.cmp(&other.Resize),
+                discriminant_Event::Resumed => core::cmp::Ordering::Equal,
+                discriminant_Event::Suspended => core::cmp::Ordering::Equal,
+                discriminant_Event::Tick => self.Tick.cmp(&other.Tick),
+                discriminant_Event::Timer => self.Timer.cmp(&other.Timer),
+            }
+        }
+    }
+}
+
+impl Clone for Event {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn clone(&self) -> Self {
+        let mut answer = unsafe {
+            match self.discriminant() {
+                discriminant_Event::ClipboardRead => Self {
+                    ClipboardRead: self.ClipboardRead.clone(),
+                },
+                discriminant_Event::ClipboardUnavailable => core::mem::transmute::<
+                    core::mem::MaybeUninit<Event>,
+                    Event,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_Event::DirListed => Self {
+                    DirListed: self.DirListed.clone(),
+                },
+                discriminant_Event::EditCancelled => Self {
+                    EditCancelled: self.EditCancelled.clone(),
+                },
+                discriminant_Event::EditFailed => Self {
+                    EditFailed: self.EditFailed.clone(),
+                },
+                discriminant_Event::Edited => Self {
+                    Edited: self.Edited.clone(),
+                },
+                discriminant_Event::FileDone => Self {
+                    FileDone: self.FileDone.clone(),
+                },
+                discriminant_Event::FileFailed => Self {
+                    FileFailed: self.FileFailed.clone(),
+                },
+                discriminant_Event::FileLinesRead => Self {
+                    FileLinesRead: self.FileLinesRead.clone(),
+                },
+                discriminant_Event::FileRead => Self {
+                    FileRead: self.FileRead.clone(),
+                },
+                discriminant_Event::FocusGained => core::mem::transmute::<
+                    core::mem::MaybeUninit<Event>,
+                    Event,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_Event::FocusLost => core::mem::transmute::<
+                    core::mem::MaybeUninit<Event>,
+                    Event,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_Event::KeyPressed => Self {
+                    KeyPressed: self.KeyPressed.clone(),
+                },
+                discriminant_Event::Mouse => Self {
+                    Mouse: self.Mouse.clone(),
+                },
+                discriminant_Event::Paste => Self {
+                    Paste: self.Paste.clone(),
+                },
+                discriminant_Event::ProcessExited => Self {
+                    ProcessExited: self.ProcessExited.clone(),
+                },
+                discriminant_Event::ProcessFailed => Self {
+                    ProcessFailed: self.ProcessFailed.clone(),
+                },
+                discriminant_Event::ProcessOutput => Self {
+                    ProcessOutput: self.ProcessOutput.clone(),
+                },
+                discriminant_Event::Resize => Self {
+                    Resize: self.Resize.clone(),
+                },
+                discriminant_Event::Resumed => core::mem::transmute::<
+                    core::mem::MaybeUninit<Event>,
+                    Event,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_Event::Suspended => core::mem::transmute::<
+                    core::mem::MaybeUninit<Event>,
+                    Event,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_Event::Tick => Self {
+                    Tick: self.Tick.clone(),
+                },
+                discriminant_Event::Timer => Self {
+                    Timer: self.Timer.clone(),
+                },
+            }
+
+        };
+
+        answer.set_discriminant(self.discriminant());
+
+        answer
+    }
+}
+
+impl core::hash::Hash for Event {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
+            discriminant_Event::ClipboardRead => unsafe {
+                    discriminant_Event::ClipboardRead.hash(state);
+                    self.ClipboardRead.hash(state);
+                },
+            discriminant_Event::ClipboardUnavailable => discriminant_Event::ClipboardUnavailable.hash(state),
+            discriminant_Event::DirListed => unsafe {
+                    discriminant_Event::DirListed.hash(state);
+                    self.DirListed.hash(state);
+                },
+            discriminant_Event::EditCancelled => unsafe {
+                    discriminant_Event::EditCancelled.hash(state);
+                    self.EditCancelled.hash(state);
+                },
+            discriminant_Event::EditFailed => unsafe {
+                    discriminant_Event::EditFailed.hash(state);
+                    self.EditFailed.hash(state);
+                },
+            discriminant_Event::Edited => unsafe {
+                    discriminant_Event::Edited.hash(state);
+                    self.Edited.hash(state);
+                },
+            discriminant_Event::FileDone => unsafe {
+                    discriminant_Event::FileDone.hash(state);
+                    self.FileDone.hash(state);
+                },
+            discriminant_Event::FileFailed => unsafe {
+                    discriminant_Event::FileFailed.hash(state);
+                    self.FileFailed.hash(state);
+                },
+            discriminant_Event::FileLinesRead => unsafe {
+                    discriminant_Event::FileLinesRead.hash(state);
+                    self.FileLinesRead.hash(state);
+                },
+            discriminant_Event::FileRead => unsafe {
+                    discriminant_Event::FileRead.hash(state);
+                    self.FileRead.hash(state);
+                },
+            discriminant_Event::FocusGained => discriminant_Event::FocusGained.hash(state),
+            discriminant_Event::FocusLost => discriminant_Event::FocusLost.hash(state),
+            discriminant_Event::KeyPressed => unsafe {
+                    discriminant_Event::KeyPressed.hash(state);
+                    self.KeyPressed.hash(state);
+                },
+            discriminant_Event::Mouse => unsafe {
+                    discriminant_Event::Mouse.hash(state);
+                    self.Mouse.hash(state);
+                },
+            discriminant_Event::Paste => unsafe {
+                    discriminant_Event::Paste.hash(state);
+                    self.Paste.hash(state);
+                },
+            discriminant_Event::ProcessExited => unsafe {
+                    discriminant_Event::ProcessExited.hash(state);
+                    self.ProcessExited.hash(state);
+                },
+            discriminant_Event::ProcessFailed => unsafe {
+                    discriminant_Event::ProcessFailed.hash(state);
+                    self.ProcessFailed.hash(state);
+                },
+            discriminant_Event::ProcessOutput => unsafe {
+                    discriminant_Event::ProcessOutput.hash(state);
+                    self.ProcessOutput.hash(state);
+                },
+            discriminant_Event::Resize => unsafe {
+                    discriminant_Event::Resize.hash(state);
+                    self.Resize.hash(state);
+                },
+            discriminant_Event::Resumed => discriminant_Event::Resumed.hash(state),
+            discriminant_Event::Suspended => discriminant_Event::Suspended.hash(state),
+            discriminant_Event::Tick => unsafe {
+                    discriminant_Event::Tick.hash(state);
+                    self.Tick.hash(state);
+                },
+            discriminant_Event::Timer => unsafe {
+                    discriminant_Event::Timer.hash(state);
+                    self.Timer.hash(state);
+                },
+        }
+    }
+}
+
+impl core::fmt::Debug for Event {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Event::")?;
+
+        unsafe {
+            match self.discriminant() {
+                discriminant_Event::ClipboardRead => f.debug_tuple("ClipboardRead")
+        .field(&*self.ClipboardRead)
+        .finish(),
+                discriminant_Event::ClipboardUnavailable => f.write_str("ClipboardUnavailable"),
+                discriminant_Event::DirListed => f.debug_tuple("DirListed")
+        .field(&*self.DirListed)
+        .finish(),
+                discriminant_Event::EditCancelled => f.debug_tuple("EditCancelled")
+        .field(&*self.EditCancelled)
+        .finish(),
+                discriminant_Event::EditFailed => f.debug_tuple("EditFailed")
+        .field(&*self.EditFailed)
+        .finish(),
+                discriminant_Event::Edited => f.debug_tuple("Edited")
+        .field(&*self.Edited)
+        .finish(),
+                discriminant_Event::FileDone => f.debug_tuple("FileDone")
+        .field(&*self.FileDone)
+        .finish(),
+                discriminant_Event::FileFailed => f.debug_tuple("FileFailed")
+        .field(&*self.FileFailed)
+        .finish(),
+                discriminant_Event::FileLinesRead => f.debug_tuple("FileLinesRead")
+        .field(&*self.FileLinesRead)
+        .finish(),
+                discriminant_Event::FileRead => f.debug_tuple("FileRead")
+        .field(&*self.FileRead)
+        .finish(),
+                discriminant_Event::FocusGained => f.write_str("FocusGained"),
+                discriminant_Event::FocusLost => f.write_str("FocusLost"),
+                discriminant_Event::KeyPressed => f.debug_tuple("KeyPressed")
+        .field(&*self.KeyPressed)
+        .finish(),
+                discriminant_Event::Mouse => f.debug_tuple("Mouse")
+        .field(&*self.Mouse)
+        .finish(),
+                discriminant_Event::Paste => f.debug_tuple("Paste")
+        .field(&*self.Paste)
+        .finish(),
+                discriminant_Event::ProcessExited => f.debug_tuple("ProcessExited")
+        .field(&*self.ProcessExited)
+        .finish(),
+                discriminant_Event::ProcessFailed => f.debug_tuple("ProcessFailed")
+        .field(&*self.ProcessFailed)
+        .finish(),
+                discriminant_Event::ProcessOutput => f.debug_tuple("ProcessOutput")
+        .field(&*self.ProcessOutput)
+        .finish(),
+                discriminant_Event::Resize => f.debug_tuple("Resize")
+        .field(&self.Resize)
+        .finish(),
+                discriminant_Event::Resumed => f.write_str("Resumed"),
+                discriminant_Event::Suspended => f.write_str("Suspended"),
+                discriminant_Event::Tick => f.debug_tuple("Tick")
+        .field(&self.Tick)
+        .finish(),
+                discriminant_Event::Timer => f.debug_tuple("Timer")
+        .field(&*self.Timer)
+        .finish(),
+            }
+        }
+    }
+}
+
+impl Constraint {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Returns which variant this tag union holds. Note that this never includes a payload!
+    pub fn discriminant(&self) -> discriminant_Constraint {
+        unsafe {
+            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
+
+            core::mem::transmute::<u8, discriminant_Constraint>(*bytes.as_ptr().add(8))
+        }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Internal helper
+    fn set_discriminant(&mut self, discriminant: discriminant_Constraint) {
+        let discriminant_ptr: *mut discriminant_Constraint = (self as *mut Constraint).cast();
+
+        unsafe {
+            *(discriminant_ptr.add(8)) = discriminant;
+        }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Length`, with the appropriate payload
+    pub fn Length(arg: u16) -> Self {
+            let mut answer = Self {
+                Length: arg
+            };
+
+            answer.set_discriminant(discriminant_Constraint::Length);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Length` and convert it to `Length`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Length`.
+            pub unsafe fn into_Length(self) -> u16 {
+                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Length);
+        let payload = self.Length;
+
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Length` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Length`.
+            pub unsafe fn as_Length(&self) -> &u16 {
+                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Length);
+        let payload = &self.Length;
+
+        &payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Max`, with the appropriate payload
+    pub fn Max(arg: u16) -> Self {
+            let mut answer = Self {
+                Max: arg
+            };
+
+            answer.set_discriminant(discriminant_Constraint::Max);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Max` and convert it to `Max`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Max`.
+            pub unsafe fn into_Max(self) -> u16 {
+                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Max);
+        let payload = self.Max;
+
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Max` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Max`.
+            pub unsafe fn as_Max(&self) -> &u16 {
+                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Max);
+        let payload = &self.Max;
+
+        &payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Min`, with the appropriate payload
+    pub fn Min(arg: u16) -> Self {
+            let mut answer = Self {
+                Min: arg
+            };
+
+            answer.set_discriminant(discriminant_Constraint::Min);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Min` and convert it to `Min`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Min`.
+            pub unsafe fn into_Min(self) -> u16 {
+                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Min);
+        let payload = self.Min;
+
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Min` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Min`.
+            pub unsafe fn as_Min(&self) -> &u16 {
+                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Min);
+        let payload = &self.Min;
+
+        &payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Percentage`, with the appropriate payload
+    pub fn Percentage(arg: u16) -> Self {
+            let mut answer = Self {
+                Percentage: arg
+            };
+
+            answer.set_discriminant(discriminant_Constraint::Percentage);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Percentage` and convert it to `Percentage`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Percentage`.
+            pub unsafe fn into_Percentage(self) -> u16 {
+                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Percentage);
+        let payload = self.Percentage;
+
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Percentage` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Percentage`.
+            pub unsafe fn as_Percentage(&self) -> &u16 {
+                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Percentage);
+        let payload = &self.Percentage;
+
+        &payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Ratio`, with the appropriate payload
+    pub fn Ratio(arg0: u32, arg1: u32) -> Self {
+            let mut answer = Self {
+                Ratio: Constraint_Ratio {
+                    f0: arg0,
+                    f1: arg1,
+                }
+            };
+
+            answer.set_discriminant(discriminant_Constraint::Ratio);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Ratio` and convert it to `Ratio`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Ratio`.
+            pub unsafe fn into_Ratio(self) -> (u32, u32) {
+                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Ratio);
+        let payload = self.Ratio;
+
+        (
+            payload.f0, 
+            payload.f1
+        )
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Ratio` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Ratio`.
+            pub unsafe fn as_Ratio(&self) -> (&u32, &u32) {
+                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Ratio);
+        let payload = &self.Ratio;
+
+        (
+            &payload.f0, 
+            &payload.f1
+        )
+    }
+}
+
+impl Eq for Constraint {}
+
+impl PartialEq for Constraint {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn eq(&self, other: &Self) -> bool {
+            if self.discriminant() != other.discriminant() {
+                return false;
+            }
+
+            unsafe {
+            match self.discriminant() {
+                discriminant_Constraint::Length => self.Length == other.Length,
+                discriminant_Constraint::Max => self.Max == other.Max,
+                discriminant_Constraint::Min => self.Min == other.Min,
+                discriminant_Constraint::Percentage => self.Percentage == other.Percentage,
+                discriminant_Constraint::Ratio => self.Ratio == other.Ratio,
+            }
+        }
+    }
+}
+
+impl PartialOrd for Constraint {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match self.discriminant().partial_cmp(&other.discriminant()) {
+            Some(core::cmp::Ordering::Equal) => {}
+            not_eq => return not_eq,
+        }
+
+        unsafe {
+            match self.discriminant() {
+                discriminant_Constraint::Length => self.Length.partial_cmp(&other.Length),
+                discriminant_Constraint::Max => self.Max.partial_cmp(&other.Max),
+                discriminant_Constraint::Min => self.Min.partial_cmp(&other.Min),
+                discriminant_Constraint::Percentage => self.Percentage.partial_cmp(&other.Percentage),
+                discriminant_Constraint::Ratio => self.Ratio.partial_cmp(&other.Ratio),
+            }
+        }
+    }
+}
+
+impl Ord for Constraint {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            match self.discriminant().cmp(&other.discriminant()) {
+                core::cmp::Ordering::Equal => {}
+                not_eq => return not_eq,
+            }
+
+            unsafe {
+            match self.discriminant() {
+                discriminant_Constraint::Length => self.Length.cmp(&other.Length),
+                discriminant_Constraint::Max => self.Max.cmp(&other.Max),
+                discriminant_Constraint::Min => self.Min.cmp(&other.Min),
+                discriminant_Constraint::Percentage => self.Percentage.cmp(&other.Percentage),
+                discriminant_Constraint::Ratio => self.Ratio.cmp(&other.Ratio),
+            }
+        }
+    }
+}
+
+impl Copy for Constraint {}
+
+impl Clone for Constraint {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn clone(&self) -> Self {
+        let mut answer = unsafe {
+            match self.discriminant() {
+                discriminant_Constraint::Length => Self {
+                    Length: self.Length.clone(),
+                },
+                discriminant_Constraint::Max => Self {
+                    Max: self.Max.clone(),
+                },
+                discriminant_Constraint::Min => Self {
+                    Min: self.Min.clone(),
+                },
+                discriminant_Constraint::Percentage => Self {
+                    Percentage: self.Percentage.clone(),
+                },
+                discriminant_Constraint::Ratio => Self {
+                    Ratio: self.Ratio.clone(),
+                },
+            }
+
+        };
+
+        answer.set_discriminant(self.discriminant());
+
+        answer
+    }
+}
+
+impl core::hash::Hash for Constraint {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
+            discriminant_Constraint::Length => unsafe {
+                    discriminant_Constraint::Length.hash(state);
+                    self.Length.hash(state);
+                },
+            discriminant_Constraint::Max => unsafe {
+                    discriminant_Constraint::Max.hash(state);
+                    self.Max.hash(state);
+                },
+            discriminant_Constraint::Min => unsafe {
+                    discriminant_Constraint::Min.hash(state);
+                    self.Min.hash(state);
+                },
+            discriminant_Constraint::Percentage => unsafe {
+                    discriminant_Constraint::Percentage.hash(state);
+                    self.Percentage.hash(state);
+                },
+            discriminant_Constraint::Ratio => unsafe {
+                    discriminant_Constraint::Ratio.hash(state);
+                    self.Ratio.hash(state);
+                },
+        }
+    }
+}
+
+impl core::fmt::Debug for Constraint {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Constraint::")?;
+
+        unsafe {
+            match self.discriminant() {
+                discriminant_Constraint::Length => f.debug_tuple("Length")
+        .field(&self.Length)
+        .finish(),
+                discriminant_Constraint::Max => f.debug_tuple("Max")
+        .field(&self.Max)
+        .finish(),
+                discriminant_Constraint::Min => f.debug_tuple("Min")
+        .field(&self.Min)
+        .finish(),
+                discriminant_Constraint::Percentage => f.debug_tuple("Percentage")
+        .field(&self.Percentage)
+        .finish(),
+                discriminant_Constraint::Ratio => f.debug_tuple("Ratio")
+        .field(&(&self.Ratio).f0)
+.field(&(&self.Ratio).f1)
+        .finish(),
+            }
+        }
+    }
+}
+
+impl BackgroundColor {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Returns which variant this tag union holds. Note that this never includes a payload!
+    pub fn discriminant(&self) -> discriminant_BackgroundColor {
+        unsafe {
+            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
+
+            core::mem::transmute::<u8, discriminant_BackgroundColor>(*bytes.as_ptr().add(3))
+        }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Internal helper
+    fn set_discriminant(&mut self, discriminant: discriminant_BackgroundColor) {
+        let discriminant_ptr: *mut discriminant_BackgroundColor = (self as *mut BackgroundColor).cast();
+
+        unsafe {
+            *(discriminant_ptr.add(3)) = discriminant;
+        }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Rgb`, with the appropriate payload
+    pub fn Rgb(arg0: u8, arg1: u8, arg2: u8) -> Self {
+            let mut answer = Self {
+                Rgb: BackgroundColor_Rgb {
+                    f0: arg0,
+                    f1: arg1,
+                    f2: arg2,
+                }
+            };
+
+            answer.set_discriminant(discriminant_BackgroundColor::Rgb);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `BackgroundColor` has a `.discriminant()` of `Rgb` and convert it to `Rgb`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Rgb`.
+            pub unsafe fn into_Rgb(self) -> (u8, u8, u8) {
+                debug_assert_eq!(self.discriminant(), discriminant_BackgroundColor::Rgb);
+        let payload = self.Rgb;
+
+        (
+            payload.f0, 
+            payload.f1, 
+            payload.f2
+        )
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `BackgroundColor` has a `.discriminant()` of `Rgb` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Rgb`.
+            pub unsafe fn as_Rgb(&self) -> (&u8, &u8, &u8) {
+                debug_assert_eq!(self.discriminant(), discriminant_BackgroundColor::Rgb);
+        let payload = &self.Rgb;
+
+        (
+            &payload.f0, 
+            &payload.f1, 
+            &payload.f2
+        )
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named Unknown, which has no payload.
+    pub const Unknown: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<BackgroundColor>()];
+
+        bytes[3] = discriminant_BackgroundColor::Unknown as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<BackgroundColor>()], BackgroundColor>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the Unknown tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Unknown(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the Unknown tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Unknown(&self) {
+        ()
+    }
+}
+
+impl Eq for BackgroundColor {}
+
+impl PartialEq for BackgroundColor {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn eq(&self, other: &Self) -> bool {
+            if self.discriminant() != other.discriminant() {
+                return false;
+            }
+
+            unsafe {
+            match self.discriminant() {
+                discriminant_BackgroundColor::Rgb => self.Rgb == other.Rgb,
+                discriminant_BackgroundColor::Unknown => true,
+            }
+        }
+    }
+}
+
+impl PartialOrd for BackgroundColor {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match self.discriminant().partial_cmp(&other.discriminant()) {
+            Some(core::cmp::Ordering::Equal) => {}
+            not_eq => return not_eq,
+        }
+
+        unsafe {
+            match self.discriminant() {
+                discriminant_BackgroundColor::Rgb => self.Rgb.partial_cmp(&other.Rgb),
+                discriminant_BackgroundColor::Unknown => Some(core::cmp::Ordering::Equal),
+            }
+        }
+    }
+}
+
+impl Ord for BackgroundColor {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            match self.discriminant().cmp(&other.discriminant()) {
+                core::cmp::Ordering::Equal => {}
+                not_eq => return not_eq,
             }
+
+            unsafe {
+            match self.discriminant() {
+                discriminant_BackgroundColor::Rgb => self.Rgb.cmp(&other.Rgb),
+                discriminant_BackgroundColor::Unknown => core::cmp::Ordering::Equal,
+            }
+        }
+    }
+}
+
+impl Copy for BackgroundColor {}
+
+impl Clone for BackgroundColor {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn clone(&self) -> Self {
+        let mut answer = unsafe {
+            match self.discriminant() {
+                discriminant_BackgroundColor::Rgb => Self {
+                    Rgb: self.Rgb.clone(),
+                },
+                discriminant_BackgroundColor::Unknown => core::mem::transmute::<
+                    core::mem::MaybeUninit<BackgroundColor>,
+                    BackgroundColor,
+                >(core::mem::MaybeUninit::uninit()),
+            }
+
+        };
+
+        answer.set_discriminant(self.discriminant());
+
+        answer
+    }
+}
+
+impl core::hash::Hash for BackgroundColor {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
+            discriminant_BackgroundColor::Rgb => unsafe {
+                    discriminant_BackgroundColor::Rgb.hash(state);
+                    self.Rgb.hash(state);
+                },
+            discriminant_BackgroundColor::Unknown => discriminant_BackgroundColor::Unknown.hash(state),
+        }
+    }
+}
+
+impl core::fmt::Debug for BackgroundColor {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("BackgroundColor::")?;
+
+        unsafe {
+            match self.discriminant() {
+                discriminant_BackgroundColor::Rgb => f.debug_tuple("Rgb")
+        .field(&(&self.Rgb).f0)
+.field(&(&self.Rgb).f1)
+.field(&(&self.Rgb).f2)
+        .finish(),
+                discriminant_BackgroundColor::Unknown => f.write_str("Unknown"),
+            }
+        }
+    }
+}
+
+impl KeyCode {
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// Returns which variant this tag union holds. Note that this never includes a payload!
+    pub fn discriminant(&self) -> discriminant_KeyCode {
+        unsafe {
+            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
+
+            core::mem::transmute::<u8, discriminant_KeyCode>(*bytes.as_ptr().add(12))
+        }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// Internal helper
+    fn set_discriminant(&mut self, discriminant: discriminant_KeyCode) {
+        let discriminant_ptr: *mut discriminant_KeyCode = (self as *mut KeyCode).cast();
+
+        unsafe {
+            *(discriminant_ptr.add(12)) = discriminant;
+        }
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named BackTab, which has no payload.
+    pub const BackTab: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::BackTab as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the BackTab tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_BackTab(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the BackTab tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_BackTab(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named Backspace, which has no payload.
+    pub const Backspace: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::Backspace as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the Backspace tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Backspace(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the Backspace tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Backspace(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named CapsLock, which has no payload.
+    pub const CapsLock: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::CapsLock as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the CapsLock tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_CapsLock(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the CapsLock tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_CapsLock(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named Delete, which has no payload.
+    pub const Delete: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::Delete as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the Delete tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Delete(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the Delete tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Delete(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named Down, which has no payload.
+    pub const Down: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::Down as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the Down tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Down(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the Down tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Down(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named End, which has no payload.
+    pub const End: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::End as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the End tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_End(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the End tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_End(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named Enter, which has no payload.
+    pub const Enter: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::Enter as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the Enter tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Enter(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the Enter tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Enter(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named Esc, which has no payload.
+    pub const Esc: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::Esc as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the Esc tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Esc(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the Esc tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Esc(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Function`, with the appropriate payload
+    pub fn Function(arg: u8) -> Self {
+            let mut answer = Self {
+                Function: arg
+            };
+
+            answer.set_discriminant(discriminant_KeyCode::Function);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `KeyCode` has a `.discriminant()` of `Function` and convert it to `Function`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Function`.
+            pub unsafe fn into_Function(self) -> u8 {
+                debug_assert_eq!(self.discriminant(), discriminant_KeyCode::Function);
+        let payload = self.Function;
+
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `KeyCode` has a `.discriminant()` of `Function` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Function`.
+            pub unsafe fn as_Function(&self) -> &u8 {
+                debug_assert_eq!(self.discriminant(), discriminant_KeyCode::Function);
+        let payload = &self.Function;
+
+        &payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named Home, which has no payload.
+    pub const Home: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::Home as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the Home tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Home(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the Home tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Home(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named Insert, which has no payload.
+    pub const Insert: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::Insert as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the Insert tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Insert(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the Insert tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Insert(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named KeypadBegin, which has no payload.
+    pub const KeypadBegin: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::KeypadBegin as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the KeypadBegin tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_KeypadBegin(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the KeypadBegin tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_KeypadBegin(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named Left, which has no payload.
+    pub const Left: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::Left as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the Left tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Left(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the Left tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Left(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Media`, with the appropriate payload
+    pub fn Media(arg: MediaKeyCode) -> Self {
+            let mut answer = Self {
+                Media: arg
+            };
+
+            answer.set_discriminant(discriminant_KeyCode::Media);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `KeyCode` has a `.discriminant()` of `Media` and convert it to `Media`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Media`.
+            pub unsafe fn into_Media(self) -> MediaKeyCode {
+                debug_assert_eq!(self.discriminant(), discriminant_KeyCode::Media);
+        let payload = self.Media;
+
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `KeyCode` has a `.discriminant()` of `Media` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Media`.
+            pub unsafe fn as_Media(&self) -> &MediaKeyCode {
+                debug_assert_eq!(self.discriminant(), discriminant_KeyCode::Media);
+        let payload = &self.Media;
+
+        &payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named Menu, which has no payload.
+    pub const Menu: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::Menu as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the Menu tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Menu(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the Menu tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Menu(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Modifier`, with the appropriate payload
+    pub fn Modifier(arg: ModifierKeyCode) -> Self {
+            let mut answer = Self {
+                Modifier: arg
+            };
+
+            answer.set_discriminant(discriminant_KeyCode::Modifier);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `KeyCode` has a `.discriminant()` of `Modifier` and convert it to `Modifier`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Modifier`.
+            pub unsafe fn into_Modifier(self) -> ModifierKeyCode {
+                debug_assert_eq!(self.discriminant(), discriminant_KeyCode::Modifier);
+        let payload = self.Modifier;
+
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `KeyCode` has a `.discriminant()` of `Modifier` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Modifier`.
+            pub unsafe fn as_Modifier(&self) -> &ModifierKeyCode {
+                debug_assert_eq!(self.discriminant(), discriminant_KeyCode::Modifier);
+        let payload = &self.Modifier;
+
+        &payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named Null, which has no payload.
+    pub const Null: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::Null as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the Null tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Null(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the Null tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Null(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named NumLock, which has no payload.
+    pub const NumLock: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::NumLock as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the NumLock tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_NumLock(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the NumLock tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_NumLock(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named PageDown, which has no payload.
+    pub const PageDown: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::PageDown as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the PageDown tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_PageDown(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the PageDown tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_PageDown(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named PageUp, which has no payload.
+    pub const PageUp: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::PageUp as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the PageUp tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_PageUp(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the PageUp tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_PageUp(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named Pause, which has no payload.
+    pub const Pause: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::Pause as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the Pause tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Pause(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the Pause tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Pause(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named PrintScreen, which has no payload.
+    pub const PrintScreen: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::PrintScreen as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the PrintScreen tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_PrintScreen(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the PrintScreen tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_PrintScreen(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named Right, which has no payload.
+    pub const Right: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::Right as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the Right tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Right(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the Right tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Right(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `Scalar`, with the appropriate payload
+    pub fn Scalar(arg: roc_std::RocStr) -> Self {
+            let mut answer = Self {
+                Scalar: core::mem::ManuallyDrop::new(arg)
+            };
+
+            answer.set_discriminant(discriminant_KeyCode::Scalar);
+
+            answer
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `KeyCode` has a `.discriminant()` of `Scalar` and convert it to `Scalar`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Scalar`.
+            pub unsafe fn into_Scalar(mut self) -> roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_KeyCode::Scalar);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.Scalar,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Unsafely assume the given `KeyCode` has a `.discriminant()` of `Scalar` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Scalar`.
+            pub unsafe fn as_Scalar(&self) -> &roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_KeyCode::Scalar);
+        let payload = &self.Scalar;
+
+        &payload
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named ScrollLock, which has no payload.
+    pub const ScrollLock: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::ScrollLock as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the ScrollLock tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_ScrollLock(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the ScrollLock tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_ScrollLock(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named Tab, which has no payload.
+    pub const Tab: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::Tab as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the Tab tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Tab(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the Tab tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Tab(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named Up, which has no payload.
+    pub const Up: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[12] = discriminant_KeyCode::Up as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the Up tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Up(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the Up tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Up(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// Returns which variant this tag union holds. Note that this never includes a payload!
+    pub fn discriminant(&self) -> discriminant_KeyCode {
+        unsafe {
+            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
+
+            core::mem::transmute::<u8, discriminant_KeyCode>(*bytes.as_ptr().add(24))
+        }
+    }
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// Internal helper
+    fn set_discriminant(&mut self, discriminant: discriminant_KeyCode) {
+        let discriminant_ptr: *mut discriminant_KeyCode = (self as *mut KeyCode).cast();
+
+        unsafe {
+            *(discriminant_ptr.add(24)) = discriminant;
         }
     }
 
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named BackTab, which has no payload.
+    pub const BackTab: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::BackTab as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named Backspace, which has no payload.
+    pub const Backspace: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::Backspace as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named CapsLock, which has no payload.
+    pub const CapsLock: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::CapsLock as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named Delete, which has no payload.
+    pub const Delete: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::Delete as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named Down, which has no payload.
+    pub const Down: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::Down as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named End, which has no payload.
+    pub const End: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::End as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named Enter, which has no payload.
+    pub const Enter: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::Enter as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named Esc, which has no payload.
+    pub const Esc: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::Esc as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named Home, which has no payload.
+    pub const Home: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::Home as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named Insert, which has no payload.
+    pub const Insert: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::Insert as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named KeypadBegin, which has no payload.
+    pub const KeypadBegin: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::KeypadBegin as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named Left, which has no payload.
+    pub const Left: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::Left as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named Menu, which has no payload.
+    pub const Menu: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::Menu as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named Null, which has no payload.
+    pub const Null: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::Null as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named NumLock, which has no payload.
+    pub const NumLock: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::NumLock as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named PageDown, which has no payload.
+    pub const PageDown: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::PageDown as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named PageUp, which has no payload.
+    pub const PageUp: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::PageUp as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named Pause, which has no payload.
+    pub const Pause: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::Pause as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named PrintScreen, which has no payload.
+    pub const PrintScreen: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::PrintScreen as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named Right, which has no payload.
+    pub const Right: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::Right as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named ScrollLock, which has no payload.
+    pub const ScrollLock: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::ScrollLock as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named Tab, which has no payload.
+    pub const Tab: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::Tab as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// A tag named Up, which has no payload.
+    pub const Up: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+
+        bytes[24] = discriminant_KeyCode::Up as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+    };
+}
+
+impl Drop for KeyCode {
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// Returns which variant this tag union holds. Note that this never includes a payload!
-    pub fn discriminant(&self) -> discriminant_Elem {
-        // The discriminant is stored in the unused bytes at the end of the recursive pointer
-        unsafe { core::mem::transmute::<u8, discriminant_Elem>((self.pointer as u8) & 0b11) }
+    fn drop(&mut self) {
+        // Drop the payloads
+                    match self.discriminant() {
+                discriminant_KeyCode::BackTab => {}
+                discriminant_KeyCode::Backspace => {}
+                discriminant_KeyCode::CapsLock => {}
+                discriminant_KeyCode::Delete => {}
+                discriminant_KeyCode::Down => {}
+                discriminant_KeyCode::End => {}
+                discriminant_KeyCode::Enter => {}
+                discriminant_KeyCode::Esc => {}
+                discriminant_KeyCode::Function => {}
+                discriminant_KeyCode::Home => {}
+                discriminant_KeyCode::Insert => {}
+                discriminant_KeyCode::KeypadBegin => {}
+                discriminant_KeyCode::Left => {}
+                discriminant_KeyCode::Media => {}
+                discriminant_KeyCode::Menu => {}
+                discriminant_KeyCode::Modifier => {}
+                discriminant_KeyCode::Null => {}
+                discriminant_KeyCode::NumLock => {}
+                discriminant_KeyCode::PageDown => {}
+                discriminant_KeyCode::PageUp => {}
+                discriminant_KeyCode::Pause => {}
+                discriminant_KeyCode::PrintScreen => {}
+                discriminant_KeyCode::Right => {}
+                discriminant_KeyCode::Scalar => unsafe { core::mem::ManuallyDrop::drop(&mut self.Scalar) },
+                discriminant_KeyCode::ScrollLock => {}
+                discriminant_KeyCode::Tab => {}
+                discriminant_KeyCode::Up => {}
+            }
+
     }
+}
+
+impl Eq for KeyCode {}
 
+impl PartialEq for KeyCode {
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// Internal helper
-    fn tag_discriminant(pointer: *mut union_Elem, discriminant: discriminant_Elem) -> *mut union_Elem {
-        // The discriminant is stored in the unused bytes at the end of the union pointer
-        let untagged = (pointer as usize) & (!0b11 as usize);
-        let tagged = untagged | (discriminant as usize);
+    fn eq(&self, other: &Self) -> bool {
+            if self.discriminant() != other.discriminant() {
+                return false;
+            }
 
-        tagged as *mut union_Elem
+            unsafe {
+            match self.discriminant() {
+                discriminant_KeyCode::BackTab => true,
+                discriminant_KeyCode::Backspace => true,
+                discriminant_KeyCode::CapsLock => true,
+                discriminant_KeyCode::Delete => true,
+                discriminant_KeyCode::Down => true,
+                discriminant_KeyCode::End => true,
+                discriminant_KeyCode::Enter => true,
+                discriminant_KeyCode::Esc => true,
+                discriminant_KeyCode::Function => self.Function == other.Function,
+                discriminant_KeyCode::Home => true,
+                discriminant_KeyCode::Insert => true,
+                discriminant_KeyCode::KeypadBegin => true,
+                discriminant_KeyCode::Left => true,
+                discriminant_KeyCode::Media => self.Media == other.Media,
+                discriminant_KeyCode::Menu => true,
+                discriminant_KeyCode::Modifier => self.Modifier == other.Modifier,
+                discriminant_KeyCode::Null => true,
+                discriminant_KeyCode::NumLock => true,
+                discriminant_KeyCode::PageDown => true,
+                discriminant_KeyCode::PageUp => true,
+                discriminant_KeyCode::Pause => true,
+                discriminant_KeyCode::PrintScreen => true,
+                discriminant_KeyCode::Right => true,
+                discriminant_KeyCode::Scalar => self.Scalar == other.Scalar,
+                discriminant_KeyCode::ScrollLock => true,
+                discriminant_KeyCode::Tab => true,
+                discriminant_KeyCode::Up => true,
+            }
+        }
     }
+}
 
+impl PartialOrd for KeyCode {
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// Internal helper
-    fn union_pointer(&self) -> *mut union_Elem {
-        // The discriminant is stored in the unused bytes at the end of the union pointer
-        ((self.pointer as usize) & (!0b11 as usize)) as *mut union_Elem
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match self.discriminant().partial_cmp(&other.discriminant()) {
+            Some(core::cmp::Ordering::Equal) => {}
+            not_eq => return not_eq,
+        }
+
+        unsafe {
+            match self.discriminant() {
+                discriminant_KeyCode::BackTab => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::Backspace => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::CapsLock => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::Delete => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::Down => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::End => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::Enter => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::Esc => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::Function => self.Function.partial_cmp(&other.Function),
+                discriminant_KeyCode::Home => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::Insert => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::KeypadBegin => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::Left => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::Media => self.Media.partial_cmp(&other.Media),
+                discriminant_KeyCode::Menu => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::Modifier => self.Modifier.partial_cmp(&other.Modifier),
+                discriminant_KeyCode::Null => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::NumLock => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::PageDown => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::PageUp => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::Pause => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::PrintScreen => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::Right => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::Scalar => self.Scalar.partial_cmp(&other.Scalar),
+                discriminant_KeyCode::ScrollLock => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::Tab => Some(core::cmp::Ordering::Equal),
+                discriminant_KeyCode::Up => Some(core::cmp::Ordering::Equal),
+            }
+        }
     }
+}
 
+impl Ord for KeyCode {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -1047,75 +7669,172 @@ impl Elem {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Construct a tag named `Block`, with the appropriate payload
-    pub fn Block(arg0: BlockConfig) -> Self {
-            let size = core::mem::size_of::<union_Elem>();
-            let align = core::mem::align_of::<union_Elem>() as u32;
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            match self.discriminant().cmp(&other.discriminant()) {
+                core::cmp::Ordering::Equal => {}
+                not_eq => return not_eq,
+            }
 
             unsafe {
-                let ptr = roc_std::roc_alloc_refcounted::<union_Elem>();
-
-                *ptr = union_Elem {
-                    Block: core::mem::ManuallyDrop::new(Elem_Block {
-                    f0: arg0,
-                })
-                };
-
-                Self {
-                    pointer: Self::tag_discriminant(ptr, discriminant_Elem::Block),
-                }
+            match self.discriminant() {
+                discriminant_KeyCode::BackTab => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::Backspace => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::CapsLock => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::Delete => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::Down => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::End => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::Enter => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::Esc => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::Function => self.Function.cmp(&other.Function),
+                discriminant_KeyCode::Home => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::Insert => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::KeypadBegin => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::Left => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::Media => self.Media.cmp(&other.Media),
+                discriminant_KeyCode::Menu => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::Modifier => self.Modifier.cmp(&other.Modifier),
+                discriminant_KeyCode::Null => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::NumLock => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::PageDown => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::PageUp => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::Pause => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::PrintScreen => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::Right => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::Scalar => self.Scalar.cmp(&other.Scalar),
+                discriminant_KeyCode::ScrollLock => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::Tab => core::cmp::Ordering::Equal,
+                discriminant_KeyCode::Up => core::cmp::Ordering::Equal,
             }
+        }
     }
+}
 
+impl Clone for KeyCode {
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Block` and convert it to `Block`'s payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Block`.
-            pub unsafe fn into_Block(mut self) -> BlockConfig {
-                debug_assert_eq!(self.discriminant(), discriminant_Elem::Block);
-        let payload = {
-            let ptr = (self.pointer as usize & !0b11) as *mut union_Elem;
-            let mut uninitialized = core::mem::MaybeUninit::uninit();
-            let swapped = unsafe {
-                core::mem::replace(
-                    &mut (*ptr).Block,
-                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
-                )
-            };
-
-            core::mem::forget(self);
+    fn clone(&self) -> Self {
+        let mut answer = unsafe {
+            match self.discriminant() {
+                discriminant_KeyCode::BackTab => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::Backspace => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::CapsLock => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::Delete => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::Down => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::End => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::Enter => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::Esc => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::Function => Self {
+                    Function: self.Function.clone(),
+                },
+                discriminant_KeyCode::Home => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::Insert => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::KeypadBegin => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::Left => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::Media => Self {
+                    Media: self.Media.clone(),
+                },
+                discriminant_KeyCode::Menu => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::Modifier => Self {
+                    Modifier: self.Modifier.clone(),
+                },
+                discriminant_KeyCode::Null => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::NumLock => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::PageDown => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::PageUp => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::Pause => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::PrintScreen => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::Right => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::Scalar => Self {
+                    Scalar: self.Scalar.clone(),
+                },
+                discriminant_KeyCode::ScrollLock => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::Tab => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_KeyCode::Up => core::mem::transmute::<
+                    core::mem::MaybeUninit<KeyCode>,
+                    KeyCode,
+                >(core::mem::MaybeUninit::uninit()),
+            }
 
-            core::mem::ManuallyDrop::into_inner(swapped)
         };
 
-        
-        payload.f0
-    }
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Block` and return its payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Block`.
-            pub unsafe fn as_Block(&self) -> &BlockConfig {
-                debug_assert_eq!(self.discriminant(), discriminant_Elem::Block);
-        let payload = {
-            let ptr = (self.pointer as usize & !0b11) as *mut union_Elem;
-
-            unsafe { &(*ptr).Block }
-        };
+        answer.set_discriminant(self.discriminant());
 
-        
-        &payload.f0
+        answer
     }
+}
 
+impl core::hash::Hash for KeyCode {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -1123,78 +7842,118 @@ impl Elem {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Construct a tag named `Layout`, with the appropriate payload
-    pub fn Layout(arg0: roc_std::RocList<Elem>, arg1: LayoutConfig) -> Self {
-            let size = core::mem::size_of::<union_Elem>();
-            let align = core::mem::align_of::<union_Elem>() as u32;
-
-            unsafe {
-                let ptr = roc_std::roc_alloc_refcounted::<union_Elem>();
-
-                *ptr = union_Elem {
-                    Layout: core::mem::ManuallyDrop::new(Elem_Layout {
-                    f0: arg0,
-                    f1: arg1,
-                })
-                };
-
-                Self {
-                    pointer: Self::tag_discriminant(ptr, discriminant_Elem::Layout),
-                }
-            }
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
+            discriminant_KeyCode::BackTab => discriminant_KeyCode::BackTab.hash(state),
+            discriminant_KeyCode::Backspace => discriminant_KeyCode::Backspace.hash(state),
+            discriminant_KeyCode::CapsLock => discriminant_KeyCode::CapsLock.hash(state),
+            discriminant_KeyCode::Delete => discriminant_KeyCode::Delete.hash(state),
+            discriminant_KeyCode::Down => discriminant_KeyCode::Down.hash(state),
+            discriminant_KeyCode::End => discriminant_KeyCode::End.hash(state),
+            discriminant_KeyCode::Enter => discriminant_KeyCode::Enter.hash(state),
+            discriminant_KeyCode::Esc => discriminant_KeyCode::Esc.hash(state),
+            discriminant_KeyCode::Function => unsafe {
+                    discriminant_KeyCode::Function.hash(state);
+                    self.Function.hash(state);
+                },
+            discriminant_KeyCode::Home => discriminant_KeyCode::Home.hash(state),
+            discriminant_KeyCode::Insert => discriminant_KeyCode::Insert.hash(state),
+            discriminant_KeyCode::KeypadBegin => discriminant_KeyCode::KeypadBegin.hash(state),
+            discriminant_KeyCode::Left => discriminant_KeyCode::Left.hash(state),
+            discriminant_KeyCode::Media => unsafe {
+                    discriminant_KeyCode::Media.hash(state);
+                    self.Media.hash(state);
+                },
+            discriminant_KeyCode::Menu => discriminant_KeyCode::Menu.hash(state),
+            discriminant_KeyCode::Modifier => unsafe {
+                    discriminant_KeyCode::Modifier.hash(state);
+                    self.Modifier.hash(state);
+                },
+            discriminant_KeyCode::Null => discriminant_KeyCode::Null.hash(state),
+            discriminant_KeyCode::NumLock => discriminant_KeyCode::NumLock.hash(state),
+            discriminant_KeyCode::PageDown => discriminant_KeyCode::PageDown.hash(state),
+            discriminant_KeyCode::PageUp => discriminant_KeyCode::PageUp.hash(state),
+            discriminant_KeyCode::Pause => discriminant_KeyCode::Pause.hash(state),
+            discriminant_KeyCode::PrintScreen => discriminant_KeyCode::PrintScreen.hash(state),
+            discriminant_KeyCode::Right => discriminant_KeyCode::Right.hash(state),
+            discriminant_KeyCode::Scalar => unsafe {
+                    discriminant_KeyCode::Scalar.hash(state);
+                    self.Scalar.hash(state);
+                },
+            discriminant_KeyCode::ScrollLock => discriminant_KeyCode::ScrollLock.hash(state),
+            discriminant_KeyCode::Tab => discriminant_KeyCode::Tab.hash(state),
+            discriminant_KeyCode::Up => discriminant_KeyCode::Up.hash(state),
+        }
     }
+}
 
+impl core::fmt::Debug for KeyCode {
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Layout` and convert it to `Layout`'s payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Layout`.
-            pub unsafe fn into_Layout(mut self) -> (roc_std::RocList<Elem>, LayoutConfig) {
-                debug_assert_eq!(self.discriminant(), discriminant_Elem::Layout);
-        let payload = {
-            let ptr = (self.pointer as usize & !0b11) as *mut union_Elem;
-            let mut uninitialized = core::mem::MaybeUninit::uninit();
-            let swapped = unsafe {
-                core::mem::replace(
-                    &mut (*ptr).Layout,
-                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
-                )
-            };
-
-            core::mem::forget(self);
-
-            core::mem::ManuallyDrop::into_inner(swapped)
-        };
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("KeyCode::")?;
 
-        (
-            payload.f0, 
-            payload.f1
-        )
+        unsafe {
+            match self.discriminant() {
+                discriminant_KeyCode::BackTab => f.write_str("BackTab"),
+                discriminant_KeyCode::Backspace => f.write_str("Backspace"),
+                discriminant_KeyCode::CapsLock => f.write_str("CapsLock"),
+                discriminant_KeyCode::Delete => f.write_str("Delete"),
+                discriminant_KeyCode::Down => f.write_str("Down"),
+                discriminant_KeyCode::End => f.write_str("End"),
+                discriminant_KeyCode::Enter => f.write_str("Enter"),
+                discriminant_KeyCode::Esc => f.write_str("Esc"),
+                discriminant_KeyCode::Function => f.debug_tuple("Function")
+        .field(&self.Function)
+        .finish(),
+                discriminant_KeyCode::Home => f.write_str("Home"),
+                discriminant_KeyCode::Insert => f.write_str("Insert"),
+                discriminant_KeyCode::KeypadBegin => f.write_str("KeypadBegin"),
+                discriminant_KeyCode::Left => f.write_str("Left"),
+                discriminant_KeyCode::Media => f.debug_tuple("Media")
+        .field(&self.Media)
+        .finish(),
+                discriminant_KeyCode::Menu => f.write_str("Menu"),
+                discriminant_KeyCode::Modifier => f.debug_tuple("Modifier")
+        .field(&self.Modifier)
+        .finish(),
+                discriminant_KeyCode::Null => f.write_str("Null"),
+                discriminant_KeyCode::NumLock => f.write_str("NumLock"),
+                discriminant_KeyCode::PageDown => f.write_str("PageDown"),
+                discriminant_KeyCode::PageUp => f.write_str("PageUp"),
+                discriminant_KeyCode::Pause => f.write_str("Pause"),
+                discriminant_KeyCode::PrintScreen => f.write_str("PrintScreen"),
+                discriminant_KeyCode::Right => f.write_str("Right"),
+                discriminant_KeyCode::Scalar => f.debug_tuple("Scalar")
+        .field(&*self.Scalar)
+        .finish(),
+                discriminant_KeyCode::ScrollLock => f.write_str("ScrollLock"),
+                discriminant_KeyCode::Tab => f.write_str("Tab"),
+                discriminant_KeyCode::Up => f.write_str("Up"),
+            }
+        }
     }
+}
 
+impl MouseEventKind {
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Layout` and return its payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Layout`.
-            pub unsafe fn as_Layout(&self) -> (&roc_std::RocList<Elem>, &LayoutConfig) {
-                debug_assert_eq!(self.discriminant(), discriminant_Elem::Layout);
-        let payload = {
-            let ptr = (self.pointer as usize & !0b11) as *mut union_Elem;
-
-            unsafe { &(*ptr).Layout }
-        };
+    /// Returns which variant this tag union holds. Note that this never includes a payload!
+    pub fn discriminant(&self) -> discriminant_MouseEventKind {
+        unsafe {
+            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
 
-        (
-            &payload.f0, 
-            &payload.f1
-        )
+            core::mem::transmute::<u8, discriminant_MouseEventKind>(*bytes.as_ptr().add(1))
+        }
     }
 
     #[cfg(any(
@@ -1204,73 +7963,48 @@ impl Elem {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Construct a tag named `ListItems`, with the appropriate payload
-    pub fn ListItems(arg0: ListConfig) -> Self {
-            let size = core::mem::size_of::<union_Elem>();
-            let align = core::mem::align_of::<union_Elem>() as u32;
-
-            unsafe {
-                let ptr = roc_std::roc_alloc_refcounted::<union_Elem>();
-
-                *ptr = union_Elem {
-                    ListItems: core::mem::ManuallyDrop::new(Elem_ListItems {
-                    f0: arg0,
-                })
-                };
+    /// Internal helper
+    fn set_discriminant(&mut self, discriminant: discriminant_MouseEventKind) {
+        let discriminant_ptr: *mut discriminant_MouseEventKind = (self as *mut MouseEventKind).cast();
 
-                Self {
-                    pointer: Self::tag_discriminant(ptr, discriminant_Elem::ListItems),
-                }
-            }
+        unsafe {
+            *(discriminant_ptr.add(1)) = discriminant;
+        }
     }
 
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Elem` has a `.discriminant()` of `ListItems` and convert it to `ListItems`'s payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `ListItems`.
-            pub unsafe fn into_ListItems(mut self) -> ListConfig {
-                debug_assert_eq!(self.discriminant(), discriminant_Elem::ListItems);
-        let payload = {
-            let ptr = (self.pointer as usize & !0b11) as *mut union_Elem;
-            let mut uninitialized = core::mem::MaybeUninit::uninit();
-            let swapped = unsafe {
-                core::mem::replace(
-                    &mut (*ptr).ListItems,
-                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
-                )
+    /// Construct a tag named `Down`, with the appropriate payload
+    pub fn Down(arg: MouseButton) -> Self {
+            let mut answer = Self {
+                Down: arg
             };
 
-            core::mem::forget(self);
-
-            core::mem::ManuallyDrop::into_inner(swapped)
-        };
+            answer.set_discriminant(discriminant_MouseEventKind::Down);
 
-        
-        payload.f0
+            answer
     }
 
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Elem` has a `.discriminant()` of `ListItems` and return its payload.
+    /// Unsafely assume the given `MouseEventKind` has a `.discriminant()` of `Down` and convert it to `Down`'s payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `ListItems`.
-            pub unsafe fn as_ListItems(&self) -> &ListConfig {
-                debug_assert_eq!(self.discriminant(), discriminant_Elem::ListItems);
-        let payload = {
-            let ptr = (self.pointer as usize & !0b11) as *mut union_Elem;
-
-            unsafe { &(*ptr).ListItems }
-        };
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Down`.
+            pub unsafe fn into_Down(self) -> MouseButton {
+                debug_assert_eq!(self.discriminant(), discriminant_MouseEventKind::Down);
+        let payload = self.Down;
 
-        
-        &payload.f0
+        payload
     }
 
     #[cfg(any(
@@ -1280,302 +8014,229 @@ impl Elem {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Construct a tag named `Paragraph`, with the appropriate payload
-    pub fn Paragraph(arg0: ParagraphConfig) -> Self {
-            let size = core::mem::size_of::<union_Elem>();
-            let align = core::mem::align_of::<union_Elem>() as u32;
-
-            unsafe {
-                let ptr = roc_std::roc_alloc_refcounted::<union_Elem>();
-
-                *ptr = union_Elem {
-                    Paragraph: core::mem::ManuallyDrop::new(Elem_Paragraph {
-                    f0: arg0,
-                })
-                };
+    /// Unsafely assume the given `MouseEventKind` has a `.discriminant()` of `Down` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Down`.
+            pub unsafe fn as_Down(&self) -> &MouseButton {
+                debug_assert_eq!(self.discriminant(), discriminant_MouseEventKind::Down);
+        let payload = &self.Down;
 
-                Self {
-                    pointer: Self::tag_discriminant(ptr, discriminant_Elem::Paragraph),
-                }
-            }
+        &payload
     }
 
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Paragraph` and convert it to `Paragraph`'s payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Paragraph`.
-            pub unsafe fn into_Paragraph(mut self) -> ParagraphConfig {
-                debug_assert_eq!(self.discriminant(), discriminant_Elem::Paragraph);
-        let payload = {
-            let ptr = (self.pointer as usize & !0b11) as *mut union_Elem;
-            let mut uninitialized = core::mem::MaybeUninit::uninit();
-            let swapped = unsafe {
-                core::mem::replace(
-                    &mut (*ptr).Paragraph,
-                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
-                )
+    /// Construct a tag named `Drag`, with the appropriate payload
+    pub fn Drag(arg: MouseButton) -> Self {
+            let mut answer = Self {
+                Drag: arg
             };
 
-            core::mem::forget(self);
-
-            core::mem::ManuallyDrop::into_inner(swapped)
-        };
+            answer.set_discriminant(discriminant_MouseEventKind::Drag);
 
-        
-        payload.f0
+            answer
     }
 
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Paragraph` and return its payload.
+    /// Unsafely assume the given `MouseEventKind` has a `.discriminant()` of `Drag` and convert it to `Drag`'s payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Paragraph`.
-            pub unsafe fn as_Paragraph(&self) -> &ParagraphConfig {
-                debug_assert_eq!(self.discriminant(), discriminant_Elem::Paragraph);
-        let payload = {
-            let ptr = (self.pointer as usize & !0b11) as *mut union_Elem;
-
-            unsafe { &(*ptr).Paragraph }
-        };
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Drag`.
+            pub unsafe fn into_Drag(self) -> MouseButton {
+                debug_assert_eq!(self.discriminant(), discriminant_MouseEventKind::Drag);
+        let payload = self.Drag;
 
-        
-        &payload.f0
+        payload
     }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Returns which variant this tag union holds. Note that this never includes a payload!
-    pub fn discriminant(&self) -> discriminant_Elem {
-        // The discriminant is stored in the unused bytes at the end of the recursive pointer
-        unsafe { core::mem::transmute::<u8, discriminant_Elem>((self.pointer as u8) & 0b111) }
+    /// Unsafely assume the given `MouseEventKind` has a `.discriminant()` of `Drag` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Drag`.
+            pub unsafe fn as_Drag(&self) -> &MouseButton {
+                debug_assert_eq!(self.discriminant(), discriminant_MouseEventKind::Drag);
+        let payload = &self.Drag;
+
+        &payload
     }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Internal helper
-    fn tag_discriminant(pointer: *mut union_Elem, discriminant: discriminant_Elem) -> *mut union_Elem {
-        // The discriminant is stored in the unused bytes at the end of the union pointer
-        let untagged = (pointer as usize) & (!0b111 as usize);
-        let tagged = untagged | (discriminant as usize);
+    /// A tag named Moved, which has no payload.
+    pub const Moved: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<MouseEventKind>()];
 
-        tagged as *mut union_Elem
-    }
+        bytes[1] = discriminant_MouseEventKind::Moved as u8;
 
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// Internal helper
-    fn union_pointer(&self) -> *mut union_Elem {
-        // The discriminant is stored in the unused bytes at the end of the union pointer
-        ((self.pointer as usize) & (!0b111 as usize)) as *mut union_Elem
-    }
+        core::mem::transmute::<[u8; core::mem::size_of::<MouseEventKind>()], MouseEventKind>(bytes)
+    };
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Block` and convert it to `Block`'s payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Block`.
-            pub unsafe fn into_Block(mut self) -> BlockConfig {
-                debug_assert_eq!(self.discriminant(), discriminant_Elem::Block);
-        let payload = {
-            let ptr = (self.pointer as usize & !0b111) as *mut union_Elem;
-            let mut uninitialized = core::mem::MaybeUninit::uninit();
-            let swapped = unsafe {
-                core::mem::replace(
-                    &mut (*ptr).Block,
-                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
-                )
-            };
-
-            core::mem::forget(self);
-
-            core::mem::ManuallyDrop::into_inner(swapped)
-        };
-
-        
-        payload.f0
+    /// Other `into_` methods return a payload, but since the Moved tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Moved(self) {
+        ()
     }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Block` and return its payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Block`.
-            pub unsafe fn as_Block(&self) -> &BlockConfig {
-                debug_assert_eq!(self.discriminant(), discriminant_Elem::Block);
-        let payload = {
-            let ptr = (self.pointer as usize & !0b111) as *mut union_Elem;
-
-            unsafe { &(*ptr).Block }
-        };
-
-        
-        &payload.f0
+    /// Other `as` methods return a payload, but since the Moved tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Moved(&self) {
+        ()
     }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Layout` and convert it to `Layout`'s payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Layout`.
-            pub unsafe fn into_Layout(mut self) -> (roc_std::RocList<Elem>, LayoutConfig) {
-                debug_assert_eq!(self.discriminant(), discriminant_Elem::Layout);
-        let payload = {
-            let ptr = (self.pointer as usize & !0b111) as *mut union_Elem;
-            let mut uninitialized = core::mem::MaybeUninit::uninit();
-            let swapped = unsafe {
-                core::mem::replace(
-                    &mut (*ptr).Layout,
-                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
-                )
-            };
-
-            core::mem::forget(self);
+    /// A tag named ScrollDown, which has no payload.
+    pub const ScrollDown: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<MouseEventKind>()];
 
-            core::mem::ManuallyDrop::into_inner(swapped)
-        };
+        bytes[1] = discriminant_MouseEventKind::ScrollDown as u8;
 
-        (
-            payload.f0, 
-            payload.f1
-        )
-    }
+        core::mem::transmute::<[u8; core::mem::size_of::<MouseEventKind>()], MouseEventKind>(bytes)
+    };
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Layout` and return its payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Layout`.
-            pub unsafe fn as_Layout(&self) -> (&roc_std::RocList<Elem>, &LayoutConfig) {
-                debug_assert_eq!(self.discriminant(), discriminant_Elem::Layout);
-        let payload = {
-            let ptr = (self.pointer as usize & !0b111) as *mut union_Elem;
-
-            unsafe { &(*ptr).Layout }
-        };
+    /// Other `into_` methods return a payload, but since the ScrollDown tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_ScrollDown(self) {
+        ()
+    }
 
-        (
-            &payload.f0, 
-            &payload.f1
-        )
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the ScrollDown tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_ScrollDown(&self) {
+        ()
     }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Elem` has a `.discriminant()` of `ListItems` and convert it to `ListItems`'s payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `ListItems`.
-            pub unsafe fn into_ListItems(mut self) -> ListConfig {
-                debug_assert_eq!(self.discriminant(), discriminant_Elem::ListItems);
-        let payload = {
-            let ptr = (self.pointer as usize & !0b111) as *mut union_Elem;
-            let mut uninitialized = core::mem::MaybeUninit::uninit();
-            let swapped = unsafe {
-                core::mem::replace(
-                    &mut (*ptr).ListItems,
-                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
-                )
-            };
+    /// A tag named ScrollUp, which has no payload.
+    pub const ScrollUp: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<MouseEventKind>()];
 
-            core::mem::forget(self);
+        bytes[1] = discriminant_MouseEventKind::ScrollUp as u8;
 
-            core::mem::ManuallyDrop::into_inner(swapped)
-        };
+        core::mem::transmute::<[u8; core::mem::size_of::<MouseEventKind>()], MouseEventKind>(bytes)
+    };
 
-        
-        payload.f0
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `into_` methods return a payload, but since the ScrollUp tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_ScrollUp(self) {
+        ()
     }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Elem` has a `.discriminant()` of `ListItems` and return its payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `ListItems`.
-            pub unsafe fn as_ListItems(&self) -> &ListConfig {
-                debug_assert_eq!(self.discriminant(), discriminant_Elem::ListItems);
-        let payload = {
-            let ptr = (self.pointer as usize & !0b111) as *mut union_Elem;
-
-            unsafe { &(*ptr).ListItems }
-        };
-
-        
-        &payload.f0
+    /// Other `as` methods return a payload, but since the ScrollUp tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_ScrollUp(&self) {
+        ()
     }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Paragraph` and convert it to `Paragraph`'s payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Paragraph`.
-            pub unsafe fn into_Paragraph(mut self) -> ParagraphConfig {
-                debug_assert_eq!(self.discriminant(), discriminant_Elem::Paragraph);
-        let payload = {
-            let ptr = (self.pointer as usize & !0b111) as *mut union_Elem;
-            let mut uninitialized = core::mem::MaybeUninit::uninit();
-            let swapped = unsafe {
-                core::mem::replace(
-                    &mut (*ptr).Paragraph,
-                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
-                )
+    /// Construct a tag named `Up`, with the appropriate payload
+    pub fn Up(arg: MouseButton) -> Self {
+            let mut answer = Self {
+                Up: arg
             };
 
-            core::mem::forget(self);
-
-            core::mem::ManuallyDrop::into_inner(swapped)
-        };
+            answer.set_discriminant(discriminant_MouseEventKind::Up);
 
-        
-        payload.f0
+            answer
     }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Elem` has a `.discriminant()` of `Paragraph` and return its payload.
+    /// Unsafely assume the given `MouseEventKind` has a `.discriminant()` of `Up` and convert it to `Up`'s payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Paragraph`.
-            pub unsafe fn as_Paragraph(&self) -> &ParagraphConfig {
-                debug_assert_eq!(self.discriminant(), discriminant_Elem::Paragraph);
-        let payload = {
-            let ptr = (self.pointer as usize & !0b111) as *mut union_Elem;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Up`.
+            pub unsafe fn into_Up(self) -> MouseButton {
+                debug_assert_eq!(self.discriminant(), discriminant_MouseEventKind::Up);
+        let payload = self.Up;
 
-            unsafe { &(*ptr).Paragraph }
-        };
-
-        
-        &payload.f0
+        payload
     }
-}
 
-impl Drop for Elem {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -1583,39 +8244,20 @@ impl Drop for Elem {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    fn drop(&mut self) {
-        // We only need to do any work if there's actually a heap-allocated payload.
-        if let Some(storage) = self.storage() {
-            let mut new_storage = storage.get();
-
-            // Decrement the refcount
-            let needs_dealloc = !new_storage.is_readonly() && new_storage.decrease();
-
-            if needs_dealloc {
-                // Drop the payload first.
-                            match self.discriminant() {
-                discriminant_Elem::Block => unsafe { core::mem::ManuallyDrop::drop(&mut (&mut *self.union_pointer()).Block) },
-                discriminant_Elem::Layout => unsafe { core::mem::ManuallyDrop::drop(&mut (&mut *self.union_pointer()).Layout) },
-                discriminant_Elem::ListItems => unsafe { core::mem::ManuallyDrop::drop(&mut (&mut *self.union_pointer()).ListItems) },
-                discriminant_Elem::Paragraph => unsafe { core::mem::ManuallyDrop::drop(&mut (&mut *self.union_pointer()).Paragraph) },
-            }
-
-
-                // Dealloc the pointer
-                let alignment = core::mem::align_of::<Self>().max(core::mem::align_of::<roc_std::Storage>());
+    /// Unsafely assume the given `MouseEventKind` has a `.discriminant()` of `Up` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Up`.
+            pub unsafe fn as_Up(&self) -> &MouseButton {
+                debug_assert_eq!(self.discriminant(), discriminant_MouseEventKind::Up);
+        let payload = &self.Up;
 
-                unsafe { crate::roc::roc_dealloc(storage.as_ptr().cast(), alignment as u32); }
-            } else {
-                // Write the storage back.
-                storage.set(new_storage);
-            }
-        }
+        &payload
     }
 }
 
-impl Eq for Elem {}
+impl Eq for MouseEventKind {}
 
-impl PartialEq for Elem {
+impl PartialEq for MouseEventKind {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -1630,16 +8272,18 @@ impl PartialEq for Elem {
 
             unsafe {
             match self.discriminant() {
-                discriminant_Elem::Block => (&*self.union_pointer()).Block == (&*other.union_pointer()).Block,
-                discriminant_Elem::Layout => (&*self.union_pointer()).Layout == (&*other.union_pointer()).Layout,
-                discriminant_Elem::ListItems => (&*self.union_pointer()).ListItems == (&*other.union_pointer()).ListItems,
-                discriminant_Elem::Paragraph => (&*self.union_pointer()).Paragraph == (&*other.union_pointer()).Paragraph,
+                discriminant_MouseEventKind::Down => self.Down == other.Down,
+                discriminant_MouseEventKind::Drag => self.Drag == other.Drag,
+                discriminant_MouseEventKind::Moved => true,
+                discriminant_MouseEventKind::ScrollDown => true,
+                discriminant_MouseEventKind::ScrollUp => true,
+                discriminant_MouseEventKind::Up => self.Up == other.Up,
             }
         }
     }
 }
 
-impl PartialOrd for Elem {
+impl PartialOrd for MouseEventKind {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -1655,16 +8299,18 @@ impl PartialOrd for Elem {
 
         unsafe {
             match self.discriminant() {
-                discriminant_Elem::Block => (&*self.union_pointer()).Block.partial_cmp(&(&*other.union_pointer()).Block),
-                discriminant_Elem::Layout => (&*self.union_pointer()).Layout.partial_cmp(&(&*other.union_pointer()).Layout),
-                discriminant_Elem::ListItems => (&*self.union_pointer()).ListItems.partial_cmp(&(&*other.union_pointer()).ListItems),
-                discriminant_Elem::Paragraph => (&*self.union_pointer()).Paragraph.partial_cmp(&(&*other.union_pointer()).Paragraph),
+                discriminant_MouseEventKind::Down => self.Down.partial_cmp(&other.Down),
+                discriminant_MouseEventKind::Drag => self.Drag.partial_cmp(&other.Drag),
+                discriminant_MouseEventKind::Moved => Some(core::cmp::Ordering::Equal),
+                discriminant_MouseEventKind::ScrollDown => Some(core::cmp::Ordering::Equal),
+                discriminant_MouseEventKind::ScrollUp => Some(core::cmp::Ordering::Equal),
+                discriminant_MouseEventKind::Up => self.Up.partial_cmp(&other.Up),
             }
         }
     }
 }
 
-impl Ord for Elem {
+impl Ord for MouseEventKind {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -1680,39 +8326,20 @@ impl Ord for Elem {
 
             unsafe {
             match self.discriminant() {
-                discriminant_Elem::Block => (&*self.union_pointer()).Block.cmp(&(&*other.union_pointer()).Block),
-                discriminant_Elem::Layout => (&*self.union_pointer()).Layout.cmp(&(&*other.union_pointer()).Layout),
-                discriminant_Elem::ListItems => (&*self.union_pointer()).ListItems.cmp(&(&*other.union_pointer()).ListItems),
-                discriminant_Elem::Paragraph => (&*self.union_pointer()).Paragraph.cmp(&(&*other.union_pointer()).Paragraph),
+                discriminant_MouseEventKind::Down => self.Down.cmp(&other.Down),
+                discriminant_MouseEventKind::Drag => self.Drag.cmp(&other.Drag),
+                discriminant_MouseEventKind::Moved => core::cmp::Ordering::Equal,
+                discriminant_MouseEventKind::ScrollDown => core::cmp::Ordering::Equal,
+                discriminant_MouseEventKind::ScrollUp => core::cmp::Ordering::Equal,
+                discriminant_MouseEventKind::Up => self.Up.cmp(&other.Up),
             }
         }
     }
 }
 
-impl Clone for Elem {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    fn clone(&self) -> Self {
-        if let Some(storage) = self.storage() {
-            let mut new_storage = storage.get();
-            if !new_storage.is_readonly() {
-                new_storage.increment_reference_count();
-                storage.set(new_storage);
-            }
-        }
-
-        Self {
-            pointer: self.pointer
-        }
-    }
-}
+impl Copy for MouseEventKind {}
 
-impl core::hash::Hash for Elem {
+impl Clone for MouseEventKind {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -1720,101 +8347,41 @@ impl core::hash::Hash for Elem {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
-            discriminant_Elem::Block => unsafe {
-                    discriminant_Elem::Block.hash(state);
-                    (&*self.union_pointer()).Block.hash(state);
-                },
-            discriminant_Elem::Layout => unsafe {
-                    discriminant_Elem::Layout.hash(state);
-                    (&*self.union_pointer()).Layout.hash(state);
+    fn clone(&self) -> Self {
+        let mut answer = unsafe {
+            match self.discriminant() {
+                discriminant_MouseEventKind::Down => Self {
+                    Down: self.Down.clone(),
                 },
-            discriminant_Elem::ListItems => unsafe {
-                    discriminant_Elem::ListItems.hash(state);
-                    (&*self.union_pointer()).ListItems.hash(state);
+                discriminant_MouseEventKind::Drag => Self {
+                    Drag: self.Drag.clone(),
                 },
-            discriminant_Elem::Paragraph => unsafe {
-                    discriminant_Elem::Paragraph.hash(state);
-                    (&*self.union_pointer()).Paragraph.hash(state);
+                discriminant_MouseEventKind::Moved => core::mem::transmute::<
+                    core::mem::MaybeUninit<MouseEventKind>,
+                    MouseEventKind,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_MouseEventKind::ScrollDown => core::mem::transmute::<
+                    core::mem::MaybeUninit<MouseEventKind>,
+                    MouseEventKind,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_MouseEventKind::ScrollUp => core::mem::transmute::<
+                    core::mem::MaybeUninit<MouseEventKind>,
+                    MouseEventKind,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_MouseEventKind::Up => Self {
+                    Up: self.Up.clone(),
                 },
-        }
-    }
-}
-
-impl core::fmt::Debug for Elem {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str("Elem::")?;
-
-        unsafe {
-            match self.discriminant() {
-                discriminant_Elem::Block => f.debug_tuple("Block")
-        .field(&(&*(&*self.union_pointer()).Block).f0)
-        .finish(),
-                discriminant_Elem::Layout => f.debug_tuple("Layout")
-        .field(&(&*(&*self.union_pointer()).Layout).f0)
-.field(&(&*(&*self.union_pointer()).Layout).f1)
-        .finish(),
-                discriminant_Elem::ListItems => f.debug_tuple("ListItems")
-        .field(&(&*(&*self.union_pointer()).ListItems).f0)
-        .finish(),
-                discriminant_Elem::Paragraph => f.debug_tuple("Paragraph")
-        .field(&(&*(&*self.union_pointer()).Paragraph).f0)
-        .finish(),
             }
-        }
-    }
-}
-
-impl Event {
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// Returns which variant this tag union holds. Note that this never includes a payload!
-    pub fn discriminant(&self) -> discriminant_Event {
-        unsafe {
-            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
 
-            core::mem::transmute::<u8, discriminant_Event>(*bytes.as_ptr().add(16))
-        }
-    }
+        };
 
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// Internal helper
-    fn set_discriminant(&mut self, discriminant: discriminant_Event) {
-        let discriminant_ptr: *mut discriminant_Event = (self as *mut Event).cast();
+        answer.set_discriminant(self.discriminant());
 
-        unsafe {
-            *(discriminant_ptr.add(16)) = discriminant;
-        }
+        answer
     }
+}
 
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// A tag named FocusGained, which has no payload.
-    pub const FocusGained: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<Event>()];
-
-        bytes[16] = discriminant_Event::FocusGained as u8;
-
-        core::mem::transmute::<[u8; core::mem::size_of::<Event>()], Event>(bytes)
-    };
-
+impl core::hash::Hash for MouseEventKind {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -1822,12 +8389,27 @@ impl Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the FocusGained tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_FocusGained(self) {
-        ()
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
+            discriminant_MouseEventKind::Down => unsafe {
+                    discriminant_MouseEventKind::Down.hash(state);
+                    self.Down.hash(state);
+                },
+            discriminant_MouseEventKind::Drag => unsafe {
+                    discriminant_MouseEventKind::Drag.hash(state);
+                    self.Drag.hash(state);
+                },
+            discriminant_MouseEventKind::Moved => discriminant_MouseEventKind::Moved.hash(state),
+            discriminant_MouseEventKind::ScrollDown => discriminant_MouseEventKind::ScrollDown.hash(state),
+            discriminant_MouseEventKind::ScrollUp => discriminant_MouseEventKind::ScrollUp.hash(state),
+            discriminant_MouseEventKind::Up => unsafe {
+                    discriminant_MouseEventKind::Up.hash(state);
+                    self.Up.hash(state);
+                },
+        }
     }
+}
 
+impl core::fmt::Debug for MouseEventKind {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -1835,50 +8417,55 @@ impl Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the FocusGained tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_FocusGained(&self) {
-        ()
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("MouseEventKind::")?;
+
+        unsafe {
+            match self.discriminant() {
+                discriminant_MouseEventKind::Down => f.debug_tuple("Down")
+        .field(&self.Down)
+        .finish(),
+                discriminant_MouseEventKind::Drag => f.debug_tuple("Drag")
+        .field(&self.Drag)
+        .finish(),
+                discriminant_MouseEventKind::Moved => f.write_str("Moved"),
+                discriminant_MouseEventKind::ScrollDown => f.write_str("ScrollDown"),
+                discriminant_MouseEventKind::ScrollUp => f.write_str("ScrollUp"),
+                discriminant_MouseEventKind::Up => f.debug_tuple("Up")
+        .field(&self.Up)
+        .finish(),
+            }
+        }
     }
+}
 
+impl Command {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "wasm32",
         target_arch = "x86"
     ))]
-    /// A tag named FocusLost, which has no payload.
-    pub const FocusLost: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<Event>()];
-
-        bytes[16] = discriminant_Event::FocusLost as u8;
-
-        core::mem::transmute::<[u8; core::mem::size_of::<Event>()], Event>(bytes)
-    };
+    /// Returns which variant this tag union holds. Note that this never includes a payload!
+    pub fn discriminant(&self) -> discriminant_Command {
+        unsafe {
+            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
 
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    /// Other `into_` methods return a payload, but since the FocusLost tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_FocusLost(self) {
-        ()
+            core::mem::transmute::<u8, discriminant_Command>(*bytes.as_ptr().add(60))
+        }
     }
 
     #[cfg(any(
         target_arch = "arm",
-        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
+        target_arch = "x86"
     ))]
-    /// Other `as` methods return a payload, but since the FocusLost tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_FocusLost(&self) {
-        ()
+    /// Internal helper
+    fn set_discriminant(&mut self, discriminant: discriminant_Command) {
+        let discriminant_ptr: *mut discriminant_Command = (self as *mut Command).cast();
+
+        unsafe {
+            *(discriminant_ptr.add(60)) = discriminant;
+        }
     }
 
     #[cfg(any(
@@ -1888,13 +8475,13 @@ impl Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Construct a tag named `KeyPressed`, with the appropriate payload
-    pub fn KeyPressed(arg: KeyCode) -> Self {
+    /// Construct a tag named `AppendFile`, with the appropriate payload
+    pub fn AppendFile(arg0: FileContents) -> Self {
             let mut answer = Self {
-                KeyPressed: core::mem::ManuallyDrop::new(arg)
+                AppendFile: core::mem::ManuallyDrop::new(arg0)
             };
 
-            answer.set_discriminant(discriminant_Event::KeyPressed);
+            answer.set_discriminant(discriminant_Command::AppendFile);
 
             answer
     }
@@ -1906,16 +8493,16 @@ impl Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Event` has a `.discriminant()` of `KeyPressed` and convert it to `KeyPressed`'s payload.
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `AppendFile` and convert it to `AppendFile`'s payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `KeyPressed`.
-            pub unsafe fn into_KeyPressed(mut self) -> KeyCode {
-                debug_assert_eq!(self.discriminant(), discriminant_Event::KeyPressed);
+            /// Panics in debug builds if the `.discriminant()` doesn't return `AppendFile`.
+            pub unsafe fn into_AppendFile(mut self) -> FileContents {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::AppendFile);
         let payload = {
             let mut uninitialized = core::mem::MaybeUninit::uninit();
             let swapped = unsafe {
                 core::mem::replace(
-                    &mut self.KeyPressed,
+                    &mut self.AppendFile,
                     core::mem::ManuallyDrop::new(uninitialized.assume_init()),
                 )
             };
@@ -1925,6 +8512,7 @@ impl Event {
             core::mem::ManuallyDrop::into_inner(swapped)
         };
 
+        
         payload
     }
 
@@ -1935,14 +8523,15 @@ impl Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Event` has a `.discriminant()` of `KeyPressed` and return its payload.
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `AppendFile` and return its payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `KeyPressed`.
-            pub unsafe fn as_KeyPressed(&self) -> &KeyCode {
-                debug_assert_eq!(self.discriminant(), discriminant_Event::KeyPressed);
-        let payload = &self.KeyPressed;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `AppendFile`.
+            pub unsafe fn as_AppendFile(&self) -> &FileContents {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::AppendFile);
+        let payload = &self.AppendFile;
 
-        &payload
+        
+        payload
     }
 
     #[cfg(any(
@@ -1952,13 +8541,13 @@ impl Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Construct a tag named `Paste`, with the appropriate payload
-    pub fn Paste(arg: roc_std::RocStr) -> Self {
+    /// Construct a tag named `CancelTimer`, with the appropriate payload
+    pub fn CancelTimer(arg: roc_std::RocStr) -> Self {
             let mut answer = Self {
-                Paste: core::mem::ManuallyDrop::new(arg)
+                CancelTimer: core::mem::ManuallyDrop::new(arg)
             };
 
-            answer.set_discriminant(discriminant_Event::Paste);
+            answer.set_discriminant(discriminant_Command::CancelTimer);
 
             answer
     }
@@ -1970,16 +8559,16 @@ impl Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Event` has a `.discriminant()` of `Paste` and convert it to `Paste`'s payload.
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `CancelTimer` and convert it to `CancelTimer`'s payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Paste`.
-            pub unsafe fn into_Paste(mut self) -> roc_std::RocStr {
-                debug_assert_eq!(self.discriminant(), discriminant_Event::Paste);
+            /// Panics in debug builds if the `.discriminant()` doesn't return `CancelTimer`.
+            pub unsafe fn into_CancelTimer(mut self) -> roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::CancelTimer);
         let payload = {
             let mut uninitialized = core::mem::MaybeUninit::uninit();
             let swapped = unsafe {
                 core::mem::replace(
-                    &mut self.Paste,
+                    &mut self.CancelTimer,
                     core::mem::ManuallyDrop::new(uninitialized.assume_init()),
                 )
             };
@@ -1999,12 +8588,12 @@ impl Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Event` has a `.discriminant()` of `Paste` and return its payload.
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `CancelTimer` and return its payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Paste`.
-            pub unsafe fn as_Paste(&self) -> &roc_std::RocStr {
-                debug_assert_eq!(self.discriminant(), discriminant_Event::Paste);
-        let payload = &self.Paste;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `CancelTimer`.
+            pub unsafe fn as_CancelTimer(&self) -> &roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::CancelTimer);
+        let payload = &self.CancelTimer;
 
         &payload
     }
@@ -2016,13 +8605,13 @@ impl Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Construct a tag named `Resize`, with the appropriate payload
-    pub fn Resize(arg0: Bounds) -> Self {
+    /// Construct a tag named `CloseStdin`, with the appropriate payload
+    pub fn CloseStdin(arg: roc_std::RocStr) -> Self {
             let mut answer = Self {
-                Resize: arg0
+                CloseStdin: core::mem::ManuallyDrop::new(arg)
             };
 
-            answer.set_discriminant(discriminant_Event::Resize);
+            answer.set_discriminant(discriminant_Command::CloseStdin);
 
             answer
     }
@@ -2034,14 +8623,25 @@ impl Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Event` has a `.discriminant()` of `Resize` and convert it to `Resize`'s payload.
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `CloseStdin` and convert it to `CloseStdin`'s payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Resize`.
-            pub unsafe fn into_Resize(self) -> Bounds {
-                debug_assert_eq!(self.discriminant(), discriminant_Event::Resize);
-        let payload = self.Resize;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `CloseStdin`.
+            pub unsafe fn into_CloseStdin(mut self) -> roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::CloseStdin);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.CloseStdin,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
 
-        
         payload
     }
 
@@ -2052,31 +8652,16 @@ impl Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Event` has a `.discriminant()` of `Resize` and return its payload.
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `CloseStdin` and return its payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Resize`.
-            pub unsafe fn as_Resize(&self) -> &Bounds {
-                debug_assert_eq!(self.discriminant(), discriminant_Event::Resize);
-        let payload = &self.Resize;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `CloseStdin`.
+            pub unsafe fn as_CloseStdin(&self) -> &roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::CloseStdin);
+        let payload = &self.CloseStdin;
 
-        
-        payload
+        &payload
     }
 
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// A tag named Tick, which has no payload.
-    pub const Tick: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<Event>()];
-
-        bytes[16] = discriminant_Event::Tick as u8;
-
-        core::mem::transmute::<[u8; core::mem::size_of::<Event>()], Event>(bytes)
-    };
-
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -2084,10 +8669,15 @@ impl Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the Tick tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_Tick(self) {
-        ()
+    /// Construct a tag named `CopyToClipboard`, with the appropriate payload
+    pub fn CopyToClipboard(arg: roc_std::RocStr) -> Self {
+            let mut answer = Self {
+                CopyToClipboard: core::mem::ManuallyDrop::new(arg)
+            };
+
+            answer.set_discriminant(discriminant_Command::CopyToClipboard);
+
+            answer
     }
 
     #[cfg(any(
@@ -2097,79 +8687,45 @@ impl Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the Tick tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_Tick(&self) {
-        ()
-    }
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// Returns which variant this tag union holds. Note that this never includes a payload!
-    pub fn discriminant(&self) -> discriminant_Event {
-        unsafe {
-            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `CopyToClipboard` and convert it to `CopyToClipboard`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `CopyToClipboard`.
+            pub unsafe fn into_CopyToClipboard(mut self) -> roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::CopyToClipboard);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.CopyToClipboard,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
 
-            core::mem::transmute::<u8, discriminant_Event>(*bytes.as_ptr().add(32))
-        }
-    }
+            core::mem::forget(self);
 
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// Internal helper
-    fn set_discriminant(&mut self, discriminant: discriminant_Event) {
-        let discriminant_ptr: *mut discriminant_Event = (self as *mut Event).cast();
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
 
-        unsafe {
-            *(discriminant_ptr.add(32)) = discriminant;
-        }
+        payload
     }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// A tag named FocusGained, which has no payload.
-    pub const FocusGained: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<Event>()];
-
-        bytes[32] = discriminant_Event::FocusGained as u8;
-
-        core::mem::transmute::<[u8; core::mem::size_of::<Event>()], Event>(bytes)
-    };
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// A tag named FocusLost, which has no payload.
-    pub const FocusLost: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<Event>()];
-
-        bytes[32] = discriminant_Event::FocusLost as u8;
-
-        core::mem::transmute::<[u8; core::mem::size_of::<Event>()], Event>(bytes)
-    };
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// A tag named Tick, which has no payload.
-    pub const Tick: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<Event>()];
-
-        bytes[32] = discriminant_Event::Tick as u8;
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `CopyToClipboard` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `CopyToClipboard`.
+            pub unsafe fn as_CopyToClipboard(&self) -> &roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::CopyToClipboard);
+        let payload = &self.CopyToClipboard;
 
-        core::mem::transmute::<[u8; core::mem::size_of::<Event>()], Event>(bytes)
-    };
-}
+        &payload
+    }
 
-impl Drop for Event {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -2177,23 +8733,17 @@ impl Drop for Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    fn drop(&mut self) {
-        // Drop the payloads
-                    match self.discriminant() {
-                discriminant_Event::FocusGained => {}
-                discriminant_Event::FocusLost => {}
-                discriminant_Event::KeyPressed => unsafe { core::mem::ManuallyDrop::drop(&mut self.KeyPressed) },
-                discriminant_Event::Paste => unsafe { core::mem::ManuallyDrop::drop(&mut self.Paste) },
-                discriminant_Event::Resize => {}
-                discriminant_Event::Tick => {}
-            }
+    /// Construct a tag named `DeleteFile`, with the appropriate payload
+    pub fn DeleteFile(arg: roc_std::RocStr) -> Self {
+            let mut answer = Self {
+                DeleteFile: core::mem::ManuallyDrop::new(arg)
+            };
 
-    }
-}
+            answer.set_discriminant(discriminant_Command::DeleteFile);
 
-impl Eq for Event {}
+            answer
+    }
 
-impl PartialEq for Event {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -2201,25 +8751,28 @@ impl PartialEq for Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    fn eq(&self, other: &Self) -> bool {
-            if self.discriminant() != other.discriminant() {
-                return false;
-            }
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `DeleteFile` and convert it to `DeleteFile`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `DeleteFile`.
+            pub unsafe fn into_DeleteFile(mut self) -> roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::DeleteFile);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.DeleteFile,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
 
-            unsafe {
-            match self.discriminant() {
-                discriminant_Event::FocusGained => true,
-                discriminant_Event::FocusLost => true,
-                discriminant_Event::KeyPressed => self.KeyPressed == other.KeyPressed,
-                discriminant_Event::Paste => self.Paste == other.Paste,
-                discriminant_Event::Resize => self.Resize == other.Resize,
-                discriminant_Event::Tick => true,
-            }
-        }
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        payload
     }
-}
 
-impl PartialOrd for Event {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -2227,26 +8780,16 @@ impl PartialOrd for Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        match self.discriminant().partial_cmp(&other.discriminant()) {
-            Some(core::cmp::Ordering::Equal) => {}
-            not_eq => return not_eq,
-        }
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `DeleteFile` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `DeleteFile`.
+            pub unsafe fn as_DeleteFile(&self) -> &roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::DeleteFile);
+        let payload = &self.DeleteFile;
 
-        unsafe {
-            match self.discriminant() {
-                discriminant_Event::FocusGained => Some(core::cmp::Ordering::Equal),
-                discriminant_Event::FocusLost => Some(core::cmp::Ordering::Equal),
-                discriminant_Event::KeyPressed => self.KeyPressed.partial_cmp(&other.KeyPressed),
-                discriminant_Event::Paste => self.Paste.partial_cmp(&other.Paste),
-                discriminant_Event::Resize => self.Resize.partial_cmp(&other.Resize),
-                discriminant_Event::Tick => Some(core::cmp::Ordering::Equal),
-            }
-        }
+        &payload
     }
-}
 
-impl Ord for Event {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -2254,26 +8797,17 @@ impl Ord for Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-            match self.discriminant().cmp(&other.discriminant()) {
-                core::cmp::Ordering::Equal => {}
-                not_eq => return not_eq,
-            }
+    /// Construct a tag named `KillProcess`, with the appropriate payload
+    pub fn KillProcess(arg: roc_std::RocStr) -> Self {
+            let mut answer = Self {
+                KillProcess: core::mem::ManuallyDrop::new(arg)
+            };
 
-            unsafe {
-            match self.discriminant() {
-                discriminant_Event::FocusGained => core::cmp::Ordering::Equal,
-                discriminant_Event::FocusLost => core::cmp::Ordering::Equal,
-                discriminant_Event::KeyPressed => self.KeyPressed.cmp(&other.KeyPressed),
-                discriminant_Event::Paste => self.Paste.cmp(&other.Paste),
-                discriminant_Event::Resize => self.Resize.cmp(&other.Resize),
-                discriminant_Event::Tick => core::cmp::Ordering::Equal,
-            }
-        }
+            answer.set_discriminant(discriminant_Command::KillProcess);
+
+            answer
     }
-}
 
-impl Clone for Event {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -2281,41 +8815,28 @@ impl Clone for Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    fn clone(&self) -> Self {
-        let mut answer = unsafe {
-            match self.discriminant() {
-                discriminant_Event::FocusGained => core::mem::transmute::<
-                    core::mem::MaybeUninit<Event>,
-                    Event,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_Event::FocusLost => core::mem::transmute::<
-                    core::mem::MaybeUninit<Event>,
-                    Event,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_Event::KeyPressed => Self {
-                    KeyPressed: self.KeyPressed.clone(),
-                },
-                discriminant_Event::Paste => Self {
-                    Paste: self.Paste.clone(),
-                },
-                discriminant_Event::Resize => Self {
-                    Resize: self.Resize.clone(),
-                },
-                discriminant_Event::Tick => core::mem::transmute::<
-                    core::mem::MaybeUninit<Event>,
-                    Event,
-                >(core::mem::MaybeUninit::uninit()),
-            }
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `KillProcess` and convert it to `KillProcess`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `KillProcess`.
+            pub unsafe fn into_KillProcess(mut self) -> roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::KillProcess);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.KillProcess,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
 
-        };
+            core::mem::forget(self);
 
-        answer.set_discriminant(self.discriminant());
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
 
-        answer
+        payload
     }
-}
 
-impl core::hash::Hash for Event {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -2323,27 +8844,16 @@ impl core::hash::Hash for Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
-            discriminant_Event::FocusGained => discriminant_Event::FocusGained.hash(state),
-            discriminant_Event::FocusLost => discriminant_Event::FocusLost.hash(state),
-            discriminant_Event::KeyPressed => unsafe {
-                    discriminant_Event::KeyPressed.hash(state);
-                    self.KeyPressed.hash(state);
-                },
-            discriminant_Event::Paste => unsafe {
-                    discriminant_Event::Paste.hash(state);
-                    self.Paste.hash(state);
-                },
-            discriminant_Event::Resize => unsafe {
-                    discriminant_Event::Resize.hash(state);
-                    self.Resize.hash(state);
-                },
-            discriminant_Event::Tick => discriminant_Event::Tick.hash(state),
-        }
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `KillProcess` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `KillProcess`.
+            pub unsafe fn as_KillProcess(&self) -> &roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::KillProcess);
+        let payload = &self.KillProcess;
+
+        &payload
     }
-}
 
-impl core::fmt::Debug for Event {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -2351,29 +8861,17 @@ impl core::fmt::Debug for Event {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str("Event::")?;
+    /// Construct a tag named `ListDir`, with the appropriate payload
+    pub fn ListDir(arg: roc_std::RocStr) -> Self {
+            let mut answer = Self {
+                ListDir: core::mem::ManuallyDrop::new(arg)
+            };
 
-        unsafe {
-            match self.discriminant() {
-                discriminant_Event::FocusGained => f.write_str("FocusGained"),
-                discriminant_Event::FocusLost => f.write_str("FocusLost"),
-                discriminant_Event::KeyPressed => f.debug_tuple("KeyPressed")
-        .field(&*self.KeyPressed)
-        .finish(),
-                discriminant_Event::Paste => f.debug_tuple("Paste")
-        .field(&*self.Paste)
-        .finish(),
-                discriminant_Event::Resize => f.debug_tuple("Resize")
-        .field(&self.Resize)
-        .finish(),
-                discriminant_Event::Tick => f.write_str("Tick"),
-            }
-        }
+            answer.set_discriminant(discriminant_Command::ListDir);
+
+            answer
     }
-}
 
-impl Constraint {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -2381,13 +8879,26 @@ impl Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Returns which variant this tag union holds. Note that this never includes a payload!
-    pub fn discriminant(&self) -> discriminant_Constraint {
-        unsafe {
-            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `ListDir` and convert it to `ListDir`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `ListDir`.
+            pub unsafe fn into_ListDir(mut self) -> roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::ListDir);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.ListDir,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
 
-            core::mem::transmute::<u8, discriminant_Constraint>(*bytes.as_ptr().add(8))
-        }
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        payload
     }
 
     #[cfg(any(
@@ -2397,13 +8908,14 @@ impl Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Internal helper
-    fn set_discriminant(&mut self, discriminant: discriminant_Constraint) {
-        let discriminant_ptr: *mut discriminant_Constraint = (self as *mut Constraint).cast();
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `ListDir` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `ListDir`.
+            pub unsafe fn as_ListDir(&self) -> &roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::ListDir);
+        let payload = &self.ListDir;
 
-        unsafe {
-            *(discriminant_ptr.add(8)) = discriminant;
-        }
+        &payload
     }
 
     #[cfg(any(
@@ -2413,13 +8925,13 @@ impl Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Construct a tag named `Length`, with the appropriate payload
-    pub fn Length(arg: u16) -> Self {
+    /// Construct a tag named `OpenEditor`, with the appropriate payload
+    pub fn OpenEditor(arg0: EditorConfig) -> Self {
             let mut answer = Self {
-                Length: arg
+                OpenEditor: core::mem::ManuallyDrop::new(arg0)
             };
 
-            answer.set_discriminant(discriminant_Constraint::Length);
+            answer.set_discriminant(discriminant_Command::OpenEditor);
 
             answer
     }
@@ -2431,13 +8943,26 @@ impl Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Length` and convert it to `Length`'s payload.
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `OpenEditor` and convert it to `OpenEditor`'s payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Length`.
-            pub unsafe fn into_Length(self) -> u16 {
-                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Length);
-        let payload = self.Length;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `OpenEditor`.
+            pub unsafe fn into_OpenEditor(mut self) -> EditorConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::OpenEditor);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.OpenEditor,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
 
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
         payload
     }
 
@@ -2448,14 +8973,15 @@ impl Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Length` and return its payload.
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `OpenEditor` and return its payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Length`.
-            pub unsafe fn as_Length(&self) -> &u16 {
-                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Length);
-        let payload = &self.Length;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `OpenEditor`.
+            pub unsafe fn as_OpenEditor(&self) -> &EditorConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::OpenEditor);
+        let payload = &self.OpenEditor;
 
-        &payload
+        
+        payload
     }
 
     #[cfg(any(
@@ -2465,13 +8991,13 @@ impl Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Construct a tag named `Max`, with the appropriate payload
-    pub fn Max(arg: u16) -> Self {
+    /// Construct a tag named `Quit`, with the appropriate payload
+    pub fn Quit(arg: u8) -> Self {
             let mut answer = Self {
-                Max: arg
+                Quit: arg
             };
 
-            answer.set_discriminant(discriminant_Constraint::Max);
+            answer.set_discriminant(discriminant_Command::Quit);
 
             answer
     }
@@ -2483,12 +9009,12 @@ impl Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Max` and convert it to `Max`'s payload.
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `Quit` and convert it to `Quit`'s payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Max`.
-            pub unsafe fn into_Max(self) -> u16 {
-                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Max);
-        let payload = self.Max;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Quit`.
+            pub unsafe fn into_Quit(self) -> u8 {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::Quit);
+        let payload = self.Quit;
 
         payload
     }
@@ -2500,16 +9026,30 @@ impl Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Max` and return its payload.
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `Quit` and return its payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Max`.
-            pub unsafe fn as_Max(&self) -> &u16 {
-                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Max);
-        let payload = &self.Max;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Quit`.
+            pub unsafe fn as_Quit(&self) -> &u8 {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::Quit);
+        let payload = &self.Quit;
 
         &payload
     }
 
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named ReadClipboard, which has no payload.
+    pub const ReadClipboard: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<Command>()];
+
+        bytes[60] = discriminant_Command::ReadClipboard as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<Command>()], Command>(bytes)
+    };
+
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -2517,13 +9057,39 @@ impl Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Construct a tag named `Min`, with the appropriate payload
-    pub fn Min(arg: u16) -> Self {
+    /// Other `into_` methods return a payload, but since the ReadClipboard tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_ReadClipboard(self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Other `as` methods return a payload, but since the ReadClipboard tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_ReadClipboard(&self) {
+        ()
+    }
+
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
+        target_arch = "x86_64"
+    ))]
+    /// Construct a tag named `ReadFile`, with the appropriate payload
+    pub fn ReadFile(arg: roc_std::RocStr) -> Self {
             let mut answer = Self {
-                Min: arg
+                ReadFile: core::mem::ManuallyDrop::new(arg)
             };
 
-            answer.set_discriminant(discriminant_Constraint::Min);
+            answer.set_discriminant(discriminant_Command::ReadFile);
 
             answer
     }
@@ -2535,12 +9101,24 @@ impl Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Min` and convert it to `Min`'s payload.
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `ReadFile` and convert it to `ReadFile`'s payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Min`.
-            pub unsafe fn into_Min(self) -> u16 {
-                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Min);
-        let payload = self.Min;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `ReadFile`.
+            pub unsafe fn into_ReadFile(mut self) -> roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::ReadFile);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.ReadFile,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
 
         payload
     }
@@ -2552,12 +9130,12 @@ impl Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Min` and return its payload.
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `ReadFile` and return its payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Min`.
-            pub unsafe fn as_Min(&self) -> &u16 {
-                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Min);
-        let payload = &self.Min;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `ReadFile`.
+            pub unsafe fn as_ReadFile(&self) -> &roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::ReadFile);
+        let payload = &self.ReadFile;
 
         &payload
     }
@@ -2569,13 +9147,13 @@ impl Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Construct a tag named `Percentage`, with the appropriate payload
-    pub fn Percentage(arg: u16) -> Self {
+    /// Construct a tag named `ReadFileLines`, with the appropriate payload
+    pub fn ReadFileLines(arg0: ReadLinesConfig) -> Self {
             let mut answer = Self {
-                Percentage: arg
+                ReadFileLines: core::mem::ManuallyDrop::new(arg0)
             };
 
-            answer.set_discriminant(discriminant_Constraint::Percentage);
+            answer.set_discriminant(discriminant_Command::ReadFileLines);
 
             answer
     }
@@ -2587,13 +9165,26 @@ impl Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Percentage` and convert it to `Percentage`'s payload.
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `ReadFileLines` and convert it to `ReadFileLines`'s payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Percentage`.
-            pub unsafe fn into_Percentage(self) -> u16 {
-                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Percentage);
-        let payload = self.Percentage;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `ReadFileLines`.
+            pub unsafe fn into_ReadFileLines(mut self) -> ReadLinesConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::ReadFileLines);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.ReadFileLines,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
 
+        
         payload
     }
 
@@ -2604,14 +9195,15 @@ impl Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Percentage` and return its payload.
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `ReadFileLines` and return its payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Percentage`.
-            pub unsafe fn as_Percentage(&self) -> &u16 {
-                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Percentage);
-        let payload = &self.Percentage;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `ReadFileLines`.
+            pub unsafe fn as_ReadFileLines(&self) -> &ReadLinesConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::ReadFileLines);
+        let payload = &self.ReadFileLines;
 
-        &payload
+        
+        payload
     }
 
     #[cfg(any(
@@ -2621,16 +9213,13 @@ impl Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Construct a tag named `Ratio`, with the appropriate payload
-    pub fn Ratio(arg0: u32, arg1: u32) -> Self {
+    /// Construct a tag named `RenameFile`, with the appropriate payload
+    pub fn RenameFile(arg0: RenameConfig) -> Self {
             let mut answer = Self {
-                Ratio: Constraint_Ratio {
-                    f0: arg0,
-                    f1: arg1,
-                }
+                RenameFile: core::mem::ManuallyDrop::new(arg0)
             };
 
-            answer.set_discriminant(discriminant_Constraint::Ratio);
+            answer.set_discriminant(discriminant_Command::RenameFile);
 
             answer
     }
@@ -2642,17 +9231,27 @@ impl Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Ratio` and convert it to `Ratio`'s payload.
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `RenameFile` and convert it to `RenameFile`'s payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Ratio`.
-            pub unsafe fn into_Ratio(self) -> (u32, u32) {
-                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Ratio);
-        let payload = self.Ratio;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `RenameFile`.
+            pub unsafe fn into_RenameFile(mut self) -> RenameConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::RenameFile);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.RenameFile,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
 
-        (
-            payload.f0, 
-            payload.f1
-        )
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
     }
 
     #[cfg(any(
@@ -2662,23 +9261,17 @@ impl Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Constraint` has a `.discriminant()` of `Ratio` and return its payload.
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `RenameFile` and return its payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Ratio`.
-            pub unsafe fn as_Ratio(&self) -> (&u32, &u32) {
-                debug_assert_eq!(self.discriminant(), discriminant_Constraint::Ratio);
-        let payload = &self.Ratio;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `RenameFile`.
+            pub unsafe fn as_RenameFile(&self) -> &RenameConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::RenameFile);
+        let payload = &self.RenameFile;
 
-        (
-            &payload.f0, 
-            &payload.f1
-        )
+        
+        payload
     }
-}
-
-impl Eq for Constraint {}
 
-impl PartialEq for Constraint {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -2686,24 +9279,17 @@ impl PartialEq for Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    fn eq(&self, other: &Self) -> bool {
-            if self.discriminant() != other.discriminant() {
-                return false;
-            }
+    /// Construct a tag named `SetBracketedPaste`, with the appropriate payload
+    pub fn SetBracketedPaste(arg: bool) -> Self {
+            let mut answer = Self {
+                SetBracketedPaste: arg
+            };
 
-            unsafe {
-            match self.discriminant() {
-                discriminant_Constraint::Length => self.Length == other.Length,
-                discriminant_Constraint::Max => self.Max == other.Max,
-                discriminant_Constraint::Min => self.Min == other.Min,
-                discriminant_Constraint::Percentage => self.Percentage == other.Percentage,
-                discriminant_Constraint::Ratio => self.Ratio == other.Ratio,
-            }
-        }
+            answer.set_discriminant(discriminant_Command::SetBracketedPaste);
+
+            answer
     }
-}
 
-impl PartialOrd for Constraint {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -2711,25 +9297,16 @@ impl PartialOrd for Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-        match self.discriminant().partial_cmp(&other.discriminant()) {
-            Some(core::cmp::Ordering::Equal) => {}
-            not_eq => return not_eq,
-        }
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `SetBracketedPaste` and convert it to `SetBracketedPaste`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `SetBracketedPaste`.
+            pub unsafe fn into_SetBracketedPaste(self) -> bool {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::SetBracketedPaste);
+        let payload = self.SetBracketedPaste;
 
-        unsafe {
-            match self.discriminant() {
-                discriminant_Constraint::Length => self.Length.partial_cmp(&other.Length),
-                discriminant_Constraint::Max => self.Max.partial_cmp(&other.Max),
-                discriminant_Constraint::Min => self.Min.partial_cmp(&other.Min),
-                discriminant_Constraint::Percentage => self.Percentage.partial_cmp(&other.Percentage),
-                discriminant_Constraint::Ratio => self.Ratio.partial_cmp(&other.Ratio),
-            }
-        }
+        payload
     }
-}
 
-impl Ord for Constraint {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -2737,27 +9314,16 @@ impl Ord for Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-            match self.discriminant().cmp(&other.discriminant()) {
-                core::cmp::Ordering::Equal => {}
-                not_eq => return not_eq,
-            }
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `SetBracketedPaste` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `SetBracketedPaste`.
+            pub unsafe fn as_SetBracketedPaste(&self) -> &bool {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::SetBracketedPaste);
+        let payload = &self.SetBracketedPaste;
 
-            unsafe {
-            match self.discriminant() {
-                discriminant_Constraint::Length => self.Length.cmp(&other.Length),
-                discriminant_Constraint::Max => self.Max.cmp(&other.Max),
-                discriminant_Constraint::Min => self.Min.cmp(&other.Min),
-                discriminant_Constraint::Percentage => self.Percentage.cmp(&other.Percentage),
-                discriminant_Constraint::Ratio => self.Ratio.cmp(&other.Ratio),
-            }
-        }
+        &payload
     }
-}
-
-impl Copy for Constraint {}
 
-impl Clone for Constraint {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -2765,35 +9331,17 @@ impl Clone for Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    fn clone(&self) -> Self {
-        let mut answer = unsafe {
-            match self.discriminant() {
-                discriminant_Constraint::Length => Self {
-                    Length: self.Length.clone(),
-                },
-                discriminant_Constraint::Max => Self {
-                    Max: self.Max.clone(),
-                },
-                discriminant_Constraint::Min => Self {
-                    Min: self.Min.clone(),
-                },
-                discriminant_Constraint::Percentage => Self {
-                    Percentage: self.Percentage.clone(),
-                },
-                discriminant_Constraint::Ratio => Self {
-                    Ratio: self.Ratio.clone(),
-                },
-            }
-
-        };
+    /// Construct a tag named `SetCursorStyle`, with the appropriate payload
+    pub fn SetCursorStyle(arg: CursorStyle) -> Self {
+            let mut answer = Self {
+                SetCursorStyle: arg
+            };
 
-        answer.set_discriminant(self.discriminant());
+            answer.set_discriminant(discriminant_Command::SetCursorStyle);
 
-        answer
+            answer
     }
-}
 
-impl core::hash::Hash for Constraint {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -2801,32 +9349,16 @@ impl core::hash::Hash for Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
-            discriminant_Constraint::Length => unsafe {
-                    discriminant_Constraint::Length.hash(state);
-                    self.Length.hash(state);
-                },
-            discriminant_Constraint::Max => unsafe {
-                    discriminant_Constraint::Max.hash(state);
-                    self.Max.hash(state);
-                },
-            discriminant_Constraint::Min => unsafe {
-                    discriminant_Constraint::Min.hash(state);
-                    self.Min.hash(state);
-                },
-            discriminant_Constraint::Percentage => unsafe {
-                    discriminant_Constraint::Percentage.hash(state);
-                    self.Percentage.hash(state);
-                },
-            discriminant_Constraint::Ratio => unsafe {
-                    discriminant_Constraint::Ratio.hash(state);
-                    self.Ratio.hash(state);
-                },
-        }
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `SetCursorStyle` and convert it to `SetCursorStyle`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `SetCursorStyle`.
+            pub unsafe fn into_SetCursorStyle(self) -> CursorStyle {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::SetCursorStyle);
+        let payload = self.SetCursorStyle;
+
+        payload
     }
-}
 
-impl core::fmt::Debug for Constraint {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -2834,75 +9366,34 @@ impl core::fmt::Debug for Constraint {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str("Constraint::")?;
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `SetCursorStyle` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `SetCursorStyle`.
+            pub unsafe fn as_SetCursorStyle(&self) -> &CursorStyle {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::SetCursorStyle);
+        let payload = &self.SetCursorStyle;
 
-        unsafe {
-            match self.discriminant() {
-                discriminant_Constraint::Length => f.debug_tuple("Length")
-        .field(&self.Length)
-        .finish(),
-                discriminant_Constraint::Max => f.debug_tuple("Max")
-        .field(&self.Max)
-        .finish(),
-                discriminant_Constraint::Min => f.debug_tuple("Min")
-        .field(&self.Min)
-        .finish(),
-                discriminant_Constraint::Percentage => f.debug_tuple("Percentage")
-        .field(&self.Percentage)
-        .finish(),
-                discriminant_Constraint::Ratio => f.debug_tuple("Ratio")
-        .field(&(&self.Ratio).f0)
-.field(&(&self.Ratio).f1)
-        .finish(),
-            }
-        }
+        &payload
     }
-}
 
-impl KeyCode {
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// Returns which variant this tag union holds. Note that this never includes a payload!
-    pub fn discriminant(&self) -> discriminant_KeyCode {
-        unsafe {
-            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
-
-            core::mem::transmute::<u8, discriminant_KeyCode>(*bytes.as_ptr().add(12))
-        }
-    }
+    /// Construct a tag named `SetFocusReporting`, with the appropriate payload
+    pub fn SetFocusReporting(arg: bool) -> Self {
+            let mut answer = Self {
+                SetFocusReporting: arg
+            };
 
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// Internal helper
-    fn set_discriminant(&mut self, discriminant: discriminant_KeyCode) {
-        let discriminant_ptr: *mut discriminant_KeyCode = (self as *mut KeyCode).cast();
+            answer.set_discriminant(discriminant_Command::SetFocusReporting);
 
-        unsafe {
-            *(discriminant_ptr.add(12)) = discriminant;
-        }
+            answer
     }
 
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// A tag named BackTab, which has no payload.
-    pub const BackTab: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[12] = discriminant_KeyCode::BackTab as u8;
-
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
-
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -2910,10 +9401,14 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the BackTab tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_BackTab(self) {
-        ()
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `SetFocusReporting` and convert it to `SetFocusReporting`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `SetFocusReporting`.
+            pub unsafe fn into_SetFocusReporting(self) -> bool {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::SetFocusReporting);
+        let payload = self.SetFocusReporting;
+
+        payload
     }
 
     #[cfg(any(
@@ -2923,25 +9418,33 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the BackTab tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_BackTab(&self) {
-        ()
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `SetFocusReporting` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `SetFocusReporting`.
+            pub unsafe fn as_SetFocusReporting(&self) -> &bool {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::SetFocusReporting);
+        let payload = &self.SetFocusReporting;
+
+        &payload
     }
 
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// A tag named Backspace, which has no payload.
-    pub const Backspace: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// Construct a tag named `SetTickRate`, with the appropriate payload
+    pub fn SetTickRate(arg: u64) -> Self {
+            let mut answer = Self {
+                SetTickRate: arg
+            };
 
-        bytes[12] = discriminant_KeyCode::Backspace as u8;
+            answer.set_discriminant(discriminant_Command::SetTickRate);
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+            answer
+    }
 
     #[cfg(any(
         target_arch = "arm",
@@ -2950,10 +9453,14 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the Backspace tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_Backspace(self) {
-        ()
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `SetTickRate` and convert it to `SetTickRate`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `SetTickRate`.
+            pub unsafe fn into_SetTickRate(self) -> u64 {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::SetTickRate);
+        let payload = self.SetTickRate;
+
+        payload
     }
 
     #[cfg(any(
@@ -2963,25 +9470,33 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the Backspace tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_Backspace(&self) {
-        ()
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `SetTickRate` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `SetTickRate`.
+            pub unsafe fn as_SetTickRate(&self) -> &u64 {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::SetTickRate);
+        let payload = &self.SetTickRate;
+
+        &payload
     }
 
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// A tag named CapsLock, which has no payload.
-    pub const CapsLock: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// Construct a tag named `SetTitle`, with the appropriate payload
+    pub fn SetTitle(arg: roc_std::RocStr) -> Self {
+            let mut answer = Self {
+                SetTitle: core::mem::ManuallyDrop::new(arg)
+            };
 
-        bytes[12] = discriminant_KeyCode::CapsLock as u8;
+            answer.set_discriminant(discriminant_Command::SetTitle);
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+            answer
+    }
 
     #[cfg(any(
         target_arch = "arm",
@@ -2990,10 +9505,26 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the CapsLock tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_CapsLock(self) {
-        ()
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `SetTitle` and convert it to `SetTitle`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `SetTitle`.
+            pub unsafe fn into_SetTitle(mut self) -> roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::SetTitle);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.SetTitle,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        payload
     }
 
     #[cfg(any(
@@ -3003,25 +9534,33 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the CapsLock tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_CapsLock(&self) {
-        ()
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `SetTitle` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `SetTitle`.
+            pub unsafe fn as_SetTitle(&self) -> &roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::SetTitle);
+        let payload = &self.SetTitle;
+
+        &payload
     }
 
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// A tag named Delete, which has no payload.
-    pub const Delete: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// Construct a tag named `SpawnProcess`, with the appropriate payload
+    pub fn SpawnProcess(arg0: SpawnConfig) -> Self {
+            let mut answer = Self {
+                SpawnProcess: core::mem::ManuallyDrop::new(arg0)
+            };
 
-        bytes[12] = discriminant_KeyCode::Delete as u8;
+            answer.set_discriminant(discriminant_Command::SpawnProcess);
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+            answer
+    }
 
     #[cfg(any(
         target_arch = "arm",
@@ -3030,10 +9569,27 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the Delete tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_Delete(self) {
-        ()
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `SpawnProcess` and convert it to `SpawnProcess`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `SpawnProcess`.
+            pub unsafe fn into_SpawnProcess(mut self) -> SpawnConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::SpawnProcess);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.SpawnProcess,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
     }
 
     #[cfg(any(
@@ -3043,25 +9599,34 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the Delete tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_Delete(&self) {
-        ()
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `SpawnProcess` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `SpawnProcess`.
+            pub unsafe fn as_SpawnProcess(&self) -> &SpawnConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::SpawnProcess);
+        let payload = &self.SpawnProcess;
+
+        
+        payload
     }
 
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// A tag named Down, which has no payload.
-    pub const Down: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// Construct a tag named `StartTimer`, with the appropriate payload
+    pub fn StartTimer(arg0: TimerConfig) -> Self {
+            let mut answer = Self {
+                StartTimer: core::mem::ManuallyDrop::new(arg0)
+            };
 
-        bytes[12] = discriminant_KeyCode::Down as u8;
+            answer.set_discriminant(discriminant_Command::StartTimer);
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+            answer
+    }
 
     #[cfg(any(
         target_arch = "arm",
@@ -3070,10 +9635,27 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the Down tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_Down(self) {
-        ()
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `StartTimer` and convert it to `StartTimer`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `StartTimer`.
+            pub unsafe fn into_StartTimer(mut self) -> TimerConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::StartTimer);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.StartTimer,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
     }
 
     #[cfg(any(
@@ -3083,25 +9665,34 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the Down tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_Down(&self) {
-        ()
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `StartTimer` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `StartTimer`.
+            pub unsafe fn as_StartTimer(&self) -> &TimerConfig {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::StartTimer);
+        let payload = &self.StartTimer;
+
+        
+        payload
     }
 
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// A tag named End, which has no payload.
-    pub const End: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// Construct a tag named `WriteFile`, with the appropriate payload
+    pub fn WriteFile(arg0: FileContents) -> Self {
+            let mut answer = Self {
+                WriteFile: core::mem::ManuallyDrop::new(arg0)
+            };
 
-        bytes[12] = discriminant_KeyCode::End as u8;
+            answer.set_discriminant(discriminant_Command::WriteFile);
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+            answer
+    }
 
     #[cfg(any(
         target_arch = "arm",
@@ -3110,10 +9701,27 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the End tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_End(self) {
-        ()
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `WriteFile` and convert it to `WriteFile`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `WriteFile`.
+            pub unsafe fn into_WriteFile(mut self) -> FileContents {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::WriteFile);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.WriteFile,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
     }
 
     #[cfg(any(
@@ -3123,25 +9731,34 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the End tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_End(&self) {
-        ()
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `WriteFile` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `WriteFile`.
+            pub unsafe fn as_WriteFile(&self) -> &FileContents {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::WriteFile);
+        let payload = &self.WriteFile;
+
+        
+        payload
     }
 
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// A tag named Enter, which has no payload.
-    pub const Enter: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// Construct a tag named `WriteStdin`, with the appropriate payload
+    pub fn WriteStdin(arg0: ProcessInput) -> Self {
+            let mut answer = Self {
+                WriteStdin: core::mem::ManuallyDrop::new(arg0)
+            };
 
-        bytes[12] = discriminant_KeyCode::Enter as u8;
+            answer.set_discriminant(discriminant_Command::WriteStdin);
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+            answer
+    }
 
     #[cfg(any(
         target_arch = "arm",
@@ -3150,10 +9767,27 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the Enter tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_Enter(self) {
-        ()
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `WriteStdin` and convert it to `WriteStdin`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `WriteStdin`.
+            pub unsafe fn into_WriteStdin(mut self) -> ProcessInput {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::WriteStdin);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.WriteStdin,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
+
+        
+        payload
     }
 
     #[cfg(any(
@@ -3163,26 +9797,58 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the Enter tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_Enter(&self) {
-        ()
+    /// Unsafely assume the given `Command` has a `.discriminant()` of `WriteStdin` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `WriteStdin`.
+            pub unsafe fn as_WriteStdin(&self) -> &ProcessInput {
+                debug_assert_eq!(self.discriminant(), discriminant_Command::WriteStdin);
+        let payload = &self.WriteStdin;
+
+        
+        payload
+    }
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// Returns which variant this tag union holds. Note that this never includes a payload!
+    pub fn discriminant(&self) -> discriminant_Command {
+        unsafe {
+            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
+
+            core::mem::transmute::<u8, discriminant_Command>(*bytes.as_ptr().add(120))
+        }
+    }
+
+    #[cfg(any(
+        target_arch = "aarch64",
+        target_arch = "x86_64"
+    ))]
+    /// Internal helper
+    fn set_discriminant(&mut self, discriminant: discriminant_Command) {
+        let discriminant_ptr: *mut discriminant_Command = (self as *mut Command).cast();
+
+        unsafe {
+            *(discriminant_ptr.add(120)) = discriminant;
+        }
     }
 
     #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "aarch64",
+        target_arch = "x86_64"
     ))]
-    /// A tag named Esc, which has no payload.
-    pub const Esc: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// A tag named ReadClipboard, which has no payload.
+    pub const ReadClipboard: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<Command>()];
 
-        bytes[12] = discriminant_KeyCode::Esc as u8;
+        bytes[120] = discriminant_Command::ReadClipboard as u8;
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+        core::mem::transmute::<[u8; core::mem::size_of::<Command>()], Command>(bytes)
     };
+}
 
+impl Drop for Command {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -3190,12 +9856,39 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the Esc tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_Esc(self) {
-        ()
+    fn drop(&mut self) {
+        // Drop the payloads
+                    match self.discriminant() {
+                discriminant_Command::AppendFile => unsafe { core::mem::ManuallyDrop::drop(&mut self.AppendFile) },
+                discriminant_Command::CancelTimer => unsafe { core::mem::ManuallyDrop::drop(&mut self.CancelTimer) },
+                discriminant_Command::CloseStdin => unsafe { core::mem::ManuallyDrop::drop(&mut self.CloseStdin) },
+                discriminant_Command::CopyToClipboard => unsafe { core::mem::ManuallyDrop::drop(&mut self.CopyToClipboard) },
+                discriminant_Command::DeleteFile => unsafe { core::mem::ManuallyDrop::drop(&mut self.DeleteFile) },
+                discriminant_Command::KillProcess => unsafe { core::mem::ManuallyDrop::drop(&mut self.KillProcess) },
+                discriminant_Command::ListDir => unsafe { core::mem::ManuallyDrop::drop(&mut self.ListDir) },
+                discriminant_Command::OpenEditor => unsafe { core::mem::ManuallyDrop::drop(&mut self.OpenEditor) },
+                discriminant_Command::Quit => {}
+                discriminant_Command::ReadClipboard => {}
+                discriminant_Command::ReadFile => unsafe { core::mem::ManuallyDrop::drop(&mut self.ReadFile) },
+                discriminant_Command::ReadFileLines => unsafe { core::mem::ManuallyDrop::drop(&mut self.ReadFileLines) },
+                discriminant_Command::RenameFile => unsafe { core::mem::ManuallyDrop::drop(&mut self.RenameFile) },
+                discriminant_Command::SetBracketedPaste => {}
+                discriminant_Command::SetCursorStyle => {}
+                discriminant_Command::SetFocusReporting => {}
+                discriminant_Command::SetTickRate => {}
+                discriminant_Command::SetTitle => unsafe { core::mem::ManuallyDrop::drop(&mut self.SetTitle) },
+                discriminant_Command::SpawnProcess => unsafe { core::mem::ManuallyDrop::drop(&mut self.SpawnProcess) },
+                discriminant_Command::StartTimer => unsafe { core::mem::ManuallyDrop::drop(&mut self.StartTimer) },
+                discriminant_Command::WriteFile => unsafe { core::mem::ManuallyDrop::drop(&mut self.WriteFile) },
+                discriminant_Command::WriteStdin => unsafe { core::mem::ManuallyDrop::drop(&mut self.WriteStdin) },
+            }
+
     }
+}
 
+impl Eq for Command {}
+
+impl PartialEq for Command {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -3203,12 +9896,41 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the Esc tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_Esc(&self) {
-        ()
+    fn eq(&self, other: &Self) -> bool {
+            if self.discriminant() != other.discriminant() {
+                return false;
+            }
+
+            unsafe {
+            match self.discriminant() {
+                discriminant_Command::AppendFile => self.AppendFile == other.AppendFile,
+                discriminant_Command::CancelTimer => self.CancelTimer == other.CancelTimer,
+                discriminant_Command::CloseStdin => self.CloseStdin == other.CloseStdin,
+                discriminant_Command::CopyToClipboard => self.CopyToClipboard == other.CopyToClipboard,
+                discriminant_Command::DeleteFile => self.DeleteFile == other.DeleteFile,
+                discriminant_Command::KillProcess => self.KillProcess == other.KillProcess,
+                discriminant_Command::ListDir => self.ListDir == other.ListDir,
+                discriminant_Command::OpenEditor => self.OpenEditor == other.OpenEditor,
+                discriminant_Command::Quit => self.Quit == other.Quit,
+                discriminant_Command::ReadClipboard => true,
+                discriminant_Command::ReadFile => self.ReadFile == other.ReadFile,
+                discriminant_Command::ReadFileLines => self.ReadFileLines == other.ReadFileLines,
+                discriminant_Command::RenameFile => self.RenameFile == other.RenameFile,
+                discriminant_Command::SetBracketedPaste => self.SetBracketedPaste == other.SetBracketedPaste,
+                discriminant_Command::SetCursorStyle => self.SetCursorStyle == other.SetCursorStyle,
+                discriminant_Command::SetFocusReporting => self.SetFocusReporting == other.SetFocusReporting,
+                discriminant_Command::SetTickRate => self.SetTickRate == other.SetTickRate,
+                discriminant_Command::SetTitle => self.SetTitle == other.SetTitle,
+                discriminant_Command::SpawnProcess => self.SpawnProcess == other.SpawnProcess,
+                discriminant_Command::StartTimer => self.StartTimer == other.StartTimer,
+                discriminant_Command::WriteFile => self.WriteFile == other.WriteFile,
+                discriminant_Command::WriteStdin => self.WriteStdin == other.WriteStdin,
+            }
+        }
     }
+}
 
+impl PartialOrd for Command {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -3216,17 +9938,42 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Construct a tag named `Function`, with the appropriate payload
-    pub fn Function(arg: u8) -> Self {
-            let mut answer = Self {
-                Function: arg
-            };
-
-            answer.set_discriminant(discriminant_KeyCode::Function);
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match self.discriminant().partial_cmp(&other.discriminant()) {
+            Some(core::cmp::Ordering::Equal) => {}
+            not_eq => return not_eq,
+        }
 
-            answer
+        unsafe {
+            match self.discriminant() {
+                discriminant_Command::AppendFile => self.AppendFile.partial_cmp(&other.AppendFile),
+                discriminant_Command::CancelTimer => self.CancelTimer.partial_cmp(&other.CancelTimer),
+                discriminant_Command::CloseStdin => self.CloseStdin.partial_cmp(&other.CloseStdin),
+                discriminant_Command::CopyToClipboard => self.CopyToClipboard.partial_cmp(&other.CopyToClipboard),
+                discriminant_Command::DeleteFile => self.DeleteFile.partial_cmp(&other.DeleteFile),
+                discriminant_Command::KillProcess => self.KillProcess.partial_cmp(&other.KillProcess),
+                discriminant_Command::ListDir => self.ListDir.partial_cmp(&other.ListDir),
+                discriminant_Command::OpenEditor => self.OpenEditor.partial_cmp(&other.OpenEditor),
+                discriminant_Command::Quit => self.Quit.partial_cmp(&other.Quit),
+                discriminant_Command::ReadClipboard => Some(core::cmp::Ordering::Equal),
+                discriminant_Command::ReadFile => self.ReadFile.partial_cmp(&other.ReadFile),
+                discriminant_Command::ReadFileLines => self.ReadFileLines.partial_cmp(&other.ReadFileLines),
+                discriminant_Command::RenameFile => self.RenameFile.partial_cmp(&other.RenameFile),
+                discriminant_Command::SetBracketedPaste => self.SetBracketedPaste.partial_cmp(&other.SetBracketedPaste),
+                discriminant_Command::SetCursorStyle => self.SetCursorStyle.partial_cmp(&other.SetCursorStyle),
+                discriminant_Command::SetFocusReporting => self.SetFocusReporting.partial_cmp(&other.SetFocusReporting),
+                discriminant_Command::SetTickRate => self.SetTickRate.partial_cmp(&other.SetTickRate),
+                discriminant_Command::SetTitle => self.SetTitle.partial_cmp(&other.SetTitle),
+                discriminant_Command::SpawnProcess => self.SpawnProcess.partial_cmp(&other.SpawnProcess),
+                discriminant_Command::StartTimer => self.StartTimer.partial_cmp(&other.StartTimer),
+                discriminant_Command::WriteFile => self.WriteFile.partial_cmp(&other.WriteFile),
+                discriminant_Command::WriteStdin => self.WriteStdin.partial_cmp(&other.WriteStdin),
+            }
+        }
     }
+}
 
+impl Ord for Command {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -3234,16 +9981,42 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `KeyCode` has a `.discriminant()` of `Function` and convert it to `Function`'s payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Function`.
-            pub unsafe fn into_Function(self) -> u8 {
-                debug_assert_eq!(self.discriminant(), discriminant_KeyCode::Function);
-        let payload = self.Function;
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            match self.discriminant().cmp(&other.discriminant()) {
+                core::cmp::Ordering::Equal => {}
+                not_eq => return not_eq,
+            }
 
-        payload
+            unsafe {
+            match self.discriminant() {
+                discriminant_Command::AppendFile => self.AppendFile.cmp(&other.AppendFile),
+                discriminant_Command::CancelTimer => self.CancelTimer.cmp(&other.CancelTimer),
+                discriminant_Command::CloseStdin => self.CloseStdin.cmp(&other.CloseStdin),
+                discriminant_Command::CopyToClipboard => self.CopyToClipboard.cmp(&other.CopyToClipboard),
+                discriminant_Command::DeleteFile => self.DeleteFile.cmp(&other.DeleteFile),
+                discriminant_Command::KillProcess => self.KillProcess.cmp(&other.KillProcess),
+                discriminant_Command::ListDir => self.ListDir.cmp(&other.ListDir),
+                discriminant_Command::OpenEditor => self.OpenEditor.cmp(&other.OpenEditor),
+                discriminant_Command::Quit => self.Quit.cmp(&other.Quit),
+                discriminant_Command::ReadClipboard => core::cmp::Ordering::Equal,
+                discriminant_Command::ReadFile => self.ReadFile.cmp(&other.ReadFile),
+                discriminant_Command::ReadFileLines => self.ReadFileLines.cmp(&other.ReadFileLines),
+                discriminant_Command::RenameFile => self.RenameFile.cmp(&other.RenameFile),
+                discriminant_Command::SetBracketedPaste => self.SetBracketedPaste.cmp(&other.SetBracketedPaste),
+                discriminant_Command::SetCursorStyle => self.SetCursorStyle.cmp(&other.SetCursorStyle),
+                discriminant_Command::SetFocusReporting => self.SetFocusReporting.cmp(&other.SetFocusReporting),
+                discriminant_Command::SetTickRate => self.SetTickRate.cmp(&other.SetTickRate),
+                discriminant_Command::SetTitle => self.SetTitle.cmp(&other.SetTitle),
+                discriminant_Command::SpawnProcess => self.SpawnProcess.cmp(&other.SpawnProcess),
+                discriminant_Command::StartTimer => self.StartTimer.cmp(&other.StartTimer),
+                discriminant_Command::WriteFile => self.WriteFile.cmp(&other.WriteFile),
+                discriminant_Command::WriteStdin => self.WriteStdin.cmp(&other.WriteStdin),
+            }
+        }
     }
+}
 
+impl Clone for Command {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -3251,30 +10024,87 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `KeyCode` has a `.discriminant()` of `Function` and return its payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Function`.
-            pub unsafe fn as_Function(&self) -> &u8 {
-                debug_assert_eq!(self.discriminant(), discriminant_KeyCode::Function);
-        let payload = &self.Function;
-
-        &payload
-    }
+    fn clone(&self) -> Self {
+        let mut answer = unsafe {
+            match self.discriminant() {
+                discriminant_Command::AppendFile => Self {
+                    AppendFile: self.AppendFile.clone(),
+                },
+                discriminant_Command::CancelTimer => Self {
+                    CancelTimer: self.CancelTimer.clone(),
+                },
+                discriminant_Command::CloseStdin => Self {
+                    CloseStdin: self.CloseStdin.clone(),
+                },
+                discriminant_Command::CopyToClipboard => Self {
+                    CopyToClipboard: self.CopyToClipboard.clone(),
+                },
+                discriminant_Command::DeleteFile => Self {
+                    DeleteFile: self.DeleteFile.clone(),
+                },
+                discriminant_Command::KillProcess => Self {
+                    KillProcess: self.KillProcess.clone(),
+                },
+                discriminant_Command::ListDir => Self {
+                    ListDir: self.ListDir.clone(),
+                },
+                discriminant_Command::OpenEditor => Self {
+                    OpenEditor: self.OpenEditor.clone(),
+                },
+                discriminant_Command::Quit => Self {
+                    Quit: self.Quit.clone(),
+                },
+                discriminant_Command::ReadClipboard => core::mem::transmute::<
+                    core::mem::MaybeUninit<Command>,
+                    Command,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_Command::ReadFile => Self {
+                    ReadFile: self.ReadFile.clone(),
+                },
+                discriminant_Command::ReadFileLines => Self {
+                    ReadFileLines: self.ReadFileLines.clone(),
+                },
+                discriminant_Command::RenameFile => Self {
+                    RenameFile: self.RenameFile.clone(),
+                },
+                discriminant_Command::SetBracketedPaste => Self {
+                    SetBracketedPaste: self.SetBracketedPaste.clone(),
+                },
+                discriminant_Command::SetCursorStyle => Self {
+                    SetCursorStyle: self.SetCursorStyle.clone(),
+                },
+                discriminant_Command::SetFocusReporting => Self {
+                    SetFocusReporting: self.SetFocusReporting.clone(),
+                },
+                discriminant_Command::SetTickRate => Self {
+                    SetTickRate: self.SetTickRate.clone(),
+                },
+                discriminant_Command::SetTitle => Self {
+                    SetTitle: self.SetTitle.clone(),
+                },
+                discriminant_Command::SpawnProcess => Self {
+                    SpawnProcess: self.SpawnProcess.clone(),
+                },
+                discriminant_Command::StartTimer => Self {
+                    StartTimer: self.StartTimer.clone(),
+                },
+                discriminant_Command::WriteFile => Self {
+                    WriteFile: self.WriteFile.clone(),
+                },
+                discriminant_Command::WriteStdin => Self {
+                    WriteStdin: self.WriteStdin.clone(),
+                },
+            }
 
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// A tag named Home, which has no payload.
-    pub const Home: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+        };
 
-        bytes[12] = discriminant_KeyCode::Home as u8;
+        answer.set_discriminant(self.discriminant());
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+        answer
+    }
+}
 
+impl core::hash::Hash for Command {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -3282,12 +10112,97 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the Home tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_Home(self) {
-        ()
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
+            discriminant_Command::AppendFile => unsafe {
+                    discriminant_Command::AppendFile.hash(state);
+                    self.AppendFile.hash(state);
+                },
+            discriminant_Command::CancelTimer => unsafe {
+                    discriminant_Command::CancelTimer.hash(state);
+                    self.CancelTimer.hash(state);
+                },
+            discriminant_Command::CloseStdin => unsafe {
+                    discriminant_Command::CloseStdin.hash(state);
+                    self.CloseStdin.hash(state);
+                },
+            discriminant_Command::CopyToClipboard => unsafe {
+                    discriminant_Command::CopyToClipboard.hash(state);
+                    self.CopyToClipboard.hash(state);
+                },
+            discriminant_Command::DeleteFile => unsafe {
+                    discriminant_Command::DeleteFile.hash(state);
+                    self.DeleteFile.hash(state);
+                },
+            discriminant_Command::KillProcess => unsafe {
+                    discriminant_Command::KillProcess.hash(state);
+                    self.KillProcess.hash(state);
+                },
+            discriminant_Command::ListDir => unsafe {
+                    discriminant_Command::ListDir.hash(state);
+                    self.ListDir.hash(state);
+                },
+            discriminant_Command::OpenEditor => unsafe {
+                    discriminant_Command::OpenEditor.hash(state);
+                    self.OpenEditor.hash(state);
+                },
+            discriminant_Command::Quit => unsafe {
+                    discriminant_Command::Quit.hash(state);
+                    self.Quit.hash(state);
+                },
+            discriminant_Command::ReadClipboard => discriminant_Command::ReadClipboard.hash(state),
+            discriminant_Command::ReadFile => unsafe {
+                    discriminant_Command::ReadFile.hash(state);
+                    self.ReadFile.hash(state);
+                },
+            discriminant_Command::ReadFileLines => unsafe {
+                    discriminant_Command::ReadFileLines.hash(state);
+                    self.ReadFileLines.hash(state);
+                },
+            discriminant_Command::RenameFile => unsafe {
+                    discriminant_Command::RenameFile.hash(state);
+                    self.RenameFile.hash(state);
+                },
+            discriminant_Command::SetBracketedPaste => unsafe {
+                    discriminant_Command::SetBracketedPaste.hash(state);
+                    self.SetBracketedPaste.hash(state);
+                },
+            discriminant_Command::SetCursorStyle => unsafe {
+                    discriminant_Command::SetCursorStyle.hash(state);
+                    self.SetCursorStyle.hash(state);
+                },
+            discriminant_Command::SetFocusReporting => unsafe {
+                    discriminant_Command::SetFocusReporting.hash(state);
+                    self.SetFocusReporting.hash(state);
+                },
+            discriminant_Command::SetTickRate => unsafe {
+                    discriminant_Command::SetTickRate.hash(state);
+                    self.SetTickRate.hash(state);
+                },
+            discriminant_Command::SetTitle => unsafe {
+                    discriminant_Command::SetTitle.hash(state);
+                    self.SetTitle.hash(state);
+                },
+            discriminant_Command::SpawnProcess => unsafe {
+                    discriminant_Command::SpawnProcess.hash(state);
+                    self.SpawnProcess.hash(state);
+                },
+            discriminant_Command::StartTimer => unsafe {
+                    discriminant_Command::StartTimer.hash(state);
+                    self.StartTimer.hash(state);
+                },
+            discriminant_Command::WriteFile => unsafe {
+                    discriminant_Command::WriteFile.hash(state);
+                    self.WriteFile.hash(state);
+                },
+            discriminant_Command::WriteStdin => unsafe {
+                    discriminant_Command::WriteStdin.hash(state);
+                    self.WriteStdin.hash(state);
+                },
+        }
     }
+}
 
+impl core::fmt::Debug for Command {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -3295,50 +10210,107 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the Home tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_Home(&self) {
-        ()
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Command::")?;
+
+        unsafe {
+            match self.discriminant() {
+                discriminant_Command::AppendFile => f.debug_tuple("AppendFile")
+        .field(&*self.AppendFile)
+        .finish(),
+                discriminant_Command::CancelTimer => f.debug_tuple("CancelTimer")
+        .field(&*self.CancelTimer)
+        .finish(),
+                discriminant_Command::CloseStdin => f.debug_tuple("CloseStdin")
+        .field(&*self.CloseStdin)
+        .finish(),
+                discriminant_Command::CopyToClipboard => f.debug_tuple("CopyToClipboard")
+        .field(&*self.CopyToClipboard)
+        .finish(),
+                discriminant_Command::DeleteFile => f.debug_tuple("DeleteFile")
+        .field(&*self.DeleteFile)
+        .finish(),
+                discriminant_Command::KillProcess => f.debug_tuple("KillProcess")
+        .field(&*self.KillProcess)
+        .finish(),
+                discriminant_Command::ListDir => f.debug_tuple("ListDir")
+        .field(&*self.ListDir)
+        .finish(),
+                discriminant_Command::OpenEditor => f.debug_tuple("OpenEditor")
+        .field(&*self.OpenEditor)
+        .finish(),
+                discriminant_Command::Quit => f.debug_tuple("Quit")
+        .field(&self.Quit)
+        .finish(),
+                discriminant_Command::ReadClipboard => f.write_str("ReadClipboard"),
+                discriminant_Command::ReadFile => f.debug_tuple("ReadFile")
+        .field(&*self.ReadFile)
+        .finish(),
+                discriminant_Command::ReadFileLines => f.debug_tuple("ReadFileLines")
+        .field(&*self.ReadFileLines)
+        .finish(),
+                discriminant_Command::RenameFile => f.debug_tuple("RenameFile")
+        .field(&*self.RenameFile)
+        .finish(),
+                discriminant_Command::SetBracketedPaste => f.debug_tuple("SetBracketedPaste")
+        .field(&self.SetBracketedPaste)
+        .finish(),
+                discriminant_Command::SetCursorStyle => f.debug_tuple("SetCursorStyle")
+        .field(&self.SetCursorStyle)
+        .finish(),
+                discriminant_Command::SetFocusReporting => f.debug_tuple("SetFocusReporting")
+        .field(&self.SetFocusReporting)
+        .finish(),
+                discriminant_Command::SetTickRate => f.debug_tuple("SetTickRate")
+        .field(&self.SetTickRate)
+        .finish(),
+                discriminant_Command::SetTitle => f.debug_tuple("SetTitle")
+        .field(&*self.SetTitle)
+        .finish(),
+                discriminant_Command::SpawnProcess => f.debug_tuple("SpawnProcess")
+        .field(&*self.SpawnProcess)
+        .finish(),
+                discriminant_Command::StartTimer => f.debug_tuple("StartTimer")
+        .field(&*self.StartTimer)
+        .finish(),
+                discriminant_Command::WriteFile => f.debug_tuple("WriteFile")
+        .field(&*self.WriteFile)
+        .finish(),
+                discriminant_Command::WriteStdin => f.debug_tuple("WriteStdin")
+        .field(&*self.WriteStdin)
+        .finish(),
+            }
+        }
     }
+}
 
+impl FileError {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "wasm32",
         target_arch = "x86"
     ))]
-    /// A tag named Insert, which has no payload.
-    pub const Insert: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[12] = discriminant_KeyCode::Insert as u8;
-
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+    /// Returns which variant this tag union holds. Note that this never includes a payload!
+    pub fn discriminant(&self) -> discriminant_FileError {
+        unsafe {
+            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
 
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    /// Other `into_` methods return a payload, but since the Insert tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_Insert(self) {
-        ()
+            core::mem::transmute::<u8, discriminant_FileError>(*bytes.as_ptr().add(12))
+        }
     }
 
     #[cfg(any(
         target_arch = "arm",
-        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
+        target_arch = "x86"
     ))]
-    /// Other `as` methods return a payload, but since the Insert tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_Insert(&self) {
-        ()
+    /// Internal helper
+    fn set_discriminant(&mut self, discriminant: discriminant_FileError) {
+        let discriminant_ptr: *mut discriminant_FileError = (self as *mut FileError).cast();
+
+        unsafe {
+            *(discriminant_ptr.add(12)) = discriminant;
+        }
     }
 
     #[cfg(any(
@@ -3346,13 +10318,13 @@ impl KeyCode {
         target_arch = "wasm32",
         target_arch = "x86"
     ))]
-    /// A tag named KeypadBegin, which has no payload.
-    pub const KeypadBegin: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// A tag named AlreadyExists, which has no payload.
+    pub const AlreadyExists: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<FileError>()];
 
-        bytes[12] = discriminant_KeyCode::KeypadBegin as u8;
+        bytes[12] = discriminant_FileError::AlreadyExists as u8;
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+        core::mem::transmute::<[u8; core::mem::size_of::<FileError>()], FileError>(bytes)
     };
 
     #[cfg(any(
@@ -3362,9 +10334,9 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the KeypadBegin tag
+    /// Other `into_` methods return a payload, but since the AlreadyExists tag
     /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_KeypadBegin(self) {
+    pub fn into_AlreadyExists(self) {
         ()
     }
 
@@ -3375,9 +10347,9 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the KeypadBegin tag
+    /// Other `as` methods return a payload, but since the AlreadyExists tag
     /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_KeypadBegin(&self) {
+    pub fn as_AlreadyExists(&self) {
         ()
     }
 
@@ -3386,13 +10358,13 @@ impl KeyCode {
         target_arch = "wasm32",
         target_arch = "x86"
     ))]
-    /// A tag named Left, which has no payload.
-    pub const Left: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// A tag named DiskFull, which has no payload.
+    pub const DiskFull: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<FileError>()];
 
-        bytes[12] = discriminant_KeyCode::Left as u8;
+        bytes[12] = discriminant_FileError::DiskFull as u8;
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+        core::mem::transmute::<[u8; core::mem::size_of::<FileError>()], FileError>(bytes)
     };
 
     #[cfg(any(
@@ -3402,9 +10374,9 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the Left tag
+    /// Other `into_` methods return a payload, but since the DiskFull tag
     /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_Left(self) {
+    pub fn into_DiskFull(self) {
         ()
     }
 
@@ -3415,29 +10387,25 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the Left tag
+    /// Other `as` methods return a payload, but since the DiskFull tag
     /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_Left(&self) {
+    pub fn as_DiskFull(&self) {
         ()
     }
 
     #[cfg(any(
-        target_arch = "arm",
-        target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
-        target_arch = "x86_64"
-    ))]
-    /// Construct a tag named `Media`, with the appropriate payload
-    pub fn Media(arg: MediaKeyCode) -> Self {
-            let mut answer = Self {
-                Media: arg
-            };
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// A tag named InvalidUtf8, which has no payload.
+    pub const InvalidUtf8: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<FileError>()];
 
-            answer.set_discriminant(discriminant_KeyCode::Media);
+        bytes[12] = discriminant_FileError::InvalidUtf8 as u8;
 
-            answer
-    }
+        core::mem::transmute::<[u8; core::mem::size_of::<FileError>()], FileError>(bytes)
+    };
 
     #[cfg(any(
         target_arch = "arm",
@@ -3446,14 +10414,10 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `KeyCode` has a `.discriminant()` of `Media` and convert it to `Media`'s payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Media`.
-            pub unsafe fn into_Media(self) -> MediaKeyCode {
-                debug_assert_eq!(self.discriminant(), discriminant_KeyCode::Media);
-        let payload = self.Media;
-
-        payload
+    /// Other `into_` methods return a payload, but since the InvalidUtf8 tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_InvalidUtf8(self) {
+        ()
     }
 
     #[cfg(any(
@@ -3463,14 +10427,10 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `KeyCode` has a `.discriminant()` of `Media` and return its payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Media`.
-            pub unsafe fn as_Media(&self) -> &MediaKeyCode {
-                debug_assert_eq!(self.discriminant(), discriminant_KeyCode::Media);
-        let payload = &self.Media;
-
-        &payload
+    /// Other `as` methods return a payload, but since the InvalidUtf8 tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_InvalidUtf8(&self) {
+        ()
     }
 
     #[cfg(any(
@@ -3478,13 +10438,13 @@ impl KeyCode {
         target_arch = "wasm32",
         target_arch = "x86"
     ))]
-    /// A tag named Menu, which has no payload.
-    pub const Menu: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// A tag named NotFound, which has no payload.
+    pub const NotFound: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<FileError>()];
 
-        bytes[12] = discriminant_KeyCode::Menu as u8;
+        bytes[12] = discriminant_FileError::NotFound as u8;
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+        core::mem::transmute::<[u8; core::mem::size_of::<FileError>()], FileError>(bytes)
     };
 
     #[cfg(any(
@@ -3494,9 +10454,9 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the Menu tag
+    /// Other `into_` methods return a payload, but since the NotFound tag
     /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_Menu(self) {
+    pub fn into_NotFound(self) {
         ()
     }
 
@@ -3507,9 +10467,9 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the Menu tag
+    /// Other `as` methods return a payload, but since the NotFound tag
     /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_Menu(&self) {
+    pub fn as_NotFound(&self) {
         ()
     }
 
@@ -3520,13 +10480,13 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Construct a tag named `Modifier`, with the appropriate payload
-    pub fn Modifier(arg: ModifierKeyCode) -> Self {
+    /// Construct a tag named `Other`, with the appropriate payload
+    pub fn Other(arg: roc_std::RocStr) -> Self {
             let mut answer = Self {
-                Modifier: arg
+                Other: core::mem::ManuallyDrop::new(arg)
             };
 
-            answer.set_discriminant(discriminant_KeyCode::Modifier);
+            answer.set_discriminant(discriminant_FileError::Other);
 
             answer
     }
@@ -3538,12 +10498,24 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `KeyCode` has a `.discriminant()` of `Modifier` and convert it to `Modifier`'s payload.
+    /// Unsafely assume the given `FileError` has a `.discriminant()` of `Other` and convert it to `Other`'s payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Modifier`.
-            pub unsafe fn into_Modifier(self) -> ModifierKeyCode {
-                debug_assert_eq!(self.discriminant(), discriminant_KeyCode::Modifier);
-        let payload = self.Modifier;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Other`.
+            pub unsafe fn into_Other(mut self) -> roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_FileError::Other);
+        let payload = {
+            let mut uninitialized = core::mem::MaybeUninit::uninit();
+            let swapped = unsafe {
+                core::mem::replace(
+                    &mut self.Other,
+                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
+                )
+            };
+
+            core::mem::forget(self);
+
+            core::mem::ManuallyDrop::into_inner(swapped)
+        };
 
         payload
     }
@@ -3555,12 +10527,12 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `KeyCode` has a `.discriminant()` of `Modifier` and return its payload.
+    /// Unsafely assume the given `FileError` has a `.discriminant()` of `Other` and return its payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Modifier`.
-            pub unsafe fn as_Modifier(&self) -> &ModifierKeyCode {
-                debug_assert_eq!(self.discriminant(), discriminant_KeyCode::Modifier);
-        let payload = &self.Modifier;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Other`.
+            pub unsafe fn as_Other(&self) -> &roc_std::RocStr {
+                debug_assert_eq!(self.discriminant(), discriminant_FileError::Other);
+        let payload = &self.Other;
 
         &payload
     }
@@ -3570,13 +10542,13 @@ impl KeyCode {
         target_arch = "wasm32",
         target_arch = "x86"
     ))]
-    /// A tag named Null, which has no payload.
-    pub const Null: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// A tag named PermissionDenied, which has no payload.
+    pub const PermissionDenied: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<FileError>()];
 
-        bytes[12] = discriminant_KeyCode::Null as u8;
+        bytes[12] = discriminant_FileError::PermissionDenied as u8;
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+        core::mem::transmute::<[u8; core::mem::size_of::<FileError>()], FileError>(bytes)
     };
 
     #[cfg(any(
@@ -3586,9 +10558,9 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the Null tag
+    /// Other `into_` methods return a payload, but since the PermissionDenied tag
     /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_Null(self) {
+    pub fn into_PermissionDenied(self) {
         ()
     }
 
@@ -3599,106 +10571,105 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the Null tag
+    /// Other `as` methods return a payload, but since the PermissionDenied tag
     /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_Null(&self) {
+    pub fn as_PermissionDenied(&self) {
         ()
     }
 
     #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "aarch64",
+        target_arch = "x86_64"
     ))]
-    /// A tag named NumLock, which has no payload.
-    pub const NumLock: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[12] = discriminant_KeyCode::NumLock as u8;
+    /// Returns which variant this tag union holds. Note that this never includes a payload!
+    pub fn discriminant(&self) -> discriminant_FileError {
+        unsafe {
+            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+            core::mem::transmute::<u8, discriminant_FileError>(*bytes.as_ptr().add(24))
+        }
+    }
 
     #[cfg(any(
-        target_arch = "arm",
         target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the NumLock tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_NumLock(self) {
-        ()
+    /// Internal helper
+    fn set_discriminant(&mut self, discriminant: discriminant_FileError) {
+        let discriminant_ptr: *mut discriminant_FileError = (self as *mut FileError).cast();
+
+        unsafe {
+            *(discriminant_ptr.add(24)) = discriminant;
+        }
     }
 
     #[cfg(any(
-        target_arch = "arm",
         target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the NumLock tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_NumLock(&self) {
-        ()
-    }
+    /// A tag named AlreadyExists, which has no payload.
+    pub const AlreadyExists: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<FileError>()];
+
+        bytes[24] = discriminant_FileError::AlreadyExists as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<FileError>()], FileError>(bytes)
+    };
 
     #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "aarch64",
+        target_arch = "x86_64"
     ))]
-    /// A tag named PageDown, which has no payload.
-    pub const PageDown: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// A tag named DiskFull, which has no payload.
+    pub const DiskFull: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<FileError>()];
 
-        bytes[12] = discriminant_KeyCode::PageDown as u8;
+        bytes[24] = discriminant_FileError::DiskFull as u8;
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+        core::mem::transmute::<[u8; core::mem::size_of::<FileError>()], FileError>(bytes)
     };
 
     #[cfg(any(
-        target_arch = "arm",
         target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the PageDown tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_PageDown(self) {
-        ()
-    }
+    /// A tag named InvalidUtf8, which has no payload.
+    pub const InvalidUtf8: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<FileError>()];
+
+        bytes[24] = discriminant_FileError::InvalidUtf8 as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<FileError>()], FileError>(bytes)
+    };
 
     #[cfg(any(
-        target_arch = "arm",
         target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the PageDown tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_PageDown(&self) {
-        ()
-    }
+    /// A tag named NotFound, which has no payload.
+    pub const NotFound: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<FileError>()];
+
+        bytes[24] = discriminant_FileError::NotFound as u8;
+
+        core::mem::transmute::<[u8; core::mem::size_of::<FileError>()], FileError>(bytes)
+    };
 
     #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "aarch64",
+        target_arch = "x86_64"
     ))]
-    /// A tag named PageUp, which has no payload.
-    pub const PageUp: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// A tag named PermissionDenied, which has no payload.
+    pub const PermissionDenied: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<FileError>()];
 
-        bytes[12] = discriminant_KeyCode::PageUp as u8;
+        bytes[24] = discriminant_FileError::PermissionDenied as u8;
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+        core::mem::transmute::<[u8; core::mem::size_of::<FileError>()], FileError>(bytes)
     };
+}
 
+impl Drop for FileError {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -3706,12 +10677,23 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the PageUp tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_PageUp(self) {
-        ()
+    fn drop(&mut self) {
+        // Drop the payloads
+                    match self.discriminant() {
+                discriminant_FileError::AlreadyExists => {}
+                discriminant_FileError::DiskFull => {}
+                discriminant_FileError::InvalidUtf8 => {}
+                discriminant_FileError::NotFound => {}
+                discriminant_FileError::Other => unsafe { core::mem::ManuallyDrop::drop(&mut self.Other) },
+                discriminant_FileError::PermissionDenied => {}
+            }
+
     }
+}
+
+impl Eq for FileError {}
 
+impl PartialEq for FileError {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -3719,26 +10701,52 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the PageUp tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_PageUp(&self) {
-        ()
+    fn eq(&self, other: &Self) -> bool {
+            if self.discriminant() != other.discriminant() {
+                return false;
+            }
+
+            unsafe {
+            match self.discriminant() {
+                discriminant_FileError::AlreadyExists => true,
+                discriminant_FileError::DiskFull => true,
+                discriminant_FileError::InvalidUtf8 => true,
+                discriminant_FileError::NotFound => true,
+                discriminant_FileError::Other => self.Other == other.Other,
+                discriminant_FileError::PermissionDenied => true,
+            }
+        }
     }
+}
 
+impl PartialOrd for FileError {
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// A tag named Pause, which has no payload.
-    pub const Pause: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[12] = discriminant_KeyCode::Pause as u8;
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match self.discriminant().partial_cmp(&other.discriminant()) {
+            Some(core::cmp::Ordering::Equal) => {}
+            not_eq => return not_eq,
+        }
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+        unsafe {
+            match self.discriminant() {
+                discriminant_FileError::AlreadyExists => Some(core::cmp::Ordering::Equal),
+                discriminant_FileError::DiskFull => Some(core::cmp::Ordering::Equal),
+                discriminant_FileError::InvalidUtf8 => Some(core::cmp::Ordering::Equal),
+                discriminant_FileError::NotFound => Some(core::cmp::Ordering::Equal),
+                discriminant_FileError::Other => self.Other.partial_cmp(&other.Other),
+                discriminant_FileError::PermissionDenied => Some(core::cmp::Ordering::Equal),
+            }
+        }
+    }
+}
 
+impl Ord for FileError {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -3746,12 +10754,26 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the Pause tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_Pause(self) {
-        ()
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            match self.discriminant().cmp(&other.discriminant()) {
+                core::cmp::Ordering::Equal => {}
+                not_eq => return not_eq,
+            }
+
+            unsafe {
+            match self.discriminant() {
+                discriminant_FileError::AlreadyExists => core::cmp::Ordering::Equal,
+                discriminant_FileError::DiskFull => core::cmp::Ordering::Equal,
+                discriminant_FileError::InvalidUtf8 => core::cmp::Ordering::Equal,
+                discriminant_FileError::NotFound => core::cmp::Ordering::Equal,
+                discriminant_FileError::Other => self.Other.cmp(&other.Other),
+                discriminant_FileError::PermissionDenied => core::cmp::Ordering::Equal,
+            }
+        }
     }
+}
 
+impl Clone for FileError {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -3759,26 +10781,43 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the Pause tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_Pause(&self) {
-        ()
-    }
+    fn clone(&self) -> Self {
+        let mut answer = unsafe {
+            match self.discriminant() {
+                discriminant_FileError::AlreadyExists => core::mem::transmute::<
+                    core::mem::MaybeUninit<FileError>,
+                    FileError,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_FileError::DiskFull => core::mem::transmute::<
+                    core::mem::MaybeUninit<FileError>,
+                    FileError,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_FileError::InvalidUtf8 => core::mem::transmute::<
+                    core::mem::MaybeUninit<FileError>,
+                    FileError,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_FileError::NotFound => core::mem::transmute::<
+                    core::mem::MaybeUninit<FileError>,
+                    FileError,
+                >(core::mem::MaybeUninit::uninit()),
+                discriminant_FileError::Other => Self {
+                    Other: self.Other.clone(),
+                },
+                discriminant_FileError::PermissionDenied => core::mem::transmute::<
+                    core::mem::MaybeUninit<FileError>,
+                    FileError,
+                >(core::mem::MaybeUninit::uninit()),
+            }
 
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// A tag named PrintScreen, which has no payload.
-    pub const PrintScreen: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+        };
 
-        bytes[12] = discriminant_KeyCode::PrintScreen as u8;
+        answer.set_discriminant(self.discriminant());
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+        answer
+    }
+}
 
+impl core::hash::Hash for FileError {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -3786,12 +10825,21 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the PrintScreen tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_PrintScreen(self) {
-        ()
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
+            discriminant_FileError::AlreadyExists => discriminant_FileError::AlreadyExists.hash(state),
+            discriminant_FileError::DiskFull => discriminant_FileError::DiskFull.hash(state),
+            discriminant_FileError::InvalidUtf8 => discriminant_FileError::InvalidUtf8.hash(state),
+            discriminant_FileError::NotFound => discriminant_FileError::NotFound.hash(state),
+            discriminant_FileError::Other => unsafe {
+                    discriminant_FileError::Other.hash(state);
+                    self.Other.hash(state);
+                },
+            discriminant_FileError::PermissionDenied => discriminant_FileError::PermissionDenied.hash(state),
+        }
     }
+}
 
+impl core::fmt::Debug for FileError {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -3799,26 +10847,25 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the PrintScreen tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_PrintScreen(&self) {
-        ()
-    }
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// A tag named Right, which has no payload.
-    pub const Right: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[12] = discriminant_KeyCode::Right as u8;
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("FileError::")?;
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+        unsafe {
+            match self.discriminant() {
+                discriminant_FileError::AlreadyExists => f.write_str("AlreadyExists"),
+                discriminant_FileError::DiskFull => f.write_str("DiskFull"),
+                discriminant_FileError::InvalidUtf8 => f.write_str("InvalidUtf8"),
+                discriminant_FileError::NotFound => f.write_str("NotFound"),
+                discriminant_FileError::Other => f.debug_tuple("Other")
+        .field(&*self.Other)
+        .finish(),
+                discriminant_FileError::PermissionDenied => f.write_str("PermissionDenied"),
+            }
+        }
+    }
+}
 
+impl ExitStatus {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -3826,10 +10873,13 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the Right tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_Right(self) {
-        ()
+    /// Returns which variant this tag union holds. Note that this never includes a payload!
+    pub fn discriminant(&self) -> discriminant_ExitStatus {
+        unsafe {
+            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
+
+            core::mem::transmute::<u8, discriminant_ExitStatus>(*bytes.as_ptr().add(4))
+        }
     }
 
     #[cfg(any(
@@ -3839,10 +10889,13 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the Right tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_Right(&self) {
-        ()
+    /// Internal helper
+    fn set_discriminant(&mut self, discriminant: discriminant_ExitStatus) {
+        let discriminant_ptr: *mut discriminant_ExitStatus = (self as *mut ExitStatus).cast();
+
+        unsafe {
+            *(discriminant_ptr.add(4)) = discriminant;
+        }
     }
 
     #[cfg(any(
@@ -3852,13 +10905,13 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Construct a tag named `Scalar`, with the appropriate payload
-    pub fn Scalar(arg: roc_std::RocStr) -> Self {
+    /// Construct a tag named `Code`, with the appropriate payload
+    pub fn Code(arg: i32) -> Self {
             let mut answer = Self {
-                Scalar: core::mem::ManuallyDrop::new(arg)
+                Code: arg
             };
 
-            answer.set_discriminant(discriminant_KeyCode::Scalar);
+            answer.set_discriminant(discriminant_ExitStatus::Code);
 
             answer
     }
@@ -3870,24 +10923,12 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `KeyCode` has a `.discriminant()` of `Scalar` and convert it to `Scalar`'s payload.
+    /// Unsafely assume the given `ExitStatus` has a `.discriminant()` of `Code` and convert it to `Code`'s payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Scalar`.
-            pub unsafe fn into_Scalar(mut self) -> roc_std::RocStr {
-                debug_assert_eq!(self.discriminant(), discriminant_KeyCode::Scalar);
-        let payload = {
-            let mut uninitialized = core::mem::MaybeUninit::uninit();
-            let swapped = unsafe {
-                core::mem::replace(
-                    &mut self.Scalar,
-                    core::mem::ManuallyDrop::new(uninitialized.assume_init()),
-                )
-            };
-
-            core::mem::forget(self);
-
-            core::mem::ManuallyDrop::into_inner(swapped)
-        };
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Code`.
+            pub unsafe fn into_Code(self) -> i32 {
+                debug_assert_eq!(self.discriminant(), discriminant_ExitStatus::Code);
+        let payload = self.Code;
 
         payload
     }
@@ -3899,29 +10940,33 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `KeyCode` has a `.discriminant()` of `Scalar` and return its payload.
+    /// Unsafely assume the given `ExitStatus` has a `.discriminant()` of `Code` and return its payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Scalar`.
-            pub unsafe fn as_Scalar(&self) -> &roc_std::RocStr {
-                debug_assert_eq!(self.discriminant(), discriminant_KeyCode::Scalar);
-        let payload = &self.Scalar;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Code`.
+            pub unsafe fn as_Code(&self) -> &i32 {
+                debug_assert_eq!(self.discriminant(), discriminant_ExitStatus::Code);
+        let payload = &self.Code;
 
         &payload
     }
 
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// A tag named ScrollLock, which has no payload.
-    pub const ScrollLock: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// Construct a tag named `Signal`, with the appropriate payload
+    pub fn Signal(arg: i32) -> Self {
+            let mut answer = Self {
+                Signal: arg
+            };
 
-        bytes[12] = discriminant_KeyCode::ScrollLock as u8;
+            answer.set_discriminant(discriminant_ExitStatus::Signal);
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+            answer
+    }
 
     #[cfg(any(
         target_arch = "arm",
@@ -3930,10 +10975,14 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the ScrollLock tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_ScrollLock(self) {
-        ()
+    /// Unsafely assume the given `ExitStatus` has a `.discriminant()` of `Signal` and convert it to `Signal`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Signal`.
+            pub unsafe fn into_Signal(self) -> i32 {
+                debug_assert_eq!(self.discriminant(), discriminant_ExitStatus::Signal);
+        let payload = self.Signal;
+
+        payload
     }
 
     #[cfg(any(
@@ -3943,26 +10992,42 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the ScrollLock tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_ScrollLock(&self) {
-        ()
+    /// Unsafely assume the given `ExitStatus` has a `.discriminant()` of `Signal` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Signal`.
+            pub unsafe fn as_Signal(&self) -> &i32 {
+                debug_assert_eq!(self.discriminant(), discriminant_ExitStatus::Signal);
+        let payload = &self.Signal;
+
+        &payload
     }
+}
+
+impl Eq for ExitStatus {}
 
+impl PartialEq for ExitStatus {
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// A tag named Tab, which has no payload.
-    pub const Tab: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[12] = discriminant_KeyCode::Tab as u8;
+    fn eq(&self, other: &Self) -> bool {
+            if self.discriminant() != other.discriminant() {
+                return false;
+            }
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+            unsafe {
+            match self.discriminant() {
+                discriminant_ExitStatus::Code => self.Code == other.Code,
+                discriminant_ExitStatus::Signal => self.Signal == other.Signal,
+            }
+        }
+    }
+}
 
+impl PartialOrd for ExitStatus {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -3970,12 +11035,22 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the Tab tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_Tab(self) {
-        ()
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match self.discriminant().partial_cmp(&other.discriminant()) {
+            Some(core::cmp::Ordering::Equal) => {}
+            not_eq => return not_eq,
+        }
+
+        unsafe {
+            match self.discriminant() {
+                discriminant_ExitStatus::Code => self.Code.partial_cmp(&other.Code),
+                discriminant_ExitStatus::Signal => self.Signal.partial_cmp(&other.Signal),
+            }
+        }
     }
+}
 
+impl Ord for ExitStatus {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -3983,26 +11058,51 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the Tab tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_Tab(&self) {
-        ()
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            match self.discriminant().cmp(&other.discriminant()) {
+                core::cmp::Ordering::Equal => {}
+                not_eq => return not_eq,
+            }
+
+            unsafe {
+            match self.discriminant() {
+                discriminant_ExitStatus::Code => self.Code.cmp(&other.Code),
+                discriminant_ExitStatus::Signal => self.Signal.cmp(&other.Signal),
+            }
+        }
     }
+}
+
+impl Copy for ExitStatus {}
 
+impl Clone for ExitStatus {
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// A tag named Up, which has no payload.
-    pub const Up: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    fn clone(&self) -> Self {
+        let mut answer = unsafe {
+            match self.discriminant() {
+                discriminant_ExitStatus::Code => Self {
+                    Code: self.Code.clone(),
+                },
+                discriminant_ExitStatus::Signal => Self {
+                    Signal: self.Signal.clone(),
+                },
+            }
 
-        bytes[12] = discriminant_KeyCode::Up as u8;
+        };
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+        answer.set_discriminant(self.discriminant());
+
+        answer
+    }
+}
 
+impl core::hash::Hash for ExitStatus {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -4010,12 +11110,20 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the Up tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_Up(self) {
-        ()
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
+            discriminant_ExitStatus::Code => unsafe {
+                    discriminant_ExitStatus::Code.hash(state);
+                    self.Code.hash(state);
+                },
+            discriminant_ExitStatus::Signal => unsafe {
+                    discriminant_ExitStatus::Signal.hash(state);
+                    self.Signal.hash(state);
+                },
+        }
     }
+}
 
+impl core::fmt::Debug for ExitStatus {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -4023,384 +11131,493 @@ impl KeyCode {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the Up tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_Up(&self) {
-        ()
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("ExitStatus::")?;
+
+        unsafe {
+            match self.discriminant() {
+                discriminant_ExitStatus::Code => f.debug_tuple("Code")
+        .field(&self.Code)
+        .finish(),
+                discriminant_ExitStatus::Signal => f.debug_tuple("Signal")
+        .field(&self.Signal)
+        .finish(),
+            }
+        }
     }
+}
 
+impl Cursor {
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
     /// Returns which variant this tag union holds. Note that this never includes a payload!
-    pub fn discriminant(&self) -> discriminant_KeyCode {
+    pub fn discriminant(&self) -> discriminant_Cursor {
         unsafe {
             let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
 
-            core::mem::transmute::<u8, discriminant_KeyCode>(*bytes.as_ptr().add(24))
+            core::mem::transmute::<u8, discriminant_Cursor>(*bytes.as_ptr().add(4))
         }
     }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
     /// Internal helper
-    fn set_discriminant(&mut self, discriminant: discriminant_KeyCode) {
-        let discriminant_ptr: *mut discriminant_KeyCode = (self as *mut KeyCode).cast();
+    fn set_discriminant(&mut self, discriminant: discriminant_Cursor) {
+        let discriminant_ptr: *mut discriminant_Cursor = (self as *mut Cursor).cast();
 
         unsafe {
-            *(discriminant_ptr.add(24)) = discriminant;
+            *(discriminant_ptr.add(4)) = discriminant;
         }
     }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// A tag named BackTab, which has no payload.
-    pub const BackTab: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[24] = discriminant_KeyCode::BackTab as u8;
-
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// A tag named Backspace, which has no payload.
-    pub const Backspace: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// Construct a tag named `At`, with the appropriate payload
+    pub fn At(arg0: CursorPosition) -> Self {
+            let mut answer = Self {
+                At: arg0
+            };
 
-        bytes[24] = discriminant_KeyCode::Backspace as u8;
+            answer.set_discriminant(discriminant_Cursor::At);
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+            answer
+    }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// A tag named CapsLock, which has no payload.
-    pub const CapsLock: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[24] = discriminant_KeyCode::CapsLock as u8;
+    /// Unsafely assume the given `Cursor` has a `.discriminant()` of `At` and convert it to `At`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `At`.
+            pub unsafe fn into_At(self) -> CursorPosition {
+                debug_assert_eq!(self.discriminant(), discriminant_Cursor::At);
+        let payload = self.At;
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+        
+        payload
+    }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// A tag named Delete, which has no payload.
-    pub const Delete: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[24] = discriminant_KeyCode::Delete as u8;
+    /// Unsafely assume the given `Cursor` has a `.discriminant()` of `At` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `At`.
+            pub unsafe fn as_At(&self) -> &CursorPosition {
+                debug_assert_eq!(self.discriminant(), discriminant_Cursor::At);
+        let payload = &self.At;
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+        
+        payload
+    }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// A tag named Down, which has no payload.
-    pub const Down: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// A tag named Hidden, which has no payload.
+    pub const Hidden: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<Cursor>()];
 
-        bytes[24] = discriminant_KeyCode::Down as u8;
+        bytes[4] = discriminant_Cursor::Hidden as u8;
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+        core::mem::transmute::<[u8; core::mem::size_of::<Cursor>()], Cursor>(bytes)
     };
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// A tag named End, which has no payload.
-    pub const End: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[24] = discriminant_KeyCode::End as u8;
-
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+    /// Other `into_` methods return a payload, but since the Hidden tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_Hidden(self) {
+        ()
+    }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// A tag named Enter, which has no payload.
-    pub const Enter: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[24] = discriminant_KeyCode::Enter as u8;
+    /// Other `as` methods return a payload, but since the Hidden tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_Hidden(&self) {
+        ()
+    }
+}
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+impl Eq for Cursor {}
 
+impl PartialEq for Cursor {
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// A tag named Esc, which has no payload.
-    pub const Esc: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[24] = discriminant_KeyCode::Esc as u8;
+    fn eq(&self, other: &Self) -> bool {
+            if self.discriminant() != other.discriminant() {
+                return false;
+            }
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+            unsafe {
+            match self.discriminant() {
+                discriminant_Cursor::At => self.At == other.At,
+                discriminant_Cursor::Hidden => true,
+            }
+        }
+    }
+}
 
+impl PartialOrd for Cursor {
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// A tag named Home, which has no payload.
-    pub const Home: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[24] = discriminant_KeyCode::Home as u8;
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match self.discriminant().partial_cmp(&other.discriminant()) {
+            Some(core::cmp::Ordering::Equal) => {}
+            not_eq => return not_eq,
+        }
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+        unsafe {
+            match self.discriminant() {
+                discriminant_Cursor::At => self.At.partial_cmp(&other.At),
+                discriminant_Cursor::Hidden => Some(core::cmp::Ordering::Equal),
+            }
+        }
+    }
+}
 
+impl Ord for Cursor {
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// A tag named Insert, which has no payload.
-    pub const Insert: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            match self.discriminant().cmp(&other.discriminant()) {
+                core::cmp::Ordering::Equal => {}
+                not_eq => return not_eq,
+            }
 
-        bytes[24] = discriminant_KeyCode::Insert as u8;
+            unsafe {
+            match self.discriminant() {
+                discriminant_Cursor::At => self.At.cmp(&other.At),
+                discriminant_Cursor::Hidden => core::cmp::Ordering::Equal,
+            }
+        }
+    }
+}
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+impl Copy for Cursor {}
 
+impl Clone for Cursor {
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// A tag named KeypadBegin, which has no payload.
-    pub const KeypadBegin: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    fn clone(&self) -> Self {
+        let mut answer = unsafe {
+            match self.discriminant() {
+                discriminant_Cursor::At => Self {
+                    At: self.At.clone(),
+                },
+                discriminant_Cursor::Hidden => core::mem::transmute::<
+                    core::mem::MaybeUninit<Cursor>,
+                    Cursor,
+                >(core::mem::MaybeUninit::uninit()),
+            }
 
-        bytes[24] = discriminant_KeyCode::KeypadBegin as u8;
+        };
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+        answer.set_discriminant(self.discriminant());
+
+        answer
+    }
+}
 
+impl core::hash::Hash for Cursor {
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// A tag named Left, which has no payload.
-    pub const Left: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[24] = discriminant_KeyCode::Left as u8;
-
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
+            discriminant_Cursor::At => unsafe {
+                    discriminant_Cursor::At.hash(state);
+                    self.At.hash(state);
+                },
+            discriminant_Cursor::Hidden => discriminant_Cursor::Hidden.hash(state),
+        }
+    }
+}
 
+impl core::fmt::Debug for Cursor {
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// A tag named Menu, which has no payload.
-    pub const Menu: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[24] = discriminant_KeyCode::Menu as u8;
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Cursor::")?;
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+        unsafe {
+            match self.discriminant() {
+                discriminant_Cursor::At => f.debug_tuple("At")
+        .field(&self.At)
+        .finish(),
+                discriminant_Cursor::Hidden => f.write_str("Hidden"),
+            }
+        }
+    }
+}
 
+impl ListSelection {
     #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
     ))]
-    /// A tag named Null, which has no payload.
-    pub const Null: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// Returns which variant this tag union holds. Note that this never includes a payload!
+    pub fn discriminant(&self) -> discriminant_ListSelection {
+        unsafe {
+            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
 
-        bytes[24] = discriminant_KeyCode::Null as u8;
+            core::mem::transmute::<u8, discriminant_ListSelection>(*bytes.as_ptr().add(4))
+        }
+    }
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// Internal helper
+    fn set_discriminant(&mut self, discriminant: discriminant_ListSelection) {
+        let discriminant_ptr: *mut discriminant_ListSelection = (self as *mut ListSelection).cast();
+
+        unsafe {
+            *(discriminant_ptr.add(4)) = discriminant;
+        }
+    }
 
     #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
     ))]
-    /// A tag named NumLock, which has no payload.
-    pub const NumLock: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// A tag named None, which has no payload.
+    pub const None: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<ListSelection>()];
 
-        bytes[24] = discriminant_KeyCode::NumLock as u8;
+        bytes[4] = discriminant_ListSelection::None as u8;
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+        core::mem::transmute::<[u8; core::mem::size_of::<ListSelection>()], ListSelection>(bytes)
     };
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// A tag named PageDown, which has no payload.
-    pub const PageDown: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[24] = discriminant_KeyCode::PageDown as u8;
-
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+    /// Other `into_` methods return a payload, but since the None tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn into_None(self) {
+        ()
+    }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// A tag named PageUp, which has no payload.
-    pub const PageUp: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// Other `as` methods return a payload, but since the None tag
+    /// has no payload, this does nothing and is only here for completeness.
+    pub fn as_None(&self) {
+        ()
+    }
 
-        bytes[24] = discriminant_KeyCode::PageUp as u8;
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// Construct a tag named `Selected`, with the appropriate payload
+    pub fn Selected(arg: u32) -> Self {
+            let mut answer = Self {
+                Selected: arg
+            };
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+            answer.set_discriminant(discriminant_ListSelection::Selected);
+
+            answer
+    }
 
     #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
     ))]
-    /// A tag named Pause, which has no payload.
-    pub const Pause: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// Unsafely assume the given `ListSelection` has a `.discriminant()` of `Selected` and convert it to `Selected`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Selected`.
+            pub unsafe fn into_Selected(self) -> u32 {
+                debug_assert_eq!(self.discriminant(), discriminant_ListSelection::Selected);
+        let payload = self.Selected;
 
-        bytes[24] = discriminant_KeyCode::Pause as u8;
+        payload
+    }
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+    #[cfg(any(
+        target_arch = "arm",
+        target_arch = "wasm32",
+        target_arch = "x86"
+    ))]
+    /// Unsafely assume the given `ListSelection` has a `.discriminant()` of `Selected` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Selected`.
+            pub unsafe fn as_Selected(&self) -> &u32 {
+                debug_assert_eq!(self.discriminant(), discriminant_ListSelection::Selected);
+        let payload = &self.Selected;
+
+        &payload
+    }
 
     #[cfg(any(
         target_arch = "aarch64",
         target_arch = "x86_64"
     ))]
-    /// A tag named PrintScreen, which has no payload.
-    pub const PrintScreen: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[24] = discriminant_KeyCode::PrintScreen as u8;
+    /// Returns which variant this tag union holds. Note that this never includes a payload!
+    pub fn discriminant(&self) -> discriminant_ListSelection {
+        unsafe {
+            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+            core::mem::transmute::<u8, discriminant_ListSelection>(*bytes.as_ptr().add(8))
+        }
+    }
 
     #[cfg(any(
         target_arch = "aarch64",
         target_arch = "x86_64"
     ))]
-    /// A tag named Right, which has no payload.
-    pub const Right: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[24] = discriminant_KeyCode::Right as u8;
+    /// Internal helper
+    fn set_discriminant(&mut self, discriminant: discriminant_ListSelection) {
+        let discriminant_ptr: *mut discriminant_ListSelection = (self as *mut ListSelection).cast();
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+        unsafe {
+            *(discriminant_ptr.add(8)) = discriminant;
+        }
+    }
 
     #[cfg(any(
         target_arch = "aarch64",
         target_arch = "x86_64"
     ))]
-    /// A tag named ScrollLock, which has no payload.
-    pub const ScrollLock: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// A tag named None, which has no payload.
+    pub const None: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<ListSelection>()];
 
-        bytes[24] = discriminant_KeyCode::ScrollLock as u8;
+        bytes[8] = discriminant_ListSelection::None as u8;
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
+        core::mem::transmute::<[u8; core::mem::size_of::<ListSelection>()], ListSelection>(bytes)
     };
 
     #[cfg(any(
         target_arch = "aarch64",
         target_arch = "x86_64"
     ))]
-    /// A tag named Tab, which has no payload.
-    pub const Tab: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
+    /// Construct a tag named `Selected`, with the appropriate payload
+    pub fn Selected(arg: u64) -> Self {
+            let mut answer = Self {
+                Selected: arg
+            };
 
-        bytes[24] = discriminant_KeyCode::Tab as u8;
+            answer.set_discriminant(discriminant_ListSelection::Selected);
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
+            answer
+    }
 
     #[cfg(any(
         target_arch = "aarch64",
         target_arch = "x86_64"
     ))]
-    /// A tag named Up, which has no payload.
-    pub const Up: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<KeyCode>()];
-
-        bytes[24] = discriminant_KeyCode::Up as u8;
+    /// Unsafely assume the given `ListSelection` has a `.discriminant()` of `Selected` and convert it to `Selected`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Selected`.
+            pub unsafe fn into_Selected(self) -> u64 {
+                debug_assert_eq!(self.discriminant(), discriminant_ListSelection::Selected);
+        let payload = self.Selected;
 
-        core::mem::transmute::<[u8; core::mem::size_of::<KeyCode>()], KeyCode>(bytes)
-    };
-}
+        payload
+    }
 
-impl Drop for KeyCode {
     #[cfg(any(
-        target_arch = "arm",
         target_arch = "aarch64",
-        target_arch = "wasm32",
-        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    fn drop(&mut self) {
-        // Drop the payloads
-                    match self.discriminant() {
-                discriminant_KeyCode::BackTab => {}
-                discriminant_KeyCode::Backspace => {}
-                discriminant_KeyCode::CapsLock => {}
-                discriminant_KeyCode::Delete => {}
-                discriminant_KeyCode::Down => {}
-                discriminant_KeyCode::End => {}
-                discriminant_KeyCode::Enter => {}
-                discriminant_KeyCode::Esc => {}
-                discriminant_KeyCode::Function => {}
-                discriminant_KeyCode::Home => {}
-                discriminant_KeyCode::Insert => {}
-                discriminant_KeyCode::KeypadBegin => {}
-                discriminant_KeyCode::Left => {}
-                discriminant_KeyCode::Media => {}
-                discriminant_KeyCode::Menu => {}
-                discriminant_KeyCode::Modifier => {}
-                discriminant_KeyCode::Null => {}
-                discriminant_KeyCode::NumLock => {}
-                discriminant_KeyCode::PageDown => {}
-                discriminant_KeyCode::PageUp => {}
-                discriminant_KeyCode::Pause => {}
-                discriminant_KeyCode::PrintScreen => {}
-                discriminant_KeyCode::Right => {}
-                discriminant_KeyCode::Scalar => unsafe { core::mem::ManuallyDrop::drop(&mut self.Scalar) },
-                discriminant_KeyCode::ScrollLock => {}
-                discriminant_KeyCode::Tab => {}
-                discriminant_KeyCode::Up => {}
-            }
+    /// Unsafely assume the given `ListSelection` has a `.discriminant()` of `Selected` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Selected`.
+            pub unsafe fn as_Selected(&self) -> &u64 {
+                debug_assert_eq!(self.discriminant(), discriminant_ListSelection::Selected);
+        let payload = &self.Selected;
 
+        &payload
     }
 }
 
-impl Eq for KeyCode {}
+impl Eq for ListSelection {}
 
-impl PartialEq for KeyCode {
+impl PartialEq for ListSelection {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -4415,39 +11632,14 @@ impl PartialEq for KeyCode {
 
             unsafe {
             match self.discriminant() {
-                discriminant_KeyCode::BackTab => true,
-                discriminant_KeyCode::Backspace => true,
-                discriminant_KeyCode::CapsLock => true,
-                discriminant_KeyCode::Delete => true,
-                discriminant_KeyCode::Down => true,
-                discriminant_KeyCode::End => true,
-                discriminant_KeyCode::Enter => true,
-                discriminant_KeyCode::Esc => true,
-                discriminant_KeyCode::Function => self.Function == other.Function,
-                discriminant_KeyCode::Home => true,
-                discriminant_KeyCode::Insert => true,
-                discriminant_KeyCode::KeypadBegin => true,
-                discriminant_KeyCode::Left => true,
-                discriminant_KeyCode::Media => self.Media == other.Media,
-                discriminant_KeyCode::Menu => true,
-                discriminant_KeyCode::Modifier => self.Modifier == other.Modifier,
-                discriminant_KeyCode::Null => true,
-                discriminant_KeyCode::NumLock => true,
-                discriminant_KeyCode::PageDown => true,
-                discriminant_KeyCode::PageUp => true,
-                discriminant_KeyCode::Pause => true,
-                discriminant_KeyCode::PrintScreen => true,
-                discriminant_KeyCode::Right => true,
-                discriminant_KeyCode::Scalar => self.Scalar == other.Scalar,
-                discriminant_KeyCode::ScrollLock => true,
-                discriminant_KeyCode::Tab => true,
-                discriminant_KeyCode::Up => true,
+                discriminant_ListSelection::None => true,
+                discriminant_ListSelection::Selected => self.Selected == other.Selected,
             }
         }
     }
 }
 
-impl PartialOrd for KeyCode {
+impl PartialOrd for ListSelection {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -4460,42 +11652,17 @@ impl PartialOrd for KeyCode {
             Some(core::cmp::Ordering::Equal) => {}
             not_eq => return not_eq,
         }
-
-        unsafe {
-            match self.discriminant() {
-                discriminant_KeyCode::BackTab => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::Backspace => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::CapsLock => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::Delete => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::Down => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::End => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::Enter => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::Esc => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::Function => self.Function.partial_cmp(&other.Function),
-                discriminant_KeyCode::Home => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::Insert => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::KeypadBegin => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::Left => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::Media => self.Media.partial_cmp(&other.Media),
-                discriminant_KeyCode::Menu => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::Modifier => self.Modifier.partial_cmp(&other.Modifier),
-                discriminant_KeyCode::Null => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::NumLock => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::PageDown => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::PageUp => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::Pause => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::PrintScreen => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::Right => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::Scalar => self.Scalar.partial_cmp(&other.Scalar),
-                discriminant_KeyCode::ScrollLock => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::Tab => Some(core::cmp::Ordering::Equal),
-                discriminant_KeyCode::Up => Some(core::cmp::Ordering::Equal),
+
+        unsafe {
+            match self.discriminant() {
+                discriminant_ListSelection::None => Some(core::cmp::Ordering::Equal),
+                discriminant_ListSelection::Selected => self.Selected.partial_cmp(&other.Selected),
             }
         }
     }
 }
 
-impl Ord for KeyCode {
+impl Ord for ListSelection {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -4511,39 +11678,16 @@ impl Ord for KeyCode {
 
             unsafe {
             match self.discriminant() {
-                discriminant_KeyCode::BackTab => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::Backspace => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::CapsLock => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::Delete => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::Down => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::End => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::Enter => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::Esc => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::Function => self.Function.cmp(&other.Function),
-                discriminant_KeyCode::Home => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::Insert => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::KeypadBegin => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::Left => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::Media => self.Media.cmp(&other.Media),
-                discriminant_KeyCode::Menu => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::Modifier => self.Modifier.cmp(&other.Modifier),
-                discriminant_KeyCode::Null => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::NumLock => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::PageDown => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::PageUp => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::Pause => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::PrintScreen => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::Right => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::Scalar => self.Scalar.cmp(&other.Scalar),
-                discriminant_KeyCode::ScrollLock => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::Tab => core::cmp::Ordering::Equal,
-                discriminant_KeyCode::Up => core::cmp::Ordering::Equal,
+                discriminant_ListSelection::None => core::cmp::Ordering::Equal,
+                discriminant_ListSelection::Selected => self.Selected.cmp(&other.Selected),
             }
         }
     }
 }
 
-impl Clone for KeyCode {
+impl Copy for ListSelection {}
+
+impl Clone for ListSelection {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -4554,110 +11698,13 @@ impl Clone for KeyCode {
     fn clone(&self) -> Self {
         let mut answer = unsafe {
             match self.discriminant() {
-                discriminant_KeyCode::BackTab => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::Backspace => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::CapsLock => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::Delete => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::Down => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::End => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::Enter => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::Esc => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::Function => Self {
-                    Function: self.Function.clone(),
-                },
-                discriminant_KeyCode::Home => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::Insert => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::KeypadBegin => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::Left => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::Media => Self {
-                    Media: self.Media.clone(),
-                },
-                discriminant_KeyCode::Menu => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::Modifier => Self {
-                    Modifier: self.Modifier.clone(),
-                },
-                discriminant_KeyCode::Null => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::NumLock => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::PageDown => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::PageUp => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::Pause => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::PrintScreen => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::Right => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
+                discriminant_ListSelection::None => core::mem::transmute::<
+                    core::mem::MaybeUninit<ListSelection>,
+                    ListSelection,
                 >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::Scalar => Self {
-                    Scalar: self.Scalar.clone(),
+                discriminant_ListSelection::Selected => Self {
+                    Selected: self.Selected.clone(),
                 },
-                discriminant_KeyCode::ScrollLock => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::Tab => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
-                discriminant_KeyCode::Up => core::mem::transmute::<
-                    core::mem::MaybeUninit<KeyCode>,
-                    KeyCode,
-                >(core::mem::MaybeUninit::uninit()),
             }
 
         };
@@ -4668,7 +11715,7 @@ impl Clone for KeyCode {
     }
 }
 
-impl core::hash::Hash for KeyCode {
+impl core::hash::Hash for ListSelection {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -4677,50 +11724,16 @@ impl core::hash::Hash for KeyCode {
         target_arch = "x86_64"
     ))]
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
-            discriminant_KeyCode::BackTab => discriminant_KeyCode::BackTab.hash(state),
-            discriminant_KeyCode::Backspace => discriminant_KeyCode::Backspace.hash(state),
-            discriminant_KeyCode::CapsLock => discriminant_KeyCode::CapsLock.hash(state),
-            discriminant_KeyCode::Delete => discriminant_KeyCode::Delete.hash(state),
-            discriminant_KeyCode::Down => discriminant_KeyCode::Down.hash(state),
-            discriminant_KeyCode::End => discriminant_KeyCode::End.hash(state),
-            discriminant_KeyCode::Enter => discriminant_KeyCode::Enter.hash(state),
-            discriminant_KeyCode::Esc => discriminant_KeyCode::Esc.hash(state),
-            discriminant_KeyCode::Function => unsafe {
-                    discriminant_KeyCode::Function.hash(state);
-                    self.Function.hash(state);
-                },
-            discriminant_KeyCode::Home => discriminant_KeyCode::Home.hash(state),
-            discriminant_KeyCode::Insert => discriminant_KeyCode::Insert.hash(state),
-            discriminant_KeyCode::KeypadBegin => discriminant_KeyCode::KeypadBegin.hash(state),
-            discriminant_KeyCode::Left => discriminant_KeyCode::Left.hash(state),
-            discriminant_KeyCode::Media => unsafe {
-                    discriminant_KeyCode::Media.hash(state);
-                    self.Media.hash(state);
-                },
-            discriminant_KeyCode::Menu => discriminant_KeyCode::Menu.hash(state),
-            discriminant_KeyCode::Modifier => unsafe {
-                    discriminant_KeyCode::Modifier.hash(state);
-                    self.Modifier.hash(state);
-                },
-            discriminant_KeyCode::Null => discriminant_KeyCode::Null.hash(state),
-            discriminant_KeyCode::NumLock => discriminant_KeyCode::NumLock.hash(state),
-            discriminant_KeyCode::PageDown => discriminant_KeyCode::PageDown.hash(state),
-            discriminant_KeyCode::PageUp => discriminant_KeyCode::PageUp.hash(state),
-            discriminant_KeyCode::Pause => discriminant_KeyCode::Pause.hash(state),
-            discriminant_KeyCode::PrintScreen => discriminant_KeyCode::PrintScreen.hash(state),
-            discriminant_KeyCode::Right => discriminant_KeyCode::Right.hash(state),
-            discriminant_KeyCode::Scalar => unsafe {
-                    discriminant_KeyCode::Scalar.hash(state);
-                    self.Scalar.hash(state);
+            discriminant_ListSelection::None => discriminant_ListSelection::None.hash(state),
+            discriminant_ListSelection::Selected => unsafe {
+                    discriminant_ListSelection::Selected.hash(state);
+                    self.Selected.hash(state);
                 },
-            discriminant_KeyCode::ScrollLock => discriminant_KeyCode::ScrollLock.hash(state),
-            discriminant_KeyCode::Tab => discriminant_KeyCode::Tab.hash(state),
-            discriminant_KeyCode::Up => discriminant_KeyCode::Up.hash(state),
         }
     }
 }
 
-impl core::fmt::Debug for KeyCode {
+impl core::fmt::Debug for ListSelection {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -4729,51 +11742,20 @@ impl core::fmt::Debug for KeyCode {
         target_arch = "x86_64"
     ))]
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str("KeyCode::")?;
+        f.write_str("ListSelection::")?;
 
         unsafe {
             match self.discriminant() {
-                discriminant_KeyCode::BackTab => f.write_str("BackTab"),
-                discriminant_KeyCode::Backspace => f.write_str("Backspace"),
-                discriminant_KeyCode::CapsLock => f.write_str("CapsLock"),
-                discriminant_KeyCode::Delete => f.write_str("Delete"),
-                discriminant_KeyCode::Down => f.write_str("Down"),
-                discriminant_KeyCode::End => f.write_str("End"),
-                discriminant_KeyCode::Enter => f.write_str("Enter"),
-                discriminant_KeyCode::Esc => f.write_str("Esc"),
-                discriminant_KeyCode::Function => f.debug_tuple("Function")
-        .field(&self.Function)
-        .finish(),
-                discriminant_KeyCode::Home => f.write_str("Home"),
-                discriminant_KeyCode::Insert => f.write_str("Insert"),
-                discriminant_KeyCode::KeypadBegin => f.write_str("KeypadBegin"),
-                discriminant_KeyCode::Left => f.write_str("Left"),
-                discriminant_KeyCode::Media => f.debug_tuple("Media")
-        .field(&self.Media)
-        .finish(),
-                discriminant_KeyCode::Menu => f.write_str("Menu"),
-                discriminant_KeyCode::Modifier => f.debug_tuple("Modifier")
-        .field(&self.Modifier)
-        .finish(),
-                discriminant_KeyCode::Null => f.write_str("Null"),
-                discriminant_KeyCode::NumLock => f.write_str("NumLock"),
-                discriminant_KeyCode::PageDown => f.write_str("PageDown"),
-                discriminant_KeyCode::PageUp => f.write_str("PageUp"),
-                discriminant_KeyCode::Pause => f.write_str("Pause"),
-                discriminant_KeyCode::PrintScreen => f.write_str("PrintScreen"),
-                discriminant_KeyCode::Right => f.write_str("Right"),
-                discriminant_KeyCode::Scalar => f.debug_tuple("Scalar")
-        .field(&*self.Scalar)
+                discriminant_ListSelection::None => f.write_str("None"),
+                discriminant_ListSelection::Selected => f.debug_tuple("Selected")
+        .field(&self.Selected)
         .finish(),
-                discriminant_KeyCode::ScrollLock => f.write_str("ScrollLock"),
-                discriminant_KeyCode::Tab => f.write_str("Tab"),
-                discriminant_KeyCode::Up => f.write_str("Up"),
             }
         }
     }
 }
 
-impl Cursor {
+impl Progress {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -4782,11 +11764,11 @@ impl Cursor {
         target_arch = "x86_64"
     ))]
     /// Returns which variant this tag union holds. Note that this never includes a payload!
-    pub fn discriminant(&self) -> discriminant_Cursor {
+    pub fn discriminant(&self) -> discriminant_Progress {
         unsafe {
             let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
 
-            core::mem::transmute::<u8, discriminant_Cursor>(*bytes.as_ptr().add(4))
+            core::mem::transmute::<u8, discriminant_Progress>(*bytes.as_ptr().add(16))
         }
     }
 
@@ -4798,11 +11780,11 @@ impl Cursor {
         target_arch = "x86_64"
     ))]
     /// Internal helper
-    fn set_discriminant(&mut self, discriminant: discriminant_Cursor) {
-        let discriminant_ptr: *mut discriminant_Cursor = (self as *mut Cursor).cast();
+    fn set_discriminant(&mut self, discriminant: discriminant_Progress) {
+        let discriminant_ptr: *mut discriminant_Progress = (self as *mut Progress).cast();
 
         unsafe {
-            *(discriminant_ptr.add(4)) = discriminant;
+            *(discriminant_ptr.add(16)) = discriminant;
         }
     }
 
@@ -4813,13 +11795,13 @@ impl Cursor {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Construct a tag named `At`, with the appropriate payload
-    pub fn At(arg0: CursorPosition) -> Self {
+    /// Construct a tag named `Percentage`, with the appropriate payload
+    pub fn Percentage(arg: u16) -> Self {
             let mut answer = Self {
-                At: arg0
+                Percentage: arg
             };
 
-            answer.set_discriminant(discriminant_Cursor::At);
+            answer.set_discriminant(discriminant_Progress::Percentage);
 
             answer
     }
@@ -4831,14 +11813,13 @@ impl Cursor {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Cursor` has a `.discriminant()` of `At` and convert it to `At`'s payload.
+    /// Unsafely assume the given `Progress` has a `.discriminant()` of `Percentage` and convert it to `Percentage`'s payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `At`.
-            pub unsafe fn into_At(self) -> CursorPosition {
-                debug_assert_eq!(self.discriminant(), discriminant_Cursor::At);
-        let payload = self.At;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Percentage`.
+            pub unsafe fn into_Percentage(self) -> u16 {
+                debug_assert_eq!(self.discriminant(), discriminant_Progress::Percentage);
+        let payload = self.Percentage;
 
-        
         payload
     }
 
@@ -4849,15 +11830,14 @@ impl Cursor {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `Cursor` has a `.discriminant()` of `At` and return its payload.
+    /// Unsafely assume the given `Progress` has a `.discriminant()` of `Percentage` and return its payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `At`.
-            pub unsafe fn as_At(&self) -> &CursorPosition {
-                debug_assert_eq!(self.discriminant(), discriminant_Cursor::At);
-        let payload = &self.At;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Percentage`.
+            pub unsafe fn as_Percentage(&self) -> &u16 {
+                debug_assert_eq!(self.discriminant(), discriminant_Progress::Percentage);
+        let payload = &self.Percentage;
 
-        
-        payload
+        &payload
     }
 
     #[cfg(any(
@@ -4867,14 +11847,19 @@ impl Cursor {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// A tag named Hidden, which has no payload.
-    pub const Hidden: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<Cursor>()];
+    /// Construct a tag named `Ratio`, with the appropriate payload
+    pub fn Ratio(arg0: u64, arg1: u64) -> Self {
+            let mut answer = Self {
+                Ratio: Progress_Ratio {
+                    f0: arg0,
+                    f1: arg1,
+                }
+            };
 
-        bytes[4] = discriminant_Cursor::Hidden as u8;
+            answer.set_discriminant(discriminant_Progress::Ratio);
 
-        core::mem::transmute::<[u8; core::mem::size_of::<Cursor>()], Cursor>(bytes)
-    };
+            answer
+    }
 
     #[cfg(any(
         target_arch = "arm",
@@ -4883,10 +11868,17 @@ impl Cursor {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the Hidden tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_Hidden(self) {
-        ()
+    /// Unsafely assume the given `Progress` has a `.discriminant()` of `Ratio` and convert it to `Ratio`'s payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Ratio`.
+            pub unsafe fn into_Ratio(self) -> (u64, u64) {
+                debug_assert_eq!(self.discriminant(), discriminant_Progress::Ratio);
+        let payload = self.Ratio;
+
+        (
+            payload.f0, 
+            payload.f1
+        )
     }
 
     #[cfg(any(
@@ -4896,16 +11888,23 @@ impl Cursor {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the Hidden tag
-    /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_Hidden(&self) {
-        ()
+    /// Unsafely assume the given `Progress` has a `.discriminant()` of `Ratio` and return its payload.
+            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Ratio`.
+            pub unsafe fn as_Ratio(&self) -> (&u64, &u64) {
+                debug_assert_eq!(self.discriminant(), discriminant_Progress::Ratio);
+        let payload = &self.Ratio;
+
+        (
+            &payload.f0, 
+            &payload.f1
+        )
     }
 }
 
-impl Eq for Cursor {}
+impl Eq for Progress {}
 
-impl PartialEq for Cursor {
+impl PartialEq for Progress {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -4920,14 +11919,14 @@ impl PartialEq for Cursor {
 
             unsafe {
             match self.discriminant() {
-                discriminant_Cursor::At => self.At == other.At,
-                discriminant_Cursor::Hidden => true,
+                discriminant_Progress::Percentage => self.Percentage == other.Percentage,
+                discriminant_Progress::Ratio => self.Ratio == other.Ratio,
             }
         }
     }
 }
 
-impl PartialOrd for Cursor {
+impl PartialOrd for Progress {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -4943,14 +11942,14 @@ impl PartialOrd for Cursor {
 
         unsafe {
             match self.discriminant() {
-                discriminant_Cursor::At => self.At.partial_cmp(&other.At),
-                discriminant_Cursor::Hidden => Some(core::cmp::Ordering::Equal),
+                discriminant_Progress::Percentage => self.Percentage.partial_cmp(&other.Percentage),
+                discriminant_Progress::Ratio => self.Ratio.partial_cmp(&other.Ratio),
             }
         }
     }
 }
 
-impl Ord for Cursor {
+impl Ord for Progress {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -4966,16 +11965,16 @@ impl Ord for Cursor {
 
             unsafe {
             match self.discriminant() {
-                discriminant_Cursor::At => self.At.cmp(&other.At),
-                discriminant_Cursor::Hidden => core::cmp::Ordering::Equal,
+                discriminant_Progress::Percentage => self.Percentage.cmp(&other.Percentage),
+                discriminant_Progress::Ratio => self.Ratio.cmp(&other.Ratio),
             }
         }
     }
 }
 
-impl Copy for Cursor {}
+impl Copy for Progress {}
 
-impl Clone for Cursor {
+impl Clone for Progress {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -4986,13 +11985,12 @@ impl Clone for Cursor {
     fn clone(&self) -> Self {
         let mut answer = unsafe {
             match self.discriminant() {
-                discriminant_Cursor::At => Self {
-                    At: self.At.clone(),
+                discriminant_Progress::Percentage => Self {
+                    Percentage: self.Percentage.clone(),
+                },
+                discriminant_Progress::Ratio => Self {
+                    Ratio: self.Ratio.clone(),
                 },
-                discriminant_Cursor::Hidden => core::mem::transmute::<
-                    core::mem::MaybeUninit<Cursor>,
-                    Cursor,
-                >(core::mem::MaybeUninit::uninit()),
             }
 
         };
@@ -5003,7 +12001,7 @@ impl Clone for Cursor {
     }
 }
 
-impl core::hash::Hash for Cursor {
+impl core::hash::Hash for Progress {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -5012,16 +12010,19 @@ impl core::hash::Hash for Cursor {
         target_arch = "x86_64"
     ))]
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
-            discriminant_Cursor::At => unsafe {
-                    discriminant_Cursor::At.hash(state);
-                    self.At.hash(state);
+            discriminant_Progress::Percentage => unsafe {
+                    discriminant_Progress::Percentage.hash(state);
+                    self.Percentage.hash(state);
+                },
+            discriminant_Progress::Ratio => unsafe {
+                    discriminant_Progress::Ratio.hash(state);
+                    self.Ratio.hash(state);
                 },
-            discriminant_Cursor::Hidden => discriminant_Cursor::Hidden.hash(state),
         }
     }
 }
 
-impl core::fmt::Debug for Cursor {
+impl core::fmt::Debug for Progress {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -5030,60 +12031,69 @@ impl core::fmt::Debug for Cursor {
         target_arch = "x86_64"
     ))]
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str("Cursor::")?;
+        f.write_str("Progress::")?;
 
         unsafe {
             match self.discriminant() {
-                discriminant_Cursor::At => f.debug_tuple("At")
-        .field(&self.At)
+                discriminant_Progress::Percentage => f.debug_tuple("Percentage")
+        .field(&self.Percentage)
+        .finish(),
+                discriminant_Progress::Ratio => f.debug_tuple("Ratio")
+        .field(&(&self.Ratio).f0)
+.field(&(&self.Ratio).f1)
         .finish(),
-                discriminant_Cursor::Hidden => f.write_str("Hidden"),
             }
         }
     }
 }
 
-impl ListSelection {
+impl SparklineMax {
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
     /// Returns which variant this tag union holds. Note that this never includes a payload!
-    pub fn discriminant(&self) -> discriminant_ListSelection {
+    pub fn discriminant(&self) -> discriminant_SparklineMax {
         unsafe {
             let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
 
-            core::mem::transmute::<u8, discriminant_ListSelection>(*bytes.as_ptr().add(4))
+            core::mem::transmute::<u8, discriminant_SparklineMax>(*bytes.as_ptr().add(8))
         }
     }
 
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
     /// Internal helper
-    fn set_discriminant(&mut self, discriminant: discriminant_ListSelection) {
-        let discriminant_ptr: *mut discriminant_ListSelection = (self as *mut ListSelection).cast();
+    fn set_discriminant(&mut self, discriminant: discriminant_SparklineMax) {
+        let discriminant_ptr: *mut discriminant_SparklineMax = (self as *mut SparklineMax).cast();
 
         unsafe {
-            *(discriminant_ptr.add(4)) = discriminant;
+            *(discriminant_ptr.add(8)) = discriminant;
         }
     }
 
     #[cfg(any(
         target_arch = "arm",
+        target_arch = "aarch64",
         target_arch = "wasm32",
-        target_arch = "x86"
+        target_arch = "x86",
+        target_arch = "x86_64"
     ))]
-    /// A tag named None, which has no payload.
-    pub const None: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<ListSelection>()];
+    /// A tag named Auto, which has no payload.
+    pub const Auto: Self = unsafe {
+        let mut bytes = [0; core::mem::size_of::<SparklineMax>()];
 
-        bytes[4] = discriminant_ListSelection::None as u8;
+        bytes[8] = discriminant_SparklineMax::Auto as u8;
 
-        core::mem::transmute::<[u8; core::mem::size_of::<ListSelection>()], ListSelection>(bytes)
+        core::mem::transmute::<[u8; core::mem::size_of::<SparklineMax>()], SparklineMax>(bytes)
     };
 
     #[cfg(any(
@@ -5093,9 +12103,9 @@ impl ListSelection {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `into_` methods return a payload, but since the None tag
+    /// Other `into_` methods return a payload, but since the Auto tag
     /// has no payload, this does nothing and is only here for completeness.
-    pub fn into_None(self) {
+    pub fn into_Auto(self) {
         ()
     }
 
@@ -5106,144 +12116,68 @@ impl ListSelection {
         target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Other `as` methods return a payload, but since the None tag
+    /// Other `as` methods return a payload, but since the Auto tag
     /// has no payload, this does nothing and is only here for completeness.
-    pub fn as_None(&self) {
+    pub fn as_Auto(&self) {
         ()
     }
 
     #[cfg(any(
         target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// Construct a tag named `Selected`, with the appropriate payload
-    pub fn Selected(arg: u32) -> Self {
-            let mut answer = Self {
-                Selected: arg
-            };
-
-            answer.set_discriminant(discriminant_ListSelection::Selected);
-
-            answer
-    }
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// Unsafely assume the given `ListSelection` has a `.discriminant()` of `Selected` and convert it to `Selected`'s payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Selected`.
-            pub unsafe fn into_Selected(self) -> u32 {
-                debug_assert_eq!(self.discriminant(), discriminant_ListSelection::Selected);
-        let payload = self.Selected;
-
-        payload
-    }
-
-    #[cfg(any(
-        target_arch = "arm",
-        target_arch = "wasm32",
-        target_arch = "x86"
-    ))]
-    /// Unsafely assume the given `ListSelection` has a `.discriminant()` of `Selected` and return its payload.
-            /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Selected`.
-            pub unsafe fn as_Selected(&self) -> &u32 {
-                debug_assert_eq!(self.discriminant(), discriminant_ListSelection::Selected);
-        let payload = &self.Selected;
-
-        &payload
-    }
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// Returns which variant this tag union holds. Note that this never includes a payload!
-    pub fn discriminant(&self) -> discriminant_ListSelection {
-        unsafe {
-            let bytes = core::mem::transmute::<&Self, &[u8; core::mem::size_of::<Self>()]>(self);
-
-            core::mem::transmute::<u8, discriminant_ListSelection>(*bytes.as_ptr().add(8))
-        }
-    }
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// Internal helper
-    fn set_discriminant(&mut self, discriminant: discriminant_ListSelection) {
-        let discriminant_ptr: *mut discriminant_ListSelection = (self as *mut ListSelection).cast();
-
-        unsafe {
-            *(discriminant_ptr.add(8)) = discriminant;
-        }
-    }
-
-    #[cfg(any(
-        target_arch = "aarch64",
-        target_arch = "x86_64"
-    ))]
-    /// A tag named None, which has no payload.
-    pub const None: Self = unsafe {
-        let mut bytes = [0; core::mem::size_of::<ListSelection>()];
-
-        bytes[8] = discriminant_ListSelection::None as u8;
-
-        core::mem::transmute::<[u8; core::mem::size_of::<ListSelection>()], ListSelection>(bytes)
-    };
-
-    #[cfg(any(
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Construct a tag named `Selected`, with the appropriate payload
-    pub fn Selected(arg: u64) -> Self {
+    /// Construct a tag named `Max`, with the appropriate payload
+    pub fn Max(arg: u64) -> Self {
             let mut answer = Self {
-                Selected: arg
+                Max: arg
             };
 
-            answer.set_discriminant(discriminant_ListSelection::Selected);
+            answer.set_discriminant(discriminant_SparklineMax::Max);
 
             answer
     }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `ListSelection` has a `.discriminant()` of `Selected` and convert it to `Selected`'s payload.
+    /// Unsafely assume the given `SparklineMax` has a `.discriminant()` of `Max` and convert it to `Max`'s payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Selected`.
-            pub unsafe fn into_Selected(self) -> u64 {
-                debug_assert_eq!(self.discriminant(), discriminant_ListSelection::Selected);
-        let payload = self.Selected;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Max`.
+            pub unsafe fn into_Max(self) -> u64 {
+                debug_assert_eq!(self.discriminant(), discriminant_SparklineMax::Max);
+        let payload = self.Max;
 
         payload
     }
 
     #[cfg(any(
+        target_arch = "arm",
         target_arch = "aarch64",
+        target_arch = "wasm32",
+        target_arch = "x86",
         target_arch = "x86_64"
     ))]
-    /// Unsafely assume the given `ListSelection` has a `.discriminant()` of `Selected` and return its payload.
+    /// Unsafely assume the given `SparklineMax` has a `.discriminant()` of `Max` and return its payload.
             /// (Always examine `.discriminant()` first to make sure this is the correct variant!)
-            /// Panics in debug builds if the `.discriminant()` doesn't return `Selected`.
-            pub unsafe fn as_Selected(&self) -> &u64 {
-                debug_assert_eq!(self.discriminant(), discriminant_ListSelection::Selected);
-        let payload = &self.Selected;
+            /// Panics in debug builds if the `.discriminant()` doesn't return `Max`.
+            pub unsafe fn as_Max(&self) -> &u64 {
+                debug_assert_eq!(self.discriminant(), discriminant_SparklineMax::Max);
+        let payload = &self.Max;
 
         &payload
     }
 }
 
-impl Eq for ListSelection {}
+impl Eq for SparklineMax {}
 
-impl PartialEq for ListSelection {
+impl PartialEq for SparklineMax {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -5258,14 +12192,14 @@ impl PartialEq for ListSelection {
 
             unsafe {
             match self.discriminant() {
-                discriminant_ListSelection::None => true,
-                discriminant_ListSelection::Selected => self.Selected == other.Selected,
+                discriminant_SparklineMax::Auto => true,
+                discriminant_SparklineMax::Max => self.Max == other.Max,
             }
         }
     }
 }
 
-impl PartialOrd for ListSelection {
+impl PartialOrd for SparklineMax {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -5281,14 +12215,14 @@ impl PartialOrd for ListSelection {
 
         unsafe {
             match self.discriminant() {
-                discriminant_ListSelection::None => Some(core::cmp::Ordering::Equal),
-                discriminant_ListSelection::Selected => self.Selected.partial_cmp(&other.Selected),
+                discriminant_SparklineMax::Auto => Some(core::cmp::Ordering::Equal),
+                discriminant_SparklineMax::Max => self.Max.partial_cmp(&other.Max),
             }
         }
     }
 }
 
-impl Ord for ListSelection {
+impl Ord for SparklineMax {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -5304,16 +12238,16 @@ impl Ord for ListSelection {
 
             unsafe {
             match self.discriminant() {
-                discriminant_ListSelection::None => core::cmp::Ordering::Equal,
-                discriminant_ListSelection::Selected => self.Selected.cmp(&other.Selected),
+                discriminant_SparklineMax::Auto => core::cmp::Ordering::Equal,
+                discriminant_SparklineMax::Max => self.Max.cmp(&other.Max),
             }
         }
     }
 }
 
-impl Copy for ListSelection {}
+impl Copy for SparklineMax {}
 
-impl Clone for ListSelection {
+impl Clone for SparklineMax {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -5324,12 +12258,12 @@ impl Clone for ListSelection {
     fn clone(&self) -> Self {
         let mut answer = unsafe {
             match self.discriminant() {
-                discriminant_ListSelection::None => core::mem::transmute::<
-                    core::mem::MaybeUninit<ListSelection>,
-                    ListSelection,
+                discriminant_SparklineMax::Auto => core::mem::transmute::<
+                    core::mem::MaybeUninit<SparklineMax>,
+                    SparklineMax,
                 >(core::mem::MaybeUninit::uninit()),
-                discriminant_ListSelection::Selected => Self {
-                    Selected: self.Selected.clone(),
+                discriminant_SparklineMax::Max => Self {
+                    Max: self.Max.clone(),
                 },
             }
 
@@ -5341,7 +12275,7 @@ impl Clone for ListSelection {
     }
 }
 
-impl core::hash::Hash for ListSelection {
+impl core::hash::Hash for SparklineMax {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -5350,16 +12284,16 @@ impl core::hash::Hash for ListSelection {
         target_arch = "x86_64"
     ))]
     fn hash<H: core::hash::Hasher>(&self, state: &mut H) {        match self.discriminant() {
-            discriminant_ListSelection::None => discriminant_ListSelection::None.hash(state),
-            discriminant_ListSelection::Selected => unsafe {
-                    discriminant_ListSelection::Selected.hash(state);
-                    self.Selected.hash(state);
+            discriminant_SparklineMax::Auto => discriminant_SparklineMax::Auto.hash(state),
+            discriminant_SparklineMax::Max => unsafe {
+                    discriminant_SparklineMax::Max.hash(state);
+                    self.Max.hash(state);
                 },
         }
     }
 }
 
-impl core::fmt::Debug for ListSelection {
+impl core::fmt::Debug for SparklineMax {
     #[cfg(any(
         target_arch = "arm",
         target_arch = "aarch64",
@@ -5368,13 +12302,13 @@ impl core::fmt::Debug for ListSelection {
         target_arch = "x86_64"
     ))]
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.write_str("ListSelection::")?;
+        f.write_str("SparklineMax::")?;
 
         unsafe {
             match self.discriminant() {
-                discriminant_ListSelection::None => f.write_str("None"),
-                discriminant_ListSelection::Selected => f.debug_tuple("Selected")
-        .field(&self.Selected)
+                discriminant_SparklineMax::Auto => f.write_str("Auto"),
+                discriminant_SparklineMax::Max => f.debug_tuple("Max")
+        .field(&self.Max)
         .finish(),
             }
         }
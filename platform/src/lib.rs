@@ -1,11 +1,16 @@
+mod capabilities;
+mod clipboard;
+mod color;
+mod editor;
+mod file;
 mod glue;
+mod process;
 mod roc;
+mod terminal;
 mod ui;
 
 #[no_mangle]
 pub extern "C" fn rust_main() -> i32 {
-    ui::run_event_loop();
-
     // Exit code
-    0
+    ui::run_event_loop()
 }
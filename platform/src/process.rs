@@ -0,0 +1,295 @@
+use crate::glue;
+use crate::ui::InputEvent;
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// A child which has closed its output but not yet exited is checked this often
+const REAP_POLL_MS: u64 = 20;
+
+/// Every running child by id. This lives for the whole process so the exit
+/// paths which never drop `Processes`, a signal or a Roc panic, can still kill
+/// the children with `kill_all`.
+static JOBS: OnceLock<Jobs> = OnceLock::new();
+
+type Jobs = Arc<Mutex<HashMap<String, Job>>>;
+
+struct Job {
+    child: std::process::Child,
+    /// Input is written on its own thread, dropping this closes the child's stdin
+    stdin: Option<Sender<String>>,
+}
+
+/// The subprocesses the app has spawned, by id. Each one's output is sent to
+/// the event loop a line at a time, and it is reaped when it exits. Any which
+/// are still running when this is dropped are killed.
+pub struct Processes {
+    jobs: Jobs,
+    tx: Sender<InputEvent>,
+}
+
+impl Processes {
+    pub fn new(tx: Sender<InputEvent>) -> Processes {
+        Processes {
+            jobs: Arc::clone(JOBS.get_or_init(Jobs::default)),
+            tx,
+        }
+    }
+
+    /// Start the command with its stdin, stdout and stderr connected to us. A
+    /// ProcessExited event is sent once it has exited and all of its output
+    /// has been sent, or ProcessFailed if it can't be started.
+    pub fn spawn(&self, id: String, mut command: std::process::Command) {
+        let mut jobs = self.jobs.lock().expect("TODO handle poisoned process list");
+        if jobs.contains_key(&id) {
+            self.failed(id, std::io::ErrorKind::AlreadyExists.into());
+            return;
+        }
+
+        command
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped());
+
+        // The child leads a process group of its own, so anything it starts
+        // can be killed along with it
+        #[cfg(unix)]
+        std::os::unix::process::CommandExt::process_group(&mut command, 0);
+        let mut child = match command.spawn() {
+            Ok(child) => child,
+            Err(error) => {
+                self.failed(id, error);
+                return;
+            }
+        };
+
+        let stdin = child.stdin.take().map(write_in_background);
+        let readers = [
+            child.stdout.take().map(|stdout| {
+                read_in_background(
+                    id.clone(),
+                    glue::OutputStream::Stdout,
+                    stdout,
+                    self.tx.clone(),
+                )
+            }),
+            child.stderr.take().map(|stderr| {
+                read_in_background(
+                    id.clone(),
+                    glue::OutputStream::Stderr,
+                    stderr,
+                    self.tx.clone(),
+                )
+            }),
+        ];
+        jobs.insert(id.clone(), Job { child, stdin });
+
+        let jobs = Arc::clone(&self.jobs);
+        let tx = self.tx.clone();
+        std::thread::spawn(move || {
+            // Wait for the output first so the exit is always the last event
+            for reader in readers.into_iter().flatten() {
+                let _ = reader.join();
+            }
+
+            // Only hold the lock briefly, so the child can still be killed
+            let status = loop {
+                let mut jobs = jobs.lock().expect("TODO handle poisoned process list");
+                match jobs.get_mut(&id).map(|job| job.child.try_wait()) {
+                    Some(Ok(None)) => {}
+                    Some(Ok(Some(status))) => {
+                        jobs.remove(&id);
+                        break Some(status);
+                    }
+                    Some(Err(_)) => {
+                        jobs.remove(&id);
+                        break None;
+                    }
+                    // Already reaped because the app is quitting
+                    None => break None,
+                }
+                drop(jobs);
+                std::thread::sleep(std::time::Duration::from_millis(REAP_POLL_MS));
+            };
+
+            if let Some(status) = status {
+                let _ = tx.send(InputEvent::ProcessExited { id, status });
+            }
+        });
+    }
+
+    /// Queue text to be written to the child's stdin, no newline is added
+    pub fn write_stdin(&self, id: String, input: String) {
+        let jobs = self.jobs.lock().expect("TODO handle poisoned process list");
+        match jobs.get(&id).and_then(|job| job.stdin.as_ref()) {
+            Some(stdin) => {
+                let _ = stdin.send(input);
+            }
+            None => self.failed(id, std::io::ErrorKind::NotFound.into()),
+        }
+    }
+
+    /// Close the child's stdin once any queued input is written, for programs
+    /// which read until the end of their input
+    pub fn close_stdin(&self, id: String) {
+        let mut jobs = self.jobs.lock().expect("TODO handle poisoned process list");
+        match jobs.get_mut(&id) {
+            Some(job) => job.stdin = None,
+            None => self.failed(id, std::io::ErrorKind::NotFound.into()),
+        }
+    }
+
+    /// Kill the child and anything it started, its ProcessExited event
+    /// follows as usual
+    pub fn kill(&self, id: String) {
+        let mut jobs = self.jobs.lock().expect("TODO handle poisoned process list");
+        let result = match jobs.get_mut(&id) {
+            Some(job) => kill_process_group(&mut job.child),
+            None => Err(std::io::ErrorKind::NotFound.into()),
+        };
+        if let Err(error) = result {
+            self.failed(id, error);
+        }
+    }
+
+    fn failed(&self, id: String, error: std::io::Error) {
+        let _ = self.tx.send(InputEvent::ProcessFailed { id, error });
+    }
+}
+
+impl Drop for Processes {
+    fn drop(&mut self) {
+        kill_all();
+    }
+}
+
+/// Kill and reap every child which is still running. This is called however
+/// the TUI exits, so children never outlive it.
+pub fn kill_all() {
+    let Some(jobs) = JOBS.get() else {
+        return;
+    };
+
+    // A thread which panicked holding the lock can't stop us cleaning up
+    let mut jobs = jobs.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    for (_, mut job) in jobs.drain() {
+        let _ = kill_process_group(&mut job.child);
+        let _ = job.child.wait();
+    }
+}
+
+/// Kill the child's whole process group. Its own children would otherwise
+/// outlive it, and keep its output open so the exit is never reported.
+#[cfg(unix)]
+fn kill_process_group(child: &mut std::process::Child) -> std::io::Result<()> {
+    // The group is still there until the child is reaped, which only happens
+    // once it has been taken out of the process list
+    if unsafe { libc::killpg(child.id() as libc::pid_t, libc::SIGKILL) } == 0 {
+        return Ok(());
+    }
+
+    let error = std::io::Error::last_os_error();
+    match error.raw_os_error() {
+        // Everything in the group has already exited
+        Some(libc::ESRCH) => Ok(()),
+        _ => Err(error),
+    }
+}
+
+#[cfg(not(unix))]
+fn kill_process_group(child: &mut std::process::Child) -> std::io::Result<()> {
+    child.kill()
+}
+
+pub fn get_exit_status(status: std::process::ExitStatus) -> glue::ExitStatus {
+    match status.code() {
+        Some(code) => glue::ExitStatus::Code(code),
+        None => glue::ExitStatus::Signal(get_signal(status)),
+    }
+}
+
+/// Only unix processes can be stopped by a signal, elsewhere there is always
+/// an exit code
+#[cfg(unix)]
+fn get_signal(status: std::process::ExitStatus) -> i32 {
+    std::os::unix::process::ExitStatusExt::signal(&status).unwrap_or_default()
+}
+
+#[cfg(not(unix))]
+fn get_signal(_status: std::process::ExitStatus) -> i32 {
+    0
+}
+
+/// Send each line of output as a ProcessOutput event without its line ending.
+/// Output which isn't UTF-8 is converted lossily rather than dropped.
+fn read_in_background<R>(
+    id: String,
+    stream: glue::OutputStream,
+    output: R,
+    tx: Sender<InputEvent>,
+) -> std::thread::JoinHandle<()>
+where
+    R: std::io::Read + Send + 'static,
+{
+    std::thread::spawn(move || {
+        let mut reader = std::io::BufReader::new(output);
+        let mut line = Vec::new();
+        loop {
+            line.clear();
+            match reader.read_until(b'\n', &mut line) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {}
+            }
+
+            let text = String::from_utf8_lossy(&line);
+            let event = InputEvent::ProcessOutput {
+                id: id.clone(),
+                stream,
+                line: text.trim_end_matches(['\n', '\r']).to_string(),
+            };
+            if tx.send(event).is_err() {
+                break;
+            }
+        }
+    })
+}
+
+/// A slow reader mustn't block the UI, so input is written on its own thread
+fn write_in_background(mut stdin: std::process::ChildStdin) -> Sender<String> {
+    let (tx, rx) = std::sync::mpsc::channel::<String>();
+    std::thread::spawn(move || {
+        for input in rx {
+            if stdin
+                .write_all(input.as_bytes())
+                .and_then(|_| stdin.flush())
+                .is_err()
+            {
+                break;
+            }
+        }
+    });
+    tx
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn killing_a_job_kills_what_it_started() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let processes = Processes::new(tx);
+
+        // The shell's sleep holds stdout open, so the exit is only reported
+        // once it has been killed too
+        let mut command = std::process::Command::new("sh");
+        command.args(["-c", "sleep 1000; true"]);
+        processes.spawn("job".to_string(), command);
+        processes.kill("job".to_string());
+
+        let timeout = std::time::Duration::from_secs(5);
+        let event = rx.recv_timeout(timeout).expect("the job should exit");
+        assert!(matches!(event, InputEvent::ProcessExited { .. }));
+    }
+}
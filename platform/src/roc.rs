@@ -1,9 +1,8 @@
-use crate::glue::{Bounds, Elem, Event};
+use crate::glue::{Command, Elem, Event, Startup};
 use core::alloc::Layout;
 use core::ffi::c_void;
 use roc_std::{RocList};
 use std::ffi::CStr;
-use std::mem::MaybeUninit;
 use std::os::raw::c_char;
 
 type Model = c_void;
@@ -20,7 +19,7 @@ extern "C" {
     // init
 
     #[link_name = "roc__programForHost_1__Init_caller"]
-    fn call_init(size: *const Bounds, closure_data: *const u8, output: *mut Model);
+    fn call_init(startup: *const Startup, closure_data: *const u8, output: *mut Model);
 
     #[link_name = "roc__programForHost_1__Init_size"]
     fn init_size() -> i64;
@@ -47,25 +46,49 @@ extern "C" {
     // render
 
     #[link_name = "roc__programForHost_1__Render_caller"]
-    fn call_render(model: *const Model, closure_data: *const u8, output: *mut RocList<Elem>);
+    fn call_render(model: *const Model, closure_data: *const u8, output: *mut u8);
 
     #[link_name = "roc__programForHost_1__Render_size"]
     fn roc_render_size() -> i64;
+
+    #[link_name = "roc__programForHost_1__Render_result_size"]
+    fn render_result_size() -> i64;
 }
 
+/// The largest alignment of any Roc value, this is used for buffers holding
+/// the Model as the host doesn't know its layout
+const MAX_ALIGNMENT: usize = 16;
+
+/// malloc only promises an alignment which is suitable for any built-in type
+const MALLOC_ALIGNMENT: u32 = 2 * std::mem::size_of::<usize>() as u32;
+
 #[no_mangle]
-pub unsafe extern "C" fn roc_alloc(size: usize, _alignment: u32) -> *mut c_void {
-    return libc::malloc(size);
+pub unsafe extern "C" fn roc_alloc(size: usize, alignment: u32) -> *mut c_void {
+    if alignment <= MALLOC_ALIGNMENT {
+        libc::malloc(size)
+    } else {
+        aligned_malloc(size, alignment as usize)
+    }
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn roc_realloc(
     c_ptr: *mut c_void,
     new_size: usize,
-    _old_size: usize,
-    _alignment: u32,
+    old_size: usize,
+    alignment: u32,
 ) -> *mut c_void {
-    return libc::realloc(c_ptr, new_size);
+    if alignment <= MALLOC_ALIGNMENT {
+        return libc::realloc(c_ptr, new_size);
+    }
+
+    // realloc can move the allocation somewhere with only malloc's alignment
+    let new_ptr = aligned_malloc(new_size, alignment as usize);
+    if !new_ptr.is_null() {
+        libc::memcpy(new_ptr, c_ptr, old_size.min(new_size));
+        libc::free(c_ptr);
+    }
+    new_ptr
 }
 
 #[no_mangle]
@@ -75,15 +98,32 @@ pub unsafe extern "C" fn roc_dealloc(c_ptr: *mut c_void, _alignment: u32) {
 
 #[no_mangle]
 pub unsafe extern "C" fn roc_panic(c_ptr: *mut c_void, tag_id: u32) {
+    let slice = CStr::from_ptr(c_ptr as *const c_char);
+    let string = slice.to_string_lossy();
+    crate::terminal::restore();
+    crate::process::kill_all();
+
+    // Tag 0 is a panic in Roc itself, any other is the app calling `crash`
     match tag_id {
-        0 => {
-            let slice = CStr::from_ptr(c_ptr as *const c_char);
-            let string = slice.to_str().unwrap();
-            eprintln!("Roc hit a panic: {}", string);
-            std::process::exit(1);
-        }
-        _ => todo!(),
+        0 => eprintln!("Roc hit a panic: {}", string),
+        _ => eprintln!("The app crashed: {}", string),
     }
+    std::process::exit(1);
+}
+
+#[cfg(unix)]
+unsafe fn aligned_malloc(size: usize, alignment: usize) -> *mut c_void {
+    let mut ptr = std::ptr::null_mut();
+    match libc::posix_memalign(&mut ptr, alignment, size) {
+        0 => ptr,
+        _ => std::ptr::null_mut(),
+    }
+}
+
+#[cfg(not(unix))]
+unsafe fn aligned_malloc(size: usize, _alignment: usize) -> *mut c_void {
+    // TODO the memory is released with free, so there is no aligned allocator to use here
+    libc::malloc(size)
 }
 
 #[no_mangle]
@@ -96,105 +136,288 @@ pub unsafe extern "C" fn roc_memset(dst: *mut c_void, c: i32, n: usize) -> *mut
     libc::memset(dst, c, n)
 }
 
-/// Call the app's init function, then render and return that result
-pub fn init_and_render(bounds: Bounds) -> (*const Model, RocList<Elem>) {
-    let closure_data_buf;
-    let closure_layout;
+/// A heap buffer for a value whose size is only known by asking the app
+struct Buffer {
+    ptr: *mut u8,
+    layout: Layout,
+}
 
-    // Call init to get the initial model
-    let model = unsafe {
-        let ret_val_layout = Layout::array::<u8>(init_result_size() as usize).unwrap();
+impl Buffer {
+    fn new(size: i64) -> Buffer {
+        // Zero sized allocations are not allowed, closures often capture nothing
+        let layout = Layout::from_size_align((size as usize).max(1), MAX_ALIGNMENT).unwrap();
+        let ptr = unsafe { std::alloc::alloc(layout) };
+        if ptr.is_null() {
+            std::alloc::handle_alloc_error(layout);
+        }
 
-        // TODO allocate on the stack if it's under a certain size
-        let ret_val_buf = std::alloc::alloc(ret_val_layout) as *mut Model;
+        Buffer { ptr, layout }
+    }
+}
 
-        closure_layout = Layout::array::<u8>(init_size() as usize).unwrap();
+impl Drop for Buffer {
+    fn drop(&mut self) {
+        unsafe { std::alloc::dealloc(self.ptr, self.layout) }
+    }
+}
 
-        // TODO allocate on the stack if it's under a certain size
-        closure_data_buf = std::alloc::alloc(closure_layout);
+/// The running Roc app, which owns the current Model.
+///
+/// Roc takes ownership of the Model whenever it is passed to update or render
+/// and hands back a new one in the result, so the model lives inside a
+/// `T (List Command) Model` result buffer. Every buffer is allocated once and
+/// then reused, which keeps memory flat no matter how many events arrive.
+pub struct App {
+    /// Result of the last init or update, holds the current Model
+    model: Buffer,
+    /// Written by the next update, then swapped with `model`
+    next_model: Buffer,
+    /// Result of the last render, `T (List Elem) Model`
+    rendered: Buffer,
+    init_closure: Buffer,
+    update_closure: Buffer,
+    render_closure: Buffer,
+}
 
-        call_init(&bounds, closure_data_buf, ret_val_buf);
+impl App {
+    /// Call the app's init function to get the initial model and any commands
+    pub fn init(startup: Startup) -> (App, RocList<Command>) {
+        let mut app = unsafe {
+            let model_size = init_result_size().max(update_result_size());
 
-        ret_val_buf
-    };
+            App {
+                model: Buffer::new(model_size),
+                next_model: Buffer::new(model_size),
+                rendered: Buffer::new(render_result_size()),
+                init_closure: Buffer::new(init_size()),
+                update_closure: Buffer::new(update_size()),
+                render_closure: Buffer::new(roc_render_size()),
+            }
+        };
 
-    // Call render passing the model to get the initial Elems
-    let elems = unsafe {
-        let mut ret_val: MaybeUninit<RocList<Elem>> = MaybeUninit::uninit();
+        let commands = unsafe {
+            call_init(&startup, app.init_closure.ptr, app.model.ptr as *mut Model);
 
-        // Reuse the buffer from the previous closure if possible
-        let closure_data_buf =
-            std::alloc::realloc(closure_data_buf, closure_layout, roc_render_size() as usize);
+            // Roc owns the startup record now, like the event given to update
+            std::mem::forget(startup);
+
+            app.take_commands()
+        };
+
+        (app, commands)
+    }
+
+    /// Call the app's update function, the new model replaces the current one
+    pub fn update(&mut self, event: Event) -> RocList<Command> {
+        unsafe {
+            call_update(
+                self.model(),
+                &event,
+                self.update_closure.ptr,
+                self.next_model.ptr as *mut Model,
+            );
+
+            // Roc owns the old model and the event now, so neither is dropped here
+            std::mem::forget(event);
+            std::mem::swap(&mut self.model, &mut self.next_model);
+
+            self.take_commands()
+        }
+    }
 
-        call_render(model, closure_data_buf, ret_val.as_mut_ptr());
+    /// Call the app's render function to get the Elems for the current model
+    pub fn render(&mut self) -> RocList<Elem> {
+        unsafe {
+            call_render(self.model(), self.render_closure.ptr, self.rendered.ptr);
+
+            // Render hands the model back alongside the Elems, move it home so
+            // the next update is given the model Roc still considers live
+            let elems_size = std::mem::size_of::<RocList<Elem>>();
+            let model_size = self.rendered.layout.size() - elems_size;
+            debug_assert!(model_size <= self.model.layout.size() - Self::model_offset());
+            std::ptr::copy_nonoverlapping(
+                self.rendered.ptr.add(elems_size),
+                self.model.ptr.add(Self::model_offset()),
+                model_size,
+            );
+
+            std::ptr::read(self.rendered.ptr as *const RocList<Elem>)
+        }
+    }
 
-        std::alloc::dealloc(closure_data_buf, closure_layout);
+    /// Init and update return `T (List Command) Model`, the commands always come first
+    fn model_offset() -> usize {
+        std::mem::size_of::<RocList<Command>>()
+    }
 
-        ret_val.assume_init()
-    };
+    fn model(&self) -> *const Model {
+        unsafe { self.model.ptr.add(Self::model_offset()) as *const Model }
+    }
 
-    (model, elems)
+    /// The host owns the commands from here, so they must only be taken once
+    /// per call to init or update
+    unsafe fn take_commands(&mut self) -> RocList<Command> {
+        std::ptr::read(self.model.ptr as *const RocList<Command>)
+    }
 }
 
-/// Call the app's update function, then render and return that result
-pub fn update(model: *const Model, event: Event) -> *const Model {
-    let closure_data_buf;
-    let closure_layout;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::glue::{BackgroundColor, Bounds, Capabilities, ColorSupport, TickInfo};
+    use roc_std::RocStr;
+    use std::alloc::{GlobalAlloc, System};
+    use std::cell::Cell;
 
-    // Call update to get the new model
-    unsafe {
-        let ret_val_layout = Layout::array::<u8>(update_result_size() as usize).unwrap();
+    // A stand-in Roc app whose Model is `Box U64` counting the updates, each
+    // update consumes the old box and allocates a new one like Roc would.
 
-        // TODO allocate on the stack if it's under a certain size
-        let ret_val_buf = std::alloc::alloc(ret_val_layout) as *mut Model;
+    type BoxedCount = *mut u64;
 
-        closure_layout = Layout::array::<u8>(update_size() as usize).unwrap();
+    thread_local! {
+        static LIVE_MODELS: Cell<isize> = const { Cell::new(0) };
+        static HOST_BYTES: Cell<isize> = const { Cell::new(0) };
+    }
 
-        // TODO allocate on the stack if it's under a certain size
-        closure_data_buf = std::alloc::alloc(closure_layout);
+    unsafe fn new_model(count: u64) -> BoxedCount {
+        LIVE_MODELS.with(|live| live.set(live.get() + 1));
+        let model = roc_alloc(std::mem::size_of::<u64>(), 8) as BoxedCount;
+        model.write(count);
+        model
+    }
 
-        call_update(model, &event, closure_data_buf, ret_val_buf);
+    unsafe fn free_model(model: BoxedCount) {
+        LIVE_MODELS.with(|live| live.set(live.get() - 1));
+        roc_dealloc(model as *mut c_void, 8);
+    }
 
-        ret_val_buf
+    #[no_mangle]
+    unsafe extern "C" fn roc__programForHost_1__Init_caller(
+        _startup: *const Startup,
+        _closure_data: *const u8,
+        output: *mut u8,
+    ) {
+        (output as *mut RocList<Command>).write(RocList::empty());
+        (output.add(App::model_offset()) as *mut BoxedCount).write(new_model(0));
     }
-}
 
-/// Call the app's update function, then render and return that result
-pub fn update_and_render(model: *const Model, event: Event) -> (*const Model, RocList<Elem>) {
-    let closure_data_buf;
-    let closure_layout;
+    #[no_mangle]
+    extern "C" fn roc__programForHost_1__Init_size() -> i64 {
+        0
+    }
 
-    // Call update to get the new model
-    let model = unsafe {
-        let ret_val_layout = Layout::array::<u8>(update_result_size() as usize).unwrap();
+    #[no_mangle]
+    extern "C" fn roc__programForHost_1__Init_result_size() -> i64 {
+        (App::model_offset() + std::mem::size_of::<BoxedCount>()) as i64
+    }
 
-        // TODO allocate on the stack if it's under a certain size
-        let ret_val_buf = std::alloc::alloc(ret_val_layout) as *mut Model;
+    #[no_mangle]
+    unsafe extern "C" fn roc__programForHost_1__Update_caller(
+        model: *const u8,
+        _event: *const Event,
+        _closure_data: *const u8,
+        output: *mut u8,
+    ) {
+        let old_model = (model as *const BoxedCount).read();
+        let count = old_model.read();
+        free_model(old_model);
+
+        (output as *mut RocList<Command>).write(RocList::empty());
+        (output.add(App::model_offset()) as *mut BoxedCount).write(new_model(count + 1));
+    }
 
-        closure_layout = Layout::array::<u8>(update_size() as usize).unwrap();
+    #[no_mangle]
+    extern "C" fn roc__programForHost_1__Update_size() -> i64 {
+        0
+    }
 
-        // TODO allocate on the stack if it's under a certain size
-        closure_data_buf = std::alloc::alloc(closure_layout);
+    #[no_mangle]
+    extern "C" fn roc__programForHost_1__Update_result_size() -> i64 {
+        (App::model_offset() + std::mem::size_of::<BoxedCount>()) as i64
+    }
 
-        call_update(model, &event, closure_data_buf, ret_val_buf);
+    #[no_mangle]
+    unsafe extern "C" fn roc__programForHost_1__Render_caller(
+        model: *const u8,
+        _closure_data: *const u8,
+        output: *mut u8,
+    ) {
+        // Like `T elems model` this moves the model into the result
+        let model = (model as *const BoxedCount).read();
+
+        (output as *mut RocList<Elem>).write(RocList::empty());
+        let elems_size = std::mem::size_of::<RocList<Elem>>();
+        (output.add(elems_size) as *mut BoxedCount).write(model);
+    }
 
-        ret_val_buf
-    };
+    #[no_mangle]
+    extern "C" fn roc__programForHost_1__Render_size() -> i64 {
+        0
+    }
 
-    // Call render passing the model to get the initial Elems
-    let elems = unsafe {
-        let mut ret_val: MaybeUninit<RocList<Elem>> = MaybeUninit::uninit();
+    #[no_mangle]
+    extern "C" fn roc__programForHost_1__Render_result_size() -> i64 {
+        (std::mem::size_of::<RocList<Elem>>() + std::mem::size_of::<BoxedCount>()) as i64
+    }
 
-        // Reuse the buffer from the previous closure if possible
-        let closure_data_buf =
-            std::alloc::realloc(closure_data_buf, closure_layout, roc_render_size() as usize);
+    /// Counts the bytes the host allocates on the current thread
+    struct CountingAllocator;
 
-        call_render(model, closure_data_buf, ret_val.as_mut_ptr());
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = HOST_BYTES.try_with(|bytes| bytes.set(bytes.get() + layout.size() as isize));
+            System.alloc(layout)
+        }
 
-        std::alloc::dealloc(closure_data_buf, closure_layout);
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            let _ = HOST_BYTES.try_with(|bytes| bytes.set(bytes.get() - layout.size() as isize));
+            System.dealloc(ptr, layout)
+        }
+    }
 
-        ret_val.assume_init()
-    };
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
 
-    (model, elems)
+    fn tick() -> Event {
+        Event::Tick(TickInfo {
+            delta: 50,
+            elapsed: 50,
+            wallClock: 0,
+        })
+    }
+
+    fn current_model(app: &App) -> u64 {
+        unsafe { (app.model() as *const BoxedCount).read().read() }
+    }
+
+    #[test]
+    fn memory_stays_flat_across_updates() {
+        let (mut app, _) = App::init(Startup {
+            args: RocList::empty(),
+            cwd: RocStr::empty(),
+            env: RocList::empty(),
+            bounds: Bounds {
+                height: 24,
+                width: 80,
+            },
+            capabilities: Capabilities {
+                background: BackgroundColor::Unknown,
+                colors: ColorSupport::Basic,
+                keyboardEnhancement: false,
+                unicode: true,
+            },
+        });
+        app.update(tick());
+        app.render();
+
+        let host_bytes = HOST_BYTES.with(Cell::get);
+        for _ in 0..10_000 {
+            app.update(tick());
+            app.render();
+        }
+
+        assert_eq!(HOST_BYTES.with(Cell::get), host_bytes);
+        assert_eq!(LIVE_MODELS.with(Cell::get), 1);
+        assert_eq!(current_model(&app), 10_001);
+    }
 }
@@ -0,0 +1,264 @@
+use crate::process;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::Mutex;
+
+/// Set while the terminal is in raw mode on the alternate screen, so that
+/// restoring it is only done once no matter how many exit paths race to it.
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+// What the app has changed, so it can be set up again after a suspend
+static BRACKETED_PASTE: AtomicBool = AtomicBool::new(true);
+static FOCUS_REPORTING: AtomicBool = AtomicBool::new(true);
+static CURSOR_STYLE: AtomicU8 = AtomicU8::new(0);
+static TITLE: Mutex<Option<String>> = Mutex::new(None);
+
+/// Owns the terminal setup for the lifetime of the app. The terminal is put
+/// back the way we found it when this is dropped, when Rust or Roc panics, and
+/// when the process is asked to stop with a signal.
+pub struct TerminalGuard;
+
+impl TerminalGuard {
+    pub fn new() -> TerminalGuard {
+        install_panic_hook();
+
+        #[cfg(unix)]
+        install_signal_handler();
+
+        enter();
+
+        TerminalGuard
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        restore();
+    }
+}
+
+/// Put the terminal into raw mode on the alternate screen, capture the mouse,
+/// and report pastes and focus changes. Apps can opt out of the last two with
+/// `set_bracketed_paste` and `set_focus_reporting`.
+pub fn enter() {
+    crossterm::terminal::enable_raw_mode().expect("TODO handle enabling Raw mode on terminal");
+    crossterm::execute!(
+        std::io::stdout(),
+        crossterm::terminal::EnterAlternateScreen,
+        crossterm::event::EnableMouseCapture,
+        // Save the window title so it can be put back when we exit
+        Ansi("\x1b[22;0t")
+    )
+    .expect("TODO handle entering alternate screen and enabling mouse capture on terminal");
+
+    // Anything the app changed before a suspend is changed again
+    set_bracketed_paste(BRACKETED_PASTE.load(Ordering::SeqCst));
+    set_focus_reporting(FOCUS_REPORTING.load(Ordering::SeqCst));
+    set_cursor_style(CURSOR_STYLE.load(Ordering::SeqCst));
+    let title = TITLE.lock().ok().and_then(|title| title.clone());
+    if let Some(title) = title {
+        set_title(&title);
+    }
+
+    ACTIVE.store(true, Ordering::SeqCst);
+}
+
+/// Undo everything `enter` did. This is safe to call from any thread and more
+/// than once, errors are ignored as we are usually already on the way out.
+pub fn restore() {
+    if !ACTIVE.swap(false, Ordering::SeqCst) {
+        return;
+    }
+
+    // Disabling a mode which isn't enabled is harmless, so there is no need to
+    // track what the app opted out of
+    let _ = crossterm::execute!(
+        std::io::stdout(),
+        crossterm::event::DisableFocusChange,
+        crossterm::event::DisableBracketedPaste,
+        crossterm::event::DisableMouseCapture,
+        crossterm::terminal::LeaveAlternateScreen,
+        crossterm::cursor::Show,
+        Ansi("\x1b[0 q"),
+        Ansi("\x1b[23;0t")
+    );
+    let _ = crossterm::terminal::disable_raw_mode();
+}
+
+/// Give the terminal back to the shell and stop, like ctrl+z does for
+/// programs which aren't in raw mode. This returns once the shell continues
+/// us with `fg`, with the terminal set up again.
+#[cfg(unix)]
+pub fn suspend() {
+    restore();
+    unsafe {
+        libc::raise(libc::SIGSTOP);
+    }
+    enter();
+}
+
+/// With bracketed paste the terminal sends pasted text as a single `Paste`
+/// event, otherwise it arrives as if each character had been typed
+pub fn set_bracketed_paste(enabled: bool) {
+    BRACKETED_PASTE.store(enabled, Ordering::SeqCst);
+    if enabled {
+        crossterm::execute!(std::io::stdout(), crossterm::event::EnableBracketedPaste)
+    } else {
+        crossterm::execute!(std::io::stdout(), crossterm::event::DisableBracketedPaste)
+    }
+    .expect("TODO handle unable to change bracketed paste mode");
+}
+
+/// Turns the FocusGained and FocusLost events on or off
+pub fn set_focus_reporting(enabled: bool) {
+    FOCUS_REPORTING.store(enabled, Ordering::SeqCst);
+    if enabled {
+        crossterm::execute!(std::io::stdout(), crossterm::event::EnableFocusChange)
+    } else {
+        crossterm::execute!(std::io::stdout(), crossterm::event::DisableFocusChange)
+    }
+    .expect("TODO handle unable to change focus reporting");
+}
+
+/// Set the title of the terminal window or tab, the original title is put
+/// back when the app exits on terminals which support it
+pub fn set_title(title: &str) {
+    if let Ok(mut current) = TITLE.lock() {
+        *current = Some(title.to_string());
+    }
+    crossterm::execute!(std::io::stdout(), crossterm::terminal::SetTitle(title))
+        .expect("TODO handle unable to set the terminal title");
+}
+
+/// Change the shape of the cursor and whether it blinks, using the DECSCUSR
+/// numbering where 0 is the terminal's default. crossterm's `SetCursorShape`
+/// can't choose between blinking and steady for every shape.
+pub fn set_cursor_style(style: u8) {
+    CURSOR_STYLE.store(style, Ordering::SeqCst);
+    crossterm::execute!(std::io::stdout(), Ansi(&format!("\x1b[{} q", style)))
+        .expect("TODO handle unable to set the cursor style");
+}
+
+/// An escape sequence crossterm doesn't have a command for. Old Windows
+/// consoles don't understand these, so nothing is done there.
+struct Ansi<'a>(&'a str);
+
+impl crossterm::Command for Ansi<'_> {
+    fn write_ansi(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
+        f.write_str(self.0)
+    }
+
+    #[cfg(windows)]
+    fn execute_winapi(&self) -> crossterm::Result<()> {
+        Ok(())
+    }
+}
+
+/// Write an escape sequence asking the terminal something, then collect its
+/// reply until `is_complete` says it has all arrived. Returns `None` if the
+/// terminal doesn't finish replying within `timeout`.
+///
+/// This reads the terminal directly, so it mustn't run while the event thread
+/// is polling or the two would steal each other's input. Anything typed while
+/// waiting is lost, so keep the timeout short.
+#[cfg(unix)]
+pub fn query(
+    request: &str,
+    timeout: std::time::Duration,
+    is_complete: fn(&[u8]) -> bool,
+) -> Option<Vec<u8>> {
+    use std::io::{Read, Write};
+    use std::os::unix::io::AsRawFd;
+
+    let mut tty = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open("/dev/tty")
+        .ok()?;
+    tty.write_all(request.as_bytes()).ok()?;
+    tty.flush().ok()?;
+
+    let deadline = std::time::Instant::now() + timeout;
+    let mut reply = Vec::new();
+    loop {
+        if is_complete(&reply) {
+            return Some(reply);
+        }
+
+        let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+        let mut fd = libc::pollfd {
+            fd: tty.as_raw_fd(),
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        if remaining.is_zero()
+            || unsafe { libc::poll(&mut fd, 1, remaining.as_millis() as libc::c_int) } <= 0
+        {
+            return None;
+        }
+
+        let mut buffer = [0; 1024];
+        let count = tty.read(&mut buffer).ok()?;
+        reply.extend_from_slice(&buffer[..count]);
+    }
+}
+
+/// Terminals can only be queried with escape sequences on unix
+#[cfg(not(unix))]
+pub fn query(
+    _request: &str,
+    _timeout: std::time::Duration,
+    _is_complete: fn(&[u8]) -> bool,
+) -> Option<Vec<u8>> {
+    None
+}
+
+/// Query the terminal with a request which is answered by an OSC sequence,
+/// and return the body of the reply
+pub fn query_osc(request: &str, timeout: std::time::Duration) -> Option<String> {
+    let reply = query(request, timeout, |reply| get_osc_body(reply).is_some())?;
+    get_osc_body(&reply)
+}
+
+/// The body of the first OSC sequence in the bytes, which is everything
+/// between `ESC ]` and the BEL or ST which ends it
+pub fn get_osc_body(bytes: &[u8]) -> Option<String> {
+    let start = bytes.windows(2).position(|w| w == b"\x1b]")? + 2;
+    let body = &bytes[start..];
+    let end = body
+        .iter()
+        .enumerate()
+        .position(|(i, byte)| *byte == b'\x07' || body[i..].starts_with(b"\x1b\\"))?;
+    Some(String::from_utf8_lossy(&body[..end]).into_owned())
+}
+
+/// Restore the terminal before the default hook prints the panic message, so
+/// the message ends up on the user's normal screen instead of being lost.
+/// A panic on any thread exits, as the app can't carry on without the thread
+/// and would otherwise draw over the user's shell.
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore();
+        process::kill_all();
+        default_hook(info);
+        std::process::exit(101);
+    }));
+}
+
+/// Raw mode stops ctrl+c from sending SIGINT, but the process can still be
+/// stopped with `kill` or by closing the terminal window.
+#[cfg(unix)]
+fn install_signal_handler() {
+    use signal_hook::consts::{SIGHUP, SIGINT, SIGTERM};
+
+    let mut signals = signal_hook::iterator::Signals::new([SIGHUP, SIGINT, SIGTERM])
+        .expect("TODO handle unable to register signal handlers");
+
+    std::thread::spawn(move || {
+        if let Some(signal) = signals.forever().next() {
+            restore();
+            process::kill_all();
+            std::process::exit(128 + signal);
+        }
+    });
+}
@@ -1,130 +1,634 @@
 use roc_std::{RocStr, RocList};
+use crate::capabilities;
+use crate::clipboard;
+use crate::color;
+use crate::editor;
+use crate::file;
 use crate::glue;
+use crate::process;
 use crate::roc;
+use crate::terminal;
 
-const SCREEN_DRAW_RATE_MS: u64 = 50;
+/// How often the app receives a `Tick` event
+const TICK_RATE_MS: u64 = 50;
 
-pub fn run_event_loop() {
+/// The screen is redrawn after any event, but never more often than this
+const MAX_FRAMES_PER_SECOND: u64 = 60;
 
-    // Setup terminal
-    crossterm::terminal::enable_raw_mode().expect("TODO handle enabling Raw mode on terminal");
-    let mut stdout = std::io::stdout();
-    crossterm::execute!(
-        stdout,
-        crossterm::terminal::EnterAlternateScreen,
-        crossterm::event::EnableMouseCapture
-    )
-    .expect("TODO handle entering alternate screen and enabling mouse capture on terminal");
-    let backend = tui::backend::CrosstermBackend::new(stdout);
+/// How many colors the terminal can show, this is set once at startup. The
+/// colors in an app's styles are downgraded to fit when they are drawn.
+static COLOR_SUPPORT: std::sync::OnceLock<glue::ColorSupport> = std::sync::OnceLock::new();
+
+pub fn run_event_loop() -> i32 {
+
+    // Read host configuration before the terminal is taken over
+    let quit_key = get_key_chord("ROC_TUI_QUIT_KEY", "none");
+    let suspend_key = get_key_chord("ROC_TUI_SUSPEND_KEY", DEFAULT_SUSPEND_KEY);
+
+    // Setup terminal, this is restored when the guard is dropped
+    let _guard = terminal::TerminalGuard::new();
+    let capabilities = capabilities::detect();
+    let _ = COLOR_SUPPORT.set(capabilities.colors);
+    let backend = tui::backend::CrosstermBackend::new(std::io::stdout());
     let mut terminal =
         tui::Terminal::new(backend).expect("TODO handle unable to create crossterm backend");
-    let tick_rate = std::time::Duration::from_millis(SCREEN_DRAW_RATE_MS);
+    let tick_rate = std::time::Duration::from_millis(TICK_RATE_MS);
     let events = Events::new(tick_rate);
+    let processes = process::Processes::new(events.sender());
+    #[cfg(unix)]
+    forward_suspend_signal(events.sender());
     let size = terminal.size().expect("TODO unable to get frame size");
-    let window_bounds = glue::Bounds {
-        height: size.height,
-        width: size.width,
-    };
+    let window_bounds = get_window_bounds(size.width, size.height);
 
     // Initialise Roc app
-    let mut elems : RocList<glue::Elem>;
-    let (mut model, _) = roc::init_and_render(window_bounds);
+    let (mut app, commands) = roc::App::init(get_startup(window_bounds, capabilities));
+    let mut exit_code = run_commands(commands, &events, &processes);
+
+    // The model is opaque to the host, so every update is assumed to change
+    // it. Events which arrive before the next frame is due are all handled
+    // first, so a burst of input only costs a single render. A frame is drawn
+    // as soon as it is due though, even if events keep arriving.
+    let frame_interval = std::time::Duration::from_millis(1000 / MAX_FRAMES_PER_SECOND);
+    let mut next_frame = std::time::Instant::now();
+    let mut dirty = true;
+
+    while exit_code.is_none() {
+        if dirty && std::time::Instant::now() >= next_frame {
+            // Draw the widgets
+            let elems = app.render();
+            terminal
+                .draw(|f| {
+                    for elem in &elems {
+                        render_widget(f, f.size(), &elem)
+                    }
+                })
+                .expect("Err: Unable to draw to terminal.");
 
-    loop {
-        let mut app_return = false;
+            dirty = false;
+            next_frame = std::time::Instant::now() + frame_interval;
+        }
+
+        let input = if dirty {
+            let wait = next_frame.saturating_duration_since(std::time::Instant::now());
+            match events.next_timeout(wait) {
+                Ok(input) => input,
+                Err(std::sync::mpsc::RecvTimeoutError::Timeout) => continue,
+                Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+                    panic!("TODO handle unable to spawn event thread")
+                }
+            }
+        } else {
+            events.next().expect("TODO handle unable to spawn event thread")
+        };
 
         // Handle any events
-        match events
-            .next()
-            .expect("TODO handle unable to spawn event thread")
-        {
+        let event = match input {
             InputEvent::KeyPressed(key) => {
-                if key.code == crossterm::event::KeyCode::Esc {
-                    // TODO don't hardcode the escape
-                    app_return = true;
-                } else {
-                    let kc = get_key_code(key.code);
-                    let event = glue::Event::KeyPressed(kc);
-                    model = roc::update(model, event);
+                if is_key_chord(quit_key, key) {
+                    exit_code = Some(130);
+                    break;
                 }
+                if is_key_chord(suspend_key, key) {
+                    let _ = events.sender().send(InputEvent::Suspend);
+                    continue;
+                }
+                glue::Event::KeyPressed(get_key_event(key))
             }
-            InputEvent::FocusGained => {
-                let event = glue::Event::FocusGained;
-                model = roc::update(model, event);
+            #[cfg(unix)]
+            InputEvent::Suspend => {
+                // Let the app save anything it needs to before we stop
+                exit_code = run_commands(app.update(glue::Event::Suspended), &events, &processes);
+                if exit_code.is_some() {
+                    break;
+                }
+
+                terminal::suspend();
+
+                // Whatever was on screen has gone, so redraw everything
+                terminal.clear().expect("TODO handle unable to clear the terminal");
+                next_frame = std::time::Instant::now();
+                glue::Event::Resumed
             }
-            InputEvent::FocusLost => {
-                let event = glue::Event::FocusLost;
-                model = roc::update(model, event);
+            #[cfg(not(unix))]
+            InputEvent::Suspend => continue,
+            InputEvent::Edit { id, text } => {
+                let result = {
+                    // The editor needs the input the event thread would read
+                    let _paused = events.pause();
+                    terminal::restore();
+                    let result = editor::edit(&text);
+                    terminal::enter();
+                    result
+                };
+
+                terminal.clear().expect("TODO handle unable to clear the terminal");
+                next_frame = std::time::Instant::now();
+
+                let id = RocStr::from(&id[..]);
+                match result {
+                    Ok(editor::Edit::Saved(text)) => glue::Event::Edited(glue::EditedText {
+                        id,
+                        text: RocStr::from(&text[..]),
+                    }),
+                    Ok(editor::Edit::Cancelled(status)) => {
+                        glue::Event::EditCancelled(glue::EditorExit {
+                            id,
+                            status: process::get_exit_status(status),
+                        })
+                    }
+                    Err(error) => glue::Event::EditFailed(glue::EditFailure {
+                        id,
+                        error: file::get_file_error(&error),
+                    }),
+                }
             }
+            InputEvent::FocusGained => glue::Event::FocusGained,
+            InputEvent::FocusLost => glue::Event::FocusLost,
             InputEvent::Paste(contents) => {
                 let roc_string = roc_std::RocStr::from(&contents[..]);
-                let event = glue::Event::Paste(roc_string);
-                model = roc::update(model, event);
+                glue::Event::Paste(roc_string)
             }
+            InputEvent::Mouse(mouse) => glue::Event::Mouse(get_mouse_event(mouse)),
             InputEvent::Resize(column, row) => {
-                let window_bounds = glue::Bounds {
-                    height: column,
-                    width: row,
-                };
-                let event = glue::Event::Resize(window_bounds);
-                model = roc::update(model, event);
-            }
-            InputEvent::Tick => {
-                let event = glue::Event::Tick;
-                (model, elems) = roc::update_and_render(model, event);
-
-                // Draw the widgets
-                terminal
-                    .draw(|f| {
-                        for elem in &elems {
-                            render_widget(f, f.size(), &elem)
-                        }
+                // Don't wait for the frame rate, the old frame is the wrong size
+                next_frame = std::time::Instant::now();
+                glue::Event::Resize(get_window_bounds(column, row))
+            }
+            InputEvent::Tick {
+                elapsed,
+                delta,
+                wall_clock,
+            } => glue::Event::Tick(glue::TickInfo {
+                elapsed: elapsed.as_millis() as u64,
+                delta: delta.as_millis() as u64,
+                wallClock: wall_clock.as_millis() as u64,
+            }),
+            InputEvent::Timer(id) => glue::Event::Timer(RocStr::from(&id[..])),
+            InputEvent::FileRead { path, contents } => glue::Event::FileRead(glue::FileContents {
+                path: RocStr::from(&path[..]),
+                contents: RocStr::from(&contents[..]),
+            }),
+            InputEvent::FileLinesRead { path, start, lines } => {
+                let lines: Vec<RocStr> = lines.iter().map(|line| RocStr::from(&line[..])).collect();
+                glue::Event::FileLinesRead(glue::FileLines {
+                    path: RocStr::from(&path[..]),
+                    start: start as _,
+                    lines: RocList::from_slice(&lines),
+                })
+            }
+            InputEvent::DirListed { path, entries } => {
+                let entries: Vec<glue::DirEntry> = entries
+                    .iter()
+                    .map(|(name, kind)| glue::DirEntry {
+                        name: RocStr::from(&name[..]),
+                        kind: *kind,
                     })
-                    .expect("Err: Unable to draw to terminal.");
-                }
+                    .collect();
+                glue::Event::DirListed(glue::DirContents {
+                    path: RocStr::from(&path[..]),
+                    entries: RocList::from_slice(&entries),
+                })
+            }
+            InputEvent::FileFailed {
+                path,
+                operation,
+                error,
+            } => glue::Event::FileFailed(glue::FileFailure {
+                path: RocStr::from(&path[..]),
+                operation,
+                error: file::get_file_error(&error),
+            }),
+            InputEvent::FileDone { path, operation } => glue::Event::FileDone(glue::FileSuccess {
+                path: RocStr::from(&path[..]),
+                operation,
+            }),
+            InputEvent::ProcessOutput { id, stream, line } => {
+                glue::Event::ProcessOutput(glue::OutputLine {
+                    id: RocStr::from(&id[..]),
+                    stream,
+                    line: RocStr::from(&line[..]),
+                })
+            }
+            InputEvent::ProcessExited { id, status } => glue::Event::ProcessExited(glue::ProcessExit {
+                id: RocStr::from(&id[..]),
+                status: process::get_exit_status(status),
+            }),
+            InputEvent::ProcessFailed { id, error } => {
+                glue::Event::ProcessFailed(glue::ProcessFailure {
+                    id: RocStr::from(&id[..]),
+                    error: file::get_file_error(&error),
+                })
+            }
+            InputEvent::ClipboardRead(contents) => {
+                glue::Event::ClipboardRead(RocStr::from(&contents[..]))
+            }
+            InputEvent::ClipboardUnavailable => glue::Event::ClipboardUnavailable,
         };
 
-        if app_return {
-            break;
+        exit_code = run_commands(app.update(event), &events, &processes);
+        dirty = true;
+    }
+
+    exit_code.unwrap_or_default()
+}
+
+/// Both the terminal size given to init and a Resize event are measured in
+/// columns and rows, which are the width and height of the window
+fn get_window_bounds(columns: u16, rows: u16) -> glue::Bounds {
+    glue::Bounds {
+        height: rows,
+        width: columns,
+    }
+}
+
+/// How the app was launched, so it can be used like any other command line
+/// tool. The first argument is the path of the program itself. Arguments and
+/// variables which aren't valid UTF-8 have the bad bytes replaced.
+fn get_startup(bounds: glue::Bounds, capabilities: glue::Capabilities) -> glue::Startup {
+    let args: Vec<RocStr> = std::env::args_os()
+        .map(|arg| RocStr::from(&arg.to_string_lossy()[..]))
+        .collect();
+
+    let mut env: Vec<(String, String)> = std::env::vars_os()
+        .map(|(name, value)| {
+            (
+                name.to_string_lossy().into_owned(),
+                value.to_string_lossy().into_owned(),
+            )
+        })
+        .collect();
+    env.sort();
+    let env: Vec<glue::EnvVar> = env
+        .iter()
+        .map(|(name, value)| glue::EnvVar {
+            name: RocStr::from(&name[..]),
+            value: RocStr::from(&value[..]),
+        })
+        .collect();
+
+    let cwd = std::env::current_dir()
+        .map(|cwd| cwd.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    glue::Startup {
+        args: RocList::from_slice(&args),
+        cwd: RocStr::from(&cwd[..]),
+        env: RocList::from_slice(&env),
+        bounds,
+        capabilities,
+    }
+}
+
+/// Carry out the commands returned from the app's init or update, in order.
+/// Returns the exit code once the app asks to quit, any commands after a
+/// `Quit` are ignored.
+fn run_commands(
+    commands: RocList<glue::Command>,
+    events: &Events,
+    processes: &process::Processes,
+) -> Option<i32> {
+    for command in &commands {
+        match command.discriminant() {
+            glue::discriminant_Command::Quit => {
+                return Some(i32::from(unsafe { *command.as_Quit() }));
+            }
+            glue::discriminant_Command::SetBracketedPaste => {
+                terminal::set_bracketed_paste(unsafe { *command.as_SetBracketedPaste() });
+            }
+            glue::discriminant_Command::SetFocusReporting => {
+                terminal::set_focus_reporting(unsafe { *command.as_SetFocusReporting() });
+            }
+            glue::discriminant_Command::SetTickRate => {
+                let milliseconds = unsafe { *command.as_SetTickRate() };
+                let tick_rate = match milliseconds {
+                    0 => None,
+                    _ => Some(std::time::Duration::from_millis(milliseconds)),
+                };
+                events.set_tick_rate(tick_rate);
+            }
+            glue::discriminant_Command::StartTimer => {
+                let timer = unsafe { command.as_StartTimer() };
+                let duration = std::time::Duration::from_millis(timer.milliseconds);
+                events.start_timer(timer.id.as_str(), duration, timer.repeat);
+            }
+            glue::discriminant_Command::CancelTimer => {
+                events.cancel_timer(unsafe { command.as_CancelTimer() }.as_str());
+            }
+            glue::discriminant_Command::ReadFile => {
+                let path = unsafe { command.as_ReadFile() };
+                file::read(path.as_str().to_string(), events.sender());
+            }
+            glue::discriminant_Command::ReadFileLines => {
+                let config = unsafe { command.as_ReadFileLines() };
+                file::read_lines(
+                    config.path.as_str().to_string(),
+                    config.start as usize,
+                    config.count as usize,
+                    events.sender(),
+                );
+            }
+            glue::discriminant_Command::ListDir => {
+                let path = unsafe { command.as_ListDir() };
+                file::list_dir(path.as_str().to_string(), events.sender());
+            }
+            glue::discriminant_Command::WriteFile => {
+                let write = unsafe { command.as_WriteFile() };
+                file::write(
+                    write.path.as_str().to_string(),
+                    write.contents.as_str().to_string(),
+                    events.sender(),
+                );
+            }
+            glue::discriminant_Command::AppendFile => {
+                let append = unsafe { command.as_AppendFile() };
+                file::append(
+                    append.path.as_str().to_string(),
+                    append.contents.as_str().to_string(),
+                    events.sender(),
+                );
+            }
+            glue::discriminant_Command::RenameFile => {
+                let rename = unsafe { command.as_RenameFile() };
+                file::rename(
+                    rename.from.as_str().to_string(),
+                    rename.to.as_str().to_string(),
+                    events.sender(),
+                );
+            }
+            glue::discriminant_Command::DeleteFile => {
+                let path = unsafe { command.as_DeleteFile() };
+                file::delete(path.as_str().to_string(), events.sender());
+            }
+            glue::discriminant_Command::SpawnProcess => {
+                let spawn = unsafe { command.as_SpawnProcess() };
+                let mut child = std::process::Command::new(spawn.command.as_str());
+                child
+                    .args(spawn.args.iter().map(|arg| arg.as_str()))
+                    .envs(spawn.env.iter().map(|var| (var.name.as_str(), var.value.as_str())));
+                if !spawn.cwd.is_empty() {
+                    child.current_dir(spawn.cwd.as_str());
+                }
+                processes.spawn(spawn.id.as_str().to_string(), child);
+            }
+            glue::discriminant_Command::WriteStdin => {
+                let write = unsafe { command.as_WriteStdin() };
+                processes.write_stdin(
+                    write.id.as_str().to_string(),
+                    write.input.as_str().to_string(),
+                );
+            }
+            glue::discriminant_Command::CloseStdin => {
+                let id = unsafe { command.as_CloseStdin() };
+                processes.close_stdin(id.as_str().to_string());
+            }
+            glue::discriminant_Command::KillProcess => {
+                let id = unsafe { command.as_KillProcess() };
+                processes.kill(id.as_str().to_string());
+            }
+            glue::discriminant_Command::CopyToClipboard => {
+                clipboard::copy(unsafe { command.as_CopyToClipboard() }.as_str());
+            }
+            glue::discriminant_Command::ReadClipboard => events.read_clipboard(),
+            glue::discriminant_Command::SetTitle => {
+                terminal::set_title(unsafe { command.as_SetTitle() }.as_str());
+            }
+            glue::discriminant_Command::SetCursorStyle => {
+                let style = unsafe { *command.as_SetCursorStyle() };
+                terminal::set_cursor_style(get_cursor_style(style));
+            }
+            glue::discriminant_Command::OpenEditor => {
+                // This needs the terminal, so it is done by the event loop
+                let edit = unsafe { command.as_OpenEditor() };
+                let _ = events.sender().send(InputEvent::Edit {
+                    id: edit.id.as_str().to_string(),
+                    text: edit.text.as_str().to_string(),
+                });
+            }
         }
     }
 
-    // restore terminal
-    crossterm::terminal::disable_raw_mode()
-        .expect("TODO handle unable to disable Raw mode on terminal");
-    crossterm::execute!(
-        terminal.backend_mut(),
-        crossterm::terminal::LeaveAlternateScreen,
-        crossterm::event::DisableMouseCapture
-    )
-    .expect("TODO handle unable to leave alternate screen or disable mouse capture");
+    None
+}
+
+/// Suspending needs job control, which only unix shells have
+const DEFAULT_SUSPEND_KEY: &str = if cfg!(unix) { "ctrl+z" } else { "none" };
+
+/// A host level key chord, such as the one which quits even if the app never
+/// returns `Quit`. Each has a default which can be changed with an environment
+/// variable e.g. `ROC_TUI_QUIT_KEY=ctrl+q`, `ctrl+alt+f4`, or `none` to
+/// disable it. The quit chord is off unless it is set, so apps keep ctrl+c.
+fn get_key_chord(
+    name: &str,
+    default: &str,
+) -> Option<(crossterm::event::KeyModifiers, crossterm::event::KeyCode)> {
+    let chord = std::env::var(name).unwrap_or_else(|_| default.to_string());
+    let chord = chord.trim().to_lowercase();
+    if chord == "none" || chord.is_empty() {
+        return None;
+    }
+
+    let mut modifiers = crossterm::event::KeyModifiers::NONE;
+    let mut code = None;
+    for part in chord.split('+') {
+        match part {
+            "ctrl" | "control" => modifiers |= crossterm::event::KeyModifiers::CONTROL,
+            "alt" => modifiers |= crossterm::event::KeyModifiers::ALT,
+            "shift" => modifiers |= crossterm::event::KeyModifiers::SHIFT,
+            "super" => modifiers |= crossterm::event::KeyModifiers::SUPER,
+            "esc" => code = Some(crossterm::event::KeyCode::Esc),
+            "enter" => code = Some(crossterm::event::KeyCode::Enter),
+            "tab" => code = Some(crossterm::event::KeyCode::Tab),
+            "backspace" => code = Some(crossterm::event::KeyCode::Backspace),
+            _ if part.chars().count() == 1 => {
+                code = part.chars().next().map(crossterm::event::KeyCode::Char)
+            }
+            _ if part.starts_with('f') && part[1..].parse::<u8>().is_ok() => {
+                code = Some(crossterm::event::KeyCode::F(part[1..].parse().unwrap()))
+            }
+            _ => panic!("Unrecognised key '{}' in {}", part, name),
+        }
+    }
 
+    match code {
+        Some(code) => Some((modifiers, code)),
+        None => panic!("{} '{}' is missing a key", name, chord),
+    }
+}
+
+fn is_key_chord(
+    chord: Option<(crossterm::event::KeyModifiers, crossterm::event::KeyCode)>,
+    key: crossterm::event::KeyEvent,
+) -> bool {
+    let code = match key.code {
+        crossterm::event::KeyCode::Char(c) => {
+            crossterm::event::KeyCode::Char(c.to_ascii_lowercase())
+        }
+        code => code,
+    };
+
+    chord == Some((key.modifiers, code))
 }
 
 pub enum InputEvent {
     KeyPressed(crossterm::event::KeyEvent),
     FocusGained,
     FocusLost,
-    // TODO Mouse(MouseEvent),
+    Mouse(crossterm::event::MouseEvent),
     Paste(String),
     Resize(u16, u16), // column, row
-    Tick,
+    Tick {
+        elapsed: std::time::Duration,
+        delta: std::time::Duration,
+        wall_clock: std::time::Duration,
+    },
+    Timer(String),
+    FileRead {
+        path: String,
+        contents: String,
+    },
+    FileLinesRead {
+        path: String,
+        start: usize,
+        lines: Vec<String>,
+    },
+    DirListed {
+        path: String,
+        entries: Vec<(String, glue::FileKind)>,
+    },
+    FileFailed {
+        path: String,
+        operation: glue::FileOperation,
+        error: std::io::Error,
+    },
+    FileDone {
+        path: String,
+        operation: glue::FileOperation,
+    },
+    ProcessOutput {
+        id: String,
+        stream: glue::OutputStream,
+        line: String,
+    },
+    ProcessExited {
+        id: String,
+        status: std::process::ExitStatus,
+    },
+    ProcessFailed {
+        id: String,
+        error: std::io::Error,
+    },
+    ClipboardRead(String),
+    ClipboardUnavailable,
+    Suspend,
+    Edit {
+        id: String,
+        text: String,
+    },
+}
+
+/// `kill -TSTP` suspends the app the same way as the suspend key. Raw mode
+/// stops ctrl+z sending this, so the suspend key is handled as a key press.
+#[cfg(unix)]
+fn forward_suspend_signal(tx: std::sync::mpsc::Sender<InputEvent>) {
+    let mut signals = signal_hook::iterator::Signals::new([signal_hook::consts::SIGTSTP])
+        .expect("TODO handle unable to register signal handlers");
+
+    std::thread::spawn(move || {
+        for _ in signals.forever() {
+            if tx.send(InputEvent::Suspend).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Changes to when the event thread sends Tick and Timer events, and requests
+/// which have to wait until it isn't reading input
+enum Schedule {
+    TickRate(Option<std::time::Duration>),
+    StartTimer(Timer),
+    CancelTimer(String),
+    ReadClipboard,
+    Pause {
+        paused: std::sync::mpsc::Sender<()>,
+        resume: std::sync::mpsc::Receiver<()>,
+    },
+}
+
+/// Input is read again once this is dropped
+pub struct Paused {
+    _resume: std::sync::mpsc::Sender<()>,
 }
 
+struct Timer {
+    id: String,
+    due: std::time::Instant,
+    repeat: Option<std::time::Duration>,
+}
+
+/// The event thread can't be woken while it waits for input, so it checks for
+/// changes to the schedule at least this often
+const SCHEDULE_POLL_MS: u64 = 10;
+
 pub struct Events {
     rx: std::sync::mpsc::Receiver<InputEvent>,
-    _tx: std::sync::mpsc::Sender<InputEvent>,
+    tx: std::sync::mpsc::Sender<InputEvent>,
+    schedule_tx: std::sync::mpsc::Sender<Schedule>,
 }
 
 impl Events {
     pub fn new(tick_rate: std::time::Duration) -> Events {
         let (tx, rx) = std::sync::mpsc::channel();
+        let (schedule_tx, schedule_rx) = std::sync::mpsc::channel();
 
         let event_tx = tx.clone(); // the thread::spawn own event_tx
         std::thread::spawn(move || {
+            let start = std::time::Instant::now();
+            let mut last_tick = start;
+            let mut tick_rate = Some(tick_rate);
+            let mut next_tick = tick_rate.and_then(|rate| start.checked_add(rate));
+            let mut timers: Vec<Timer> = Vec::new();
             loop {
-                // poll for tick rate duration, if no event, sent tick event.
-                if crossterm::event::poll(tick_rate)
+                for change in schedule_rx.try_iter() {
+                    match change {
+                        Schedule::TickRate(rate) => {
+                            // A rate too long to add to the clock never ticks
+                            tick_rate = rate;
+                            next_tick =
+                                rate.and_then(|rate| std::time::Instant::now().checked_add(rate));
+                        }
+                        Schedule::StartTimer(timer) => {
+                            timers.retain(|t| t.id != timer.id);
+                            timers.push(timer);
+                        }
+                        Schedule::CancelTimer(id) => timers.retain(|t| t.id != id),
+                        Schedule::ReadClipboard => {
+                            // The terminal replies on the input we would
+                            // otherwise be polling, so read it here
+                            let event = match clipboard::read() {
+                                Some(contents) => InputEvent::ClipboardRead(contents),
+                                None => InputEvent::ClipboardUnavailable,
+                            };
+                            event_tx
+                                .send(event)
+                                .expect("TODO hangle unable to send clipboard event to channel");
+                        }
+                        Schedule::Pause { paused, resume } => {
+                            let _ = paused.send(());
+                            // Returns when the Paused guard is dropped
+                            let _ = resume.recv();
+                        }
+                    }
+                }
+
+                // poll until the next tick or timer is due, input doesn't delay them
+                let due = timers.iter().map(|t| t.due).chain(next_tick).min();
+                let max_timeout = std::time::Duration::from_millis(SCHEDULE_POLL_MS);
+                let timeout = match due {
+                    Some(due) => due.saturating_duration_since(std::time::Instant::now()),
+                    None => max_timeout,
+                };
+                if crossterm::event::poll(timeout.min(max_timeout))
                     .expect("TODO handle unable to poll for crossterm events")
                 {
                     match crossterm::event::read().expect(
@@ -146,8 +650,10 @@ impl Events {
                                 .send(InputEvent::FocusLost)
                                 .expect("TODO hangle unable to send focus lost event to channel");
                         }
-                        crossterm::event::Event::Mouse(_) => {
-                            // TODO support mouse stuff
+                        crossterm::event::Event::Mouse(mouse) => {
+                            event_tx
+                                .send(InputEvent::Mouse(mouse))
+                                .expect("TODO hangle unable to send mouse event to channel");
                         }
                         crossterm::event::Event::Paste(contents) => {
                             event_tx
@@ -161,13 +667,46 @@ impl Events {
                         }
                     }
                 }
-                event_tx
-                    .send(InputEvent::Tick)
-                    .expect("TODO hangle unable to send tick event to channel");
+
+                let now = std::time::Instant::now();
+                if let (Some(due), Some(rate)) = (next_tick, tick_rate) {
+                    if now >= due {
+                        let wall_clock = std::time::SystemTime::now()
+                            .duration_since(std::time::UNIX_EPOCH)
+                            .unwrap_or_default();
+                        event_tx
+                            .send(InputEvent::Tick {
+                                elapsed: now - start,
+                                delta: now - last_tick,
+                                wall_clock,
+                            })
+                            .expect("TODO hangle unable to send tick event to channel");
+                        last_tick = now;
+                        next_tick = now.checked_add(rate);
+                    }
+                }
+
+                for timer in timers.iter_mut().filter(|t| now >= t.due) {
+                    event_tx
+                        .send(InputEvent::Timer(timer.id.clone()))
+                        .expect("TODO hangle unable to send timer event to channel");
+                    if let Some(repeat) = timer.repeat {
+                        match get_next_due(timer.due, repeat, now) {
+                            Some(due) => timer.due = due,
+                            // Never due again, so it is removed below
+                            None => timer.repeat = None,
+                        }
+                    }
+                }
+                timers.retain(|t| t.repeat.is_some() || t.due > now);
             }
         });
 
-        Events { rx, _tx: tx }
+        Events {
+            rx,
+            tx,
+            schedule_tx,
+        }
     }
 
     /// Attempts to read an event.
@@ -175,6 +714,95 @@ impl Events {
     pub fn next(&self) -> Result<InputEvent, std::sync::mpsc::RecvError> {
         self.rx.recv()
     }
+
+    /// Attempts to read an event, waiting no longer than the timeout.
+    /// A zero timeout only returns events which are already queued.
+    pub fn next_timeout(
+        &self,
+        timeout: std::time::Duration,
+    ) -> Result<InputEvent, std::sync::mpsc::RecvTimeoutError> {
+        self.rx.recv_timeout(timeout)
+    }
+
+    /// Work done in the background sends its result as an event with this
+    pub fn sender(&self) -> std::sync::mpsc::Sender<InputEvent> {
+        self.tx.clone()
+    }
+
+    /// Change how often Tick events are sent, or stop them with `None`
+    pub fn set_tick_rate(&self, tick_rate: Option<std::time::Duration>) {
+        self.schedule(Schedule::TickRate(tick_rate));
+    }
+
+    /// Send a Timer event with this id after the duration has passed, and then
+    /// every duration after that if it repeats. This replaces any running
+    /// timer with the same id. A zero duration timer only goes off once, and
+    /// one too long to add to the clock never goes off.
+    pub fn start_timer(&self, id: &str, duration: std::time::Duration, repeat: bool) {
+        let Some(due) = std::time::Instant::now().checked_add(duration) else {
+            self.cancel_timer(id);
+            return;
+        };
+
+        self.schedule(Schedule::StartTimer(Timer {
+            id: id.to_string(),
+            due,
+            repeat: if repeat && !duration.is_zero() {
+                Some(duration)
+            } else {
+                None
+            },
+        }));
+    }
+
+    pub fn cancel_timer(&self, id: &str) {
+        self.schedule(Schedule::CancelTimer(id.to_string()));
+    }
+
+    /// Send the clipboard's contents in a ClipboardRead event, or
+    /// ClipboardUnavailable if the terminal won't share it
+    pub fn read_clipboard(&self) {
+        self.schedule(Schedule::ReadClipboard);
+    }
+
+    /// Stop reading input until the returned guard is dropped, so a program
+    /// run in the foreground has the keyboard to itself. Ticks and timers
+    /// are held up too.
+    pub fn pause(&self) -> Paused {
+        let (paused_tx, paused_rx) = std::sync::mpsc::channel();
+        let (resume_tx, resume_rx) = std::sync::mpsc::channel();
+        self.schedule(Schedule::Pause {
+            paused: paused_tx,
+            resume: resume_rx,
+        });
+
+        // Wait until the event thread has finished its current poll
+        let _ = paused_rx.recv();
+
+        Paused { _resume: resume_tx }
+    }
+
+    fn schedule(&self, change: Schedule) {
+        self.schedule_tx
+            .send(change)
+            .expect("TODO handle unable to send schedule change to event thread");
+    }
+}
+
+/// When a repeating timer is next due. It keeps to its original cadence
+/// unless it has fallen behind, and is `None` if that is too far away to add
+/// to the clock.
+fn get_next_due(
+    due: std::time::Instant,
+    repeat: std::time::Duration,
+    now: std::time::Instant,
+) -> Option<std::time::Instant> {
+    let next = due.checked_add(repeat)?;
+    if next <= now {
+        now.checked_add(repeat)
+    } else {
+        Some(next)
+    }
 }
 
 fn get_cursor(cursor : glue::Cursor) -> Option<(u16, u16)> {
@@ -197,6 +825,11 @@ fn render_widget<B: tui::backend::Backend>(
         glue::discriminant_Elem::Layout => render_layout(f, area, elem),
         glue::discriminant_Elem::Block => render_block(f, area, elem),
         glue::discriminant_Elem::ListItems => render_list(f, area, elem),
+        glue::discriminant_Elem::Tabs => render_tabs(f, area, elem),
+        glue::discriminant_Elem::Table => render_table(f, area, elem),
+        glue::discriminant_Elem::Gauge => render_gauge(f, area, elem),
+        glue::discriminant_Elem::LineGauge => render_line_gauge(f, area, elem),
+        glue::discriminant_Elem::Sparkline => render_sparkline(f, area, elem),
     }
 }
 
@@ -414,15 +1047,216 @@ fn render_list<B: tui::backend::Backend>(
 
 }
 
+fn render_tabs<B: tui::backend::Backend>(
+    f: &mut tui::Frame<B>,
+    area: tui::layout::Rect,
+    tabs: &glue::Elem,
+) {
+    let config = unsafe { tabs.as_Tabs() };
+
+    let titles = config.titles.iter().map(get_spans).collect();
+
+    let divider = tui::text::Span::styled(config.divider.text.as_str(), get_style(&config.divider.style));
+    let tabs = tui::widgets::Tabs::new(titles)
+        .block(get_block(&config.block))
+        .select(config.selected as usize)
+        .style(get_style(&config.style))
+        .highlight_style(get_style(&config.highlightStyle))
+        .divider(divider);
+
+    // Render to the frame
+    f.render_widget(tabs, area);
+}
+
+fn render_table<B: tui::backend::Backend>(
+    f: &mut tui::Frame<B>,
+    area: tui::layout::Rect,
+    table: &glue::Elem,
+) {
+    let config = unsafe { table.as_Table() };
+
+    let rows = config.rows.iter().map(get_row);
+
+    // tui panics on percentages over 100 in the column widths
+    let widths: Vec<tui::layout::Constraint> = get_constraints(&config.widths)
+        .into_iter()
+        .map(|width| match width {
+            tui::layout::Constraint::Percentage(p) => {
+                tui::layout::Constraint::Percentage(p.min(100))
+            }
+            _ => width,
+        })
+        .collect();
+
+    let mut table = tui::widgets::Table::new(rows)
+        .block(get_block(&config.block))
+        .widths(&widths)
+        .column_spacing(config.columnSpacing)
+        .style(get_style(&config.style))
+        .highlight_style(get_style(&config.highlightStyle))
+        .highlight_symbol(config.highlightSymbol.as_str());
+
+    if !config.header.cells.is_empty() {
+        table = table.header(get_row(&config.header));
+    }
+
+    let selection = get_list_selection(&config.selected);
+    let mut table_state = tui::widgets::TableState::default();
+    table_state.select(selection);
+
+    // Render to the frame
+    f.render_stateful_widget(table, area, &mut table_state);
+}
+
+fn render_gauge<B: tui::backend::Backend>(
+    f: &mut tui::Frame<B>,
+    area: tui::layout::Rect,
+    gauge: &glue::Elem,
+) {
+    let config = unsafe { gauge.as_Gauge() };
+
+    let mut gauge = tui::widgets::Gauge::default()
+        .block(get_block(&config.block))
+        .ratio(get_ratio(&config.progress))
+        .style(get_style(&config.style))
+        .gauge_style(get_style(&config.gaugeStyle))
+        .use_unicode(config.useUnicode);
+
+    // tui shows the percentage when there is no label
+    if !config.label.text.is_empty() {
+        let label = &config.label;
+        gauge = gauge.label(tui::text::Span::styled(label.text.as_str(), get_style(&label.style)));
+    }
+
+    // Render to the frame
+    f.render_widget(gauge, area);
+}
+
+fn render_line_gauge<B: tui::backend::Backend>(
+    f: &mut tui::Frame<B>,
+    area: tui::layout::Rect,
+    gauge: &glue::Elem,
+) {
+    let config = unsafe { gauge.as_LineGauge() };
+
+    let mut gauge = tui::widgets::LineGauge::default()
+        .block(get_block(&config.block))
+        .ratio(get_ratio(&config.progress))
+        .style(get_style(&config.style))
+        .gauge_style(get_style(&config.gaugeStyle))
+        .line_set(get_line_set(config.lineType));
+
+    // tui shows the percentage when there is no label
+    if !config.label.text.is_empty() {
+        let label = &config.label;
+        gauge = gauge.label(tui::text::Span::styled(label.text.as_str(), get_style(&label.style)));
+    }
+
+    // Render to the frame
+    f.render_widget(gauge, area);
+}
+
+fn render_sparkline<B: tui::backend::Backend>(
+    f: &mut tui::Frame<B>,
+    area: tui::layout::Rect,
+    sparkline: &glue::Elem,
+) {
+    let config = unsafe { sparkline.as_Sparkline() };
+
+    let mut sparkline = tui::widgets::Sparkline::default()
+        .block(get_block(&config.block))
+        .data(config.data.as_slice())
+        .style(get_style(&config.style))
+        .bar_set(get_bar_set(config.barSet));
+
+    // tui uses the largest data point when there is no max
+    if let glue::discriminant_SparklineMax::Max = config.max.discriminant() {
+        sparkline = sparkline.max(unsafe { *config.max.as_Max() });
+    }
+
+    // Render to the frame
+    f.render_widget(sparkline, area);
+}
+
+/// Block window for a widget to live in
+fn get_block(config: &glue::BlockConfig) -> tui::widgets::Block<'_> {
+    let title = tui::text::Span::styled(config.title.text.as_str(), get_style(&config.title.style));
+    tui::widgets::Block::default()
+        .title(title)
+        .title_alignment(get_alignment(config.titleAlignment))
+        .borders(get_borders(&config.borders))
+        .border_style(get_style(&config.borderStyle))
+        .border_type(get_border_type(config.borderType))
+        .style(get_style(&config.style))
+}
+
+/// tui panics on a ratio outside 0 to 1, so progress past either end is
+/// clamped. A ratio out of zero counts as no progress.
+fn get_ratio(progress: &glue::Progress) -> f64 {
+    let ratio = match progress.discriminant() {
+        glue::discriminant_Progress::Percentage => {
+            let percent = unsafe { progress.as_Percentage() };
+            f64::from(*percent) / 100.0
+        }
+        glue::discriminant_Progress::Ratio => {
+            let (numerator, denominator) = unsafe { progress.as_Ratio() };
+            if *denominator == 0 {
+                0.0
+            } else {
+                *numerator as f64 / *denominator as f64
+            }
+        }
+    };
+    ratio.clamp(0.0, 1.0)
+}
+
+fn get_line_set(line_type: glue::BorderType) -> tui::symbols::line::Set {
+    match line_type {
+        glue::BorderType::Plain => tui::symbols::line::NORMAL,
+        glue::BorderType::Rounded => tui::symbols::line::ROUNDED,
+        glue::BorderType::Double => tui::symbols::line::DOUBLE,
+        glue::BorderType::Thick => tui::symbols::line::THICK,
+    }
+}
+
+fn get_bar_set(bar_set: glue::BarSet) -> tui::symbols::bar::Set {
+    match bar_set {
+        glue::BarSet::NineLevels => tui::symbols::bar::NINE_LEVELS,
+        glue::BarSet::ThreeLevels => tui::symbols::bar::THREE_LEVELS,
+    }
+}
+
+fn get_row(row: &glue::TableRow) -> tui::widgets::Row<'_> {
+    let cells = row.cells.iter().map(|cell| tui::widgets::Cell::from(get_spans(cell)));
+    tui::widgets::Row::new(cells).style(get_style(&row.style))
+}
+
+/// Build a line up from its Span(s)
+fn get_spans(line: &RocList<glue::Span>) -> tui::text::Spans<'_> {
+    let spans: Vec<tui::text::Span> = line
+        .iter()
+        .map(|span| tui::text::Span::styled(span.text.as_str(), get_style(&span.style)))
+        .collect();
+    tui::text::Spans::from(spans)
+}
+
 fn get_style(roc_style: &glue::Style) -> tui::style::Style {
     let mut style = tui::style::Style::default();
+    let color_support = COLOR_SUPPORT
+        .get()
+        .copied()
+        .unwrap_or(glue::ColorSupport::TrueColor);
 
     if roc_style.bg.discriminant() != glue::discriminant_Color::Default {
-        style = style.bg(get_color(roc_style.bg));
+        if let Some(bg) = color::downgrade(get_color(roc_style.bg), color_support) {
+            style = style.bg(bg);
+        }
     }
 
     if roc_style.fg.discriminant() != glue::discriminant_Color::Default {
-        style = style.fg(get_color(roc_style.fg));
+        if let Some(fg) = color::downgrade(get_color(roc_style.fg), color_support) {
+            style = style.fg(fg);
+        }
     }
 
     let mut modifiers = tui::style::Modifier::empty();
@@ -474,6 +1308,19 @@ fn get_color(color: glue::Color) -> tui::style::Color {
     }
 }
 
+/// The DECSCUSR number for each cursor style
+fn get_cursor_style(style: glue::CursorStyle) -> u8 {
+    match style {
+        glue::CursorStyle::Default => 0,
+        glue::CursorStyle::BlinkingBlock => 1,
+        glue::CursorStyle::SteadyBlock => 2,
+        glue::CursorStyle::BlinkingUnderline => 3,
+        glue::CursorStyle::SteadyUnderline => 4,
+        glue::CursorStyle::BlinkingBar => 5,
+        glue::CursorStyle::SteadyBar => 6,
+    }
+}
+
 fn get_alignment(roc_alignment: glue::Alignment) -> tui::layout::Alignment {
     match roc_alignment {
         glue::Alignment::Left => tui::layout::Alignment::Left,
@@ -542,6 +1389,79 @@ fn get_layout_direction(direction: glue::LayoutDirection) -> tui::layout::Direct
     }
 }
 
+fn get_key_event(key: crossterm::event::KeyEvent) -> glue::KeyEvent {
+    glue::KeyEvent {
+        code: get_key_code(key.code),
+        modifiers: get_key_modifiers(key.modifiers),
+        kind: get_key_event_kind(key.kind),
+    }
+}
+
+fn get_key_modifiers(km: crossterm::event::KeyModifiers) -> RocList<glue::KeyModifier> {
+    let mut modifiers = Vec::new();
+    if km.contains(crossterm::event::KeyModifiers::SHIFT) {
+        modifiers.push(glue::KeyModifier::Shift);
+    }
+    if km.contains(crossterm::event::KeyModifiers::CONTROL) {
+        modifiers.push(glue::KeyModifier::Control);
+    }
+    if km.contains(crossterm::event::KeyModifiers::ALT) {
+        modifiers.push(glue::KeyModifier::Alt);
+    }
+    if km.contains(crossterm::event::KeyModifiers::SUPER) {
+        modifiers.push(glue::KeyModifier::Super);
+    }
+    if km.contains(crossterm::event::KeyModifiers::HYPER) {
+        modifiers.push(glue::KeyModifier::Hyper);
+    }
+    if km.contains(crossterm::event::KeyModifiers::META) {
+        modifiers.push(glue::KeyModifier::Meta);
+    }
+    RocList::from_slice(&modifiers)
+}
+
+fn get_key_event_kind(kind: crossterm::event::KeyEventKind) -> glue::KeyEventKind {
+    match kind {
+        crossterm::event::KeyEventKind::Press => glue::KeyEventKind::Press,
+        crossterm::event::KeyEventKind::Repeat => glue::KeyEventKind::Repeat,
+        crossterm::event::KeyEventKind::Release => glue::KeyEventKind::Release,
+    }
+}
+
+fn get_mouse_event(mouse: crossterm::event::MouseEvent) -> glue::MouseEvent {
+    glue::MouseEvent {
+        kind: get_mouse_event_kind(mouse.kind),
+        column: mouse.column,
+        row: mouse.row,
+        modifiers: get_key_modifiers(mouse.modifiers),
+    }
+}
+
+fn get_mouse_event_kind(kind: crossterm::event::MouseEventKind) -> glue::MouseEventKind {
+    match kind {
+        crossterm::event::MouseEventKind::Down(button) => {
+            glue::MouseEventKind::Down(get_mouse_button(button))
+        }
+        crossterm::event::MouseEventKind::Up(button) => {
+            glue::MouseEventKind::Up(get_mouse_button(button))
+        }
+        crossterm::event::MouseEventKind::Drag(button) => {
+            glue::MouseEventKind::Drag(get_mouse_button(button))
+        }
+        crossterm::event::MouseEventKind::Moved => glue::MouseEventKind::Moved,
+        crossterm::event::MouseEventKind::ScrollDown => glue::MouseEventKind::ScrollDown,
+        crossterm::event::MouseEventKind::ScrollUp => glue::MouseEventKind::ScrollUp,
+    }
+}
+
+fn get_mouse_button(button: crossterm::event::MouseButton) -> glue::MouseButton {
+    match button {
+        crossterm::event::MouseButton::Left => glue::MouseButton::Left,
+        crossterm::event::MouseButton::Right => glue::MouseButton::Right,
+        crossterm::event::MouseButton::Middle => glue::MouseButton::Middle,
+    }
+}
+
 fn get_key_code(event: crossterm::event::KeyCode) -> glue::KeyCode {
     match event {
         crossterm::event::KeyCode::BackTab => glue::KeyCode::BackTab,
@@ -657,3 +1577,56 @@ fn get_popup(popup : &glue::PopupConfig) -> Option<(u16,u16)>{
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resize_bounds_match_the_terminal_size() {
+        let backend = tui::backend::TestBackend::new(80, 24);
+        let mut terminal = tui::Terminal::new(backend).unwrap();
+        let size = terminal.size().unwrap();
+        let initial_bounds = get_window_bounds(size.width, size.height);
+        assert_eq!(initial_bounds, glue::Bounds { height: 24, width: 80 });
+
+        // crossterm reports the new size as Resize(column, row)
+        terminal.backend_mut().resize(120, 40);
+        let resized_bounds = get_window_bounds(120, 40);
+
+        let mut frame_size = tui::layout::Rect::default();
+        terminal.draw(|f| frame_size = f.size()).unwrap();
+        assert_eq!(
+            resized_bounds,
+            glue::Bounds {
+                height: frame_size.height,
+                width: frame_size.width
+            }
+        );
+        assert_eq!(resized_bounds, glue::Bounds { height: 40, width: 120 });
+    }
+
+    #[test]
+    fn repeating_timers_keep_their_cadence() {
+        let now = std::time::Instant::now();
+        let second = std::time::Duration::from_secs(1);
+
+        assert_eq!(get_next_due(now, second, now), Some(now + second));
+        // Fallen behind, so the next one is a whole period from now
+        assert_eq!(get_next_due(now, second, now + 3 * second), Some(now + 4 * second));
+        // Too far away for the clock. Where that starts depends on the
+        // platform, some can't add `Num.maxU64` milliseconds.
+        let forever = std::time::Duration::MAX;
+        assert_eq!(get_next_due(now, forever, now), None);
+    }
+
+    #[test]
+    fn gauge_progress_is_clamped() {
+        assert_eq!(get_ratio(&glue::Progress::Percentage(40)), 0.4);
+        assert_eq!(get_ratio(&glue::Progress::Percentage(250)), 1.0);
+        assert_eq!(get_ratio(&glue::Progress::Ratio(3, 4)), 0.75);
+        assert_eq!(get_ratio(&glue::Progress::Ratio(5, 4)), 1.0);
+        assert_eq!(get_ratio(&glue::Progress::Ratio(1, 0)), 0.0);
+        assert_eq!(get_ratio(&glue::Progress::Ratio(3 << 32, 4 << 32)), 0.75);
+    }
+}